@@ -0,0 +1,486 @@
+//! Derive macros for `rust-asn1`. Use them through the `derive` feature of `rust-asn1`,
+//! which re-exports them from `rust_asn1::der`.
+
+use proc_macro::TokenStream;
+use proc_macro2::TokenStream as TokenStream2;
+use quote::quote;
+use syn::{
+    parse_macro_input, parse_quote, Attribute, Data, DeriveInput, Expr, Fields, GenericArgument, LitInt, LitStr, Member, Path, PathArguments, Type,
+};
+
+/// Derives `DERParseable`, `DERSerializable` and `DERImplicitlyTaggable` for a struct that
+/// maps to a SEQUENCE, one component per field in declaration order.
+///
+/// Every field type must implement `DERParseable` and `DERSerializable`. For generic
+/// structs these bounds are added to the generated impls.
+///
+/// A struct with a borrowed field, such as `&'a ASN1Integer` or `Option<&'a T>`, cannot be
+/// decoded, so only `DERSerializable` is derived for it and the bounds are placed on the
+/// borrowed types. `parse_with` is not allowed on its fields.
+///
+/// Fields accept an `#[asn1(...)]` attribute:
+///
+/// - `context = N, explicit` wraps the field in a constructed `[N] EXPLICIT` tag.
+/// - `context = N, implicit` replaces the field's identifier with `[N] IMPLICIT`; the field
+///   type must implement `DERImplicitlyTaggable`.
+/// - `optional` marks an `Option<T>` field as OPTIONAL. The next component is only taken when
+///   its identifier matches the field's tag, or is one `T` accepts if it is untagged.
+/// - `default = "expr"` marks a field as DEFAULT `expr`. An absent field decodes to `expr`, and
+///   a field equal to `expr` is omitted on encode; DER rejects an encoded default value.
+/// - `parse_with = "path"` decodes the field's node with `fn(ASN1Node) -> Result<T, ASN1Error>`
+///   instead of `T::from_der_node`, and `serialize_with = "path"` encodes it with
+///   `fn(&T, &mut Serializer) -> Result<(), ASN1Error>` instead of `T::serialize`. With an
+///   explicit tag they see the node inside the tag. They cannot be combined with an implicit
+///   tag, and an untagged `optional` or `default` field cannot use `parse_with`, as both need
+///   `T`'s identifier.
+///
+/// `#[asn1(automatic_tags)]` on the struct matches a module declared with AUTOMATIC TAGS: the
+/// fields are tagged `[0] IMPLICIT`, `[1] IMPLICIT` and so on in declaration order. `context`
+/// is not allowed on its fields, and `explicit` makes a field's automatic tag EXPLICIT, as
+/// X.680 requires for CHOICE and open type components.
+#[proc_macro_derive(DERSequence, attributes(asn1))]
+pub fn derive_der_sequence(input: TokenStream) -> TokenStream {
+    let input = parse_macro_input!(input as DeriveInput);
+    expand_der_sequence(input).unwrap_or_else(syn::Error::into_compile_error).into()
+}
+
+struct Tag {
+    number: u64,
+    explicit: bool,
+}
+
+enum Presence {
+    Required,
+    /// `#[asn1(optional)]` on an `Option<T>` field; holds `T`.
+    Optional(Type),
+    /// `#[asn1(default = "expr")]`.
+    Default(Expr),
+}
+
+struct Field {
+    member: Member,
+    ty: Type,
+    tag: Option<Tag>,
+    presence: Presence,
+    parse_with: Option<Path>,
+    serialize_with: Option<Path>,
+}
+
+impl Field {
+    fn identifier(tag: &Tag) -> TokenStream2 {
+        let number = tag.number;
+        quote! {
+            ::rust_asn1::asn1_types::ASN1Identifier::new(#number, ::rust_asn1::asn1_types::TagClass::ContextSpecific)
+        }
+    }
+
+    /// The type that is actually encoded: `T` for an optional `Option<T>`, otherwise the field type.
+    fn value_ty(&self) -> &Type {
+        match &self.presence {
+            Presence::Optional(inner) => inner,
+            _ => &self.ty,
+        }
+    }
+
+    /// Whether the encoded value is a reference, which makes the struct serialize-only.
+    fn borrowed(&self) -> bool {
+        matches!(self.value_ty(), Type::Reference(_))
+    }
+
+    /// Decodes the value from its node: `parse_with` if given, otherwise `value_ty`'s own parser.
+    fn node_parser(&self) -> TokenStream2 {
+        let ty = self.value_ty();
+        match &self.parse_with {
+            Some(path) => quote! { #path },
+            None => quote! { <#ty as ::rust_asn1::der::DERParseable>::from_der_node },
+        }
+    }
+
+    fn decoder(&self) -> TokenStream2 {
+        let default = match &self.presence {
+            Presence::Required => return self.required_decoder(),
+            Presence::Optional(_) => return self.optional_decoder(),
+            Presence::Default(default) => default,
+        };
+        let optional = self.optional_decoder();
+        quote! { ::rust_asn1::der::decode_default(#optional, || #default)? }
+    }
+
+    fn required_decoder(&self) -> TokenStream2 {
+        let ty = &self.ty;
+        let next_node = quote! {
+            <::rust_asn1::asn1::ASN1Node as ::rust_asn1::der::DERParseable>::from_der_iterator(iter)?
+        };
+        match &self.tag {
+            None if let Some(path) = &self.parse_with => quote! { #path(#next_node)? },
+            None => quote! { <#ty as ::rust_asn1::der::DERParseable>::from_der_iterator(iter)? },
+            Some(tag @ Tag { explicit: true, .. }) => {
+                let number = tag.number;
+                let parser = self.node_parser();
+                quote! {
+                    ::rust_asn1::der::explicitly_tagged(
+                        #next_node,
+                        #number,
+                        ::rust_asn1::asn1_types::TagClass::ContextSpecific,
+                        #parser,
+                    )?
+                }
+            }
+            Some(tag) => {
+                let identifier = Self::identifier(tag);
+                quote! {
+                    <#ty as ::rust_asn1::der::DERImplicitlyTaggable>::from_der_node_with_identifier(#next_node, #identifier)?
+                }
+            }
+        }
+    }
+
+    /// Decodes the field as `Option<value_ty>`, only consuming the next node when its
+    /// identifier matches.
+    fn optional_decoder(&self) -> TokenStream2 {
+        let ty = self.value_ty();
+        match &self.tag {
+            None => quote! {
+                <::core::option::Option<#ty> as ::rust_asn1::der::DERParseable>::from_der_iterator(iter)?
+            },
+            Some(tag @ Tag { explicit: true, .. }) if let Some(path) = &self.parse_with => {
+                let number = tag.number;
+                let identifier = Self::identifier(tag);
+                quote! {
+                    if ::core::matches!(iter.peek(), ::core::option::Option::Some(node) if node.identifier == #identifier) {
+                        ::core::option::Option::Some(::rust_asn1::der::explicitly_tagged_from_iterator(
+                            iter,
+                            #number,
+                            ::rust_asn1::asn1_types::TagClass::ContextSpecific,
+                            #path,
+                        )?)
+                    } else {
+                        ::core::option::Option::None
+                    }
+                }
+            }
+            Some(tag @ Tag { explicit: true, .. }) => {
+                let number = tag.number;
+                quote! {
+                    ::rust_asn1::der::optional_explicitly_tagged::<#ty>(
+                        iter,
+                        #number,
+                        ::rust_asn1::asn1_types::TagClass::ContextSpecific,
+                    )?
+                }
+            }
+            Some(tag) => {
+                let identifier = Self::identifier(tag);
+                quote! { ::rust_asn1::der::optional_implicitly_tagged::<#ty>(iter, #identifier)? }
+            }
+        }
+    }
+
+    fn encoder(&self) -> TokenStream2 {
+        let member = &self.member;
+        match &self.presence {
+            Presence::Required => self.value_encoder(quote!(&self.#member)),
+            Presence::Optional(_) => {
+                let encode = self.value_encoder(quote!(value));
+                quote! {
+                    if let ::core::option::Option::Some(value) = &self.#member {
+                        #encode
+                    }
+                }
+            }
+            // DER omits a field that holds its DEFAULT value.
+            Presence::Default(default) => {
+                let encode = self.value_encoder(quote!(&self.#member));
+                quote! {
+                    if self.#member != #default {
+                        #encode
+                    }
+                }
+            }
+        }
+    }
+
+    fn value_encoder(&self, value: TokenStream2) -> TokenStream2 {
+        match &self.tag {
+            None if let Some(path) = &self.serialize_with => quote! { #path(#value, nested)?; },
+            None => quote! { nested.serialize(#value)?; },
+            Some(tag @ Tag { explicit: true, .. }) => {
+                let identifier = Self::identifier(tag);
+                let encode = match &self.serialize_with {
+                    Some(path) => quote! { #path(#value, tagged) },
+                    None => quote! { tagged.serialize(#value) },
+                };
+                quote! { nested.append_constructed_node(#identifier, |tagged| #encode)?; }
+            }
+            // `serialize_with_identifier` needs the borrowed type itself, not the reference.
+            Some(tag) if self.borrowed() => {
+                let identifier = Self::identifier(tag);
+                quote! { nested.serialize_with_identifier(*#value, #identifier)?; }
+            }
+            Some(tag) => {
+                let identifier = Self::identifier(tag);
+                quote! { nested.serialize_with_identifier(#value, #identifier)?; }
+            }
+        }
+    }
+
+    /// Whether the field is read or written through `value_ty`'s identifier: implicitly
+    /// tagged, or untagged and possibly absent, which is detected by the identifiers it accepts.
+    fn taggable(&self) -> bool {
+        match (&self.tag, &self.presence) {
+            (Some(tag), _) => !tag.explicit,
+            (None, presence) => !matches!(presence, Presence::Required),
+        }
+    }
+
+    fn bounds(&self) -> Vec<TokenStream2> {
+        let mut bounds = Vec::new();
+        if self.taggable() {
+            bounds.push(quote! { ::rust_asn1::der::DERImplicitlyTaggable });
+        } else {
+            if self.parse_with.is_none() {
+                bounds.push(quote! { ::rust_asn1::der::DERParseable });
+            }
+            if self.serialize_with.is_none() {
+                bounds.push(quote! { ::rust_asn1::der::DERSerializable });
+            }
+        }
+        if let Presence::Default(_) = &self.presence {
+            bounds.push(quote! { ::core::cmp::PartialEq });
+        }
+        bounds
+    }
+
+    /// The bounds of a serialize-only struct, which are placed on [`serialized_ty`](Self::serialized_ty).
+    fn serialize_bounds(&self) -> Vec<TokenStream2> {
+        let mut bounds = Vec::new();
+        if matches!(self.tag, Some(Tag { explicit: false, .. })) {
+            bounds.push(quote! { ::rust_asn1::der::DERImplicitlyTaggable });
+        } else if self.serialize_with.is_none() {
+            bounds.push(quote! { ::rust_asn1::der::DERSerializable });
+        }
+        if let Presence::Default(_) = &self.presence {
+            bounds.push(quote! { ::core::cmp::PartialEq });
+        }
+        bounds
+    }
+
+    /// `T` for a field borrowing `&T`, otherwise [`value_ty`](Self::value_ty).
+    fn serialized_ty(&self) -> &Type {
+        match self.value_ty() {
+            Type::Reference(reference) => &reference.elem,
+            ty => ty,
+        }
+    }
+}
+
+/// Returns `T` when `ty` is spelled `Option<T>`.
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else { return None };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(arguments) = &segment.arguments else { return None };
+    match arguments.args.first()? {
+        GenericArgument::Type(inner) if arguments.args.len() == 1 => Some(inner),
+        _ => None,
+    }
+}
+
+/// Parses a field's `#[asn1(...)]` attributes. Under AUTOMATIC TAGS the field is tagged
+/// `[automatic_tag] IMPLICIT`, or EXPLICIT when it is marked `explicit`.
+fn parse_field(member: Member, field: &syn::Field, automatic_tag: Option<u64>) -> syn::Result<Field> {
+    let mut tag = automatic_tag.map(|number| Tag { number, explicit: false });
+    let mut presence = Presence::Required;
+    let mut parse_with = None;
+    let mut serialize_with = None;
+    for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("asn1")) {
+        let mut number = None;
+        let mut explicit = None;
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("context") {
+                number = Some(meta.value()?.parse::<LitInt>()?.base10_parse::<u64>()?);
+            } else if meta.path.is_ident("explicit") {
+                explicit = Some(true);
+            } else if meta.path.is_ident("implicit") {
+                explicit = Some(false);
+            } else if meta.path.is_ident("optional") || meta.path.is_ident("default") {
+                if !matches!(presence, Presence::Required) {
+                    return Err(meta.error("a field can only be one of `optional` or `default`"));
+                }
+                presence = if meta.path.is_ident("optional") {
+                    let inner = option_inner(&field.ty)
+                        .ok_or_else(|| meta.error("`optional` fields must have type `Option<T>`"))?;
+                    Presence::Optional(inner.clone())
+                } else {
+                    Presence::Default(meta.value()?.parse::<LitStr>()?.parse::<Expr>()?)
+                };
+            } else if meta.path.is_ident("parse_with") {
+                parse_with = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+            } else if meta.path.is_ident("serialize_with") {
+                serialize_with = Some(meta.value()?.parse::<LitStr>()?.parse::<Path>()?);
+            } else {
+                return Err(meta.error("unsupported asn1 attribute"));
+            }
+            Ok(())
+        })?;
+        match (number, explicit) {
+            (Some(_), _) if automatic_tag.is_some() => {
+                return Err(syn::Error::new_spanned(attr, "`context` cannot be used with `automatic_tags`"));
+            }
+            (None, Some(explicit)) if let (Some(_), Some(tag)) = (automatic_tag, &mut tag) => tag.explicit = explicit,
+            (Some(number), Some(explicit)) => tag = Some(Tag { number, explicit }),
+            (Some(_), None) => return Err(syn::Error::new_spanned(attr, "a context tag must be marked `explicit` or `implicit`")),
+            (None, Some(_)) => return Err(syn::Error::new_spanned(attr, "`explicit` and `implicit` require `context = N`")),
+            (None, None) => {}
+        }
+    }
+    let field = Field { member, ty: field.ty.clone(), tag, presence, parse_with, serialize_with };
+    let implicit = matches!(field.tag, Some(Tag { explicit: false, .. }));
+    if let Some(path) = field.parse_with.as_ref().filter(|_| field.taggable()) {
+        return Err(syn::Error::new_spanned(path, "`parse_with` needs an untagged required field or an explicit tag"));
+    }
+    if let Some(path) = field.serialize_with.as_ref().filter(|_| implicit) {
+        return Err(syn::Error::new_spanned(path, "`serialize_with` cannot be used with an implicit tag"));
+    }
+    Ok(field)
+}
+
+fn automatic_tags(attrs: &[Attribute]) -> syn::Result<bool> {
+    let mut automatic_tags = false;
+    for attr in attrs.iter().filter(|attr| attr.path().is_ident("asn1")) {
+        attr.parse_nested_meta(|meta| {
+            if meta.path.is_ident("automatic_tags") {
+                automatic_tags = true;
+                Ok(())
+            } else {
+                Err(meta.error("unsupported asn1 attribute"))
+            }
+        })?;
+    }
+    Ok(automatic_tags)
+}
+
+fn struct_fields(input: &DeriveInput) -> syn::Result<(Vec<Field>, &Fields)> {
+    let Data::Struct(data) = &input.data else {
+        return Err(syn::Error::new_spanned(&input.ident, "DERSequence can only be derived for structs"));
+    };
+    let automatic_tags = automatic_tags(&input.attrs)?;
+    let fields = data
+        .fields
+        .members()
+        .zip(&data.fields)
+        .zip(0u64..)
+        .map(|((member, field), index)| parse_field(member, field, automatic_tags.then_some(index)))
+        .collect::<syn::Result<_>>()?;
+    Ok((fields, &data.fields))
+}
+
+fn expand_der_sequence(input: DeriveInput) -> syn::Result<TokenStream2> {
+    let (fields, shape) = struct_fields(&input)?;
+    let name = &input.ident;
+    let serialize_only = fields.iter().any(Field::borrowed);
+    if serialize_only && let Some(path) = fields.iter().find_map(|field| field.parse_with.as_ref()) {
+        return Err(syn::Error::new_spanned(path, "`parse_with` cannot be used in a struct with borrowed fields"));
+    }
+
+    let mut generics = input.generics.clone();
+    if generics.type_params().next().is_some() {
+        let where_clause = generics.make_where_clause();
+        for field in &fields {
+            let (ty, bounds) = if serialize_only {
+                (field.serialized_ty(), field.serialize_bounds())
+            } else {
+                (field.value_ty(), field.bounds())
+            };
+            if !bounds.is_empty() {
+                where_clause.predicates.push(parse_quote! { #ty: #(#bounds)+* });
+            }
+        }
+    }
+    let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
+
+    let decoders = fields.iter().map(Field::decoder);
+    let construct = match shape {
+        Fields::Named(_) => {
+            let members = fields.iter().map(|field| &field.member);
+            quote! { #name { #(#members: #decoders,)* } }
+        }
+        Fields::Unnamed(_) => quote! { #name(#(#decoders,)*) },
+        Fields::Unit => quote! { #name },
+    };
+    // Name the closure parameters `_` when there are no fields, so they are not unused.
+    let (iter, nested) = if fields.is_empty() {
+        (quote!(_), quote!(_))
+    } else {
+        (quote!(iter), quote!(nested))
+    };
+    let encoders = fields.iter().map(Field::encoder);
+
+    if serialize_only {
+        return Ok(quote! {
+            impl #impl_generics ::rust_asn1::der::DERSerializable for #name #ty_generics #where_clause {
+                fn serialize(
+                    &self,
+                    serializer: &mut ::rust_asn1::der::Serializer,
+                ) -> ::core::result::Result<(), ::rust_asn1::errors::ASN1Error> {
+                    serializer.append_constructed_node(::rust_asn1::asn1_types::ASN1Identifier::SEQUENCE, |nested| {
+                        #(#encoders)*
+                        ::core::result::Result::Ok(())
+                    })
+                }
+            }
+        });
+    }
+
+    Ok(quote! {
+        impl #impl_generics ::rust_asn1::der::DERParseable for #name #ty_generics #where_clause {
+            fn from_der_node(
+                node: ::rust_asn1::asn1::ASN1Node,
+            ) -> ::core::result::Result<Self, ::rust_asn1::errors::ASN1Error> {
+                <Self as ::rust_asn1::der::DERImplicitlyTaggable>::from_der_node_with_identifier(
+                    node,
+                    ::rust_asn1::asn1_types::ASN1Identifier::SEQUENCE,
+                )
+            }
+        }
+
+        impl #impl_generics ::rust_asn1::der::DERSerializable for #name #ty_generics #where_clause {
+            fn serialize(
+                &self,
+                serializer: &mut ::rust_asn1::der::Serializer,
+            ) -> ::core::result::Result<(), ::rust_asn1::errors::ASN1Error> {
+                <Self as ::rust_asn1::der::DERImplicitlyTaggable>::serialize_with_identifier(
+                    self,
+                    serializer,
+                    ::rust_asn1::asn1_types::ASN1Identifier::SEQUENCE,
+                )
+            }
+        }
+
+        impl #impl_generics ::rust_asn1::der::DERImplicitlyTaggable for #name #ty_generics #where_clause {
+            fn default_identifier() -> ::rust_asn1::asn1_types::ASN1Identifier {
+                ::rust_asn1::asn1_types::ASN1Identifier::SEQUENCE
+            }
+
+            fn from_der_node_with_identifier(
+                node: ::rust_asn1::asn1::ASN1Node,
+                identifier: ::rust_asn1::asn1_types::ASN1Identifier,
+            ) -> ::core::result::Result<Self, ::rust_asn1::errors::ASN1Error> {
+                ::rust_asn1::der::sequence(node, identifier, |#iter| ::core::result::Result::Ok(#construct))
+            }
+
+            fn serialize_with_identifier(
+                &self,
+                serializer: &mut ::rust_asn1::der::Serializer,
+                identifier: ::rust_asn1::asn1_types::ASN1Identifier,
+            ) -> ::core::result::Result<(), ::rust_asn1::errors::ASN1Error> {
+                serializer.append_constructed_node(identifier, |#nested| {
+                    #(#encoders)*
+                    ::core::result::Result::Ok(())
+                })
+            }
+        }
+    })
+}
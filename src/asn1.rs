@@ -1,6 +1,6 @@
 use crate::asn1_types::{ASN1Identifier, TagClass};
 use crate::errors::{ASN1Error, ErrorCode};
-use bytes::Bytes;
+use crate::buffer::Bytes;
 use std::ops::Range;
 use std::sync::Arc;
 
@@ -15,7 +15,15 @@ fn minimal_octet_len(value: u64) -> usize {
         return 1;
     }
     let significant_bits = 64 - value.leading_zeros();
-    ((significant_bits + 7) / 8) as usize
+    significant_bits.div_ceil(8) as usize
+}
+
+/// Optional parser checks beyond what the encoding rules require.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ParseOptions {
+    /// Reject universal tags that are reserved or unassigned (15 and above 36), and
+    /// tag 0 anywhere other than as the end-of-contents marker of an indefinite length.
+    pub reject_reserved_universal_tags: bool,
 }
 
 impl EncodingRules {
@@ -28,17 +36,45 @@ impl EncodingRules {
     }
 }
 
+/// A single entry in the flat node list produced by [`ParseResult`].
+///
+/// Nodes are stored in document (pre-order) order. A node's children are the
+/// entries that immediately follow it with a greater `depth`, up to the next entry
+/// whose depth is less than or equal to its own.
 #[derive(Debug, Clone)]
-pub(crate) struct ParserNode {
-    pub identifier: ASN1Identifier,
-    pub depth: usize,
-    pub is_constructed: bool,
-    pub encoded_bytes: Bytes,
-    pub data_bytes: Option<Bytes>,
+pub struct ParserNode {
+    pub(crate) identifier: ASN1Identifier,
+    pub(crate) depth: usize,
+    pub(crate) is_constructed: bool,
+    pub(crate) encoded_bytes: Bytes,
+    pub(crate) data_bytes: Option<Bytes>,
 }
 
 impl ParserNode {
-    pub fn is_end_marker(&self) -> bool {
+    pub fn identifier(&self) -> ASN1Identifier {
+        self.identifier
+    }
+
+    /// Nesting depth of the node; the root of a document has depth 1.
+    pub fn depth(&self) -> usize {
+        self.depth
+    }
+
+    pub fn is_constructed(&self) -> bool {
+        self.is_constructed
+    }
+
+    /// The full TLV encoding of this node, including identifier and length octets.
+    pub fn encoded_bytes(&self) -> &Bytes {
+        &self.encoded_bytes
+    }
+
+    /// The content octets of a primitive node, or `None` for constructed nodes.
+    pub fn data_bytes(&self) -> Option<&Bytes> {
+        self.data_bytes.as_ref()
+    }
+
+    pub(crate) fn is_end_marker(&self) -> bool {
         self.identifier.tag_class == TagClass::Universal
             && self.identifier.tag_number == 0
             && !self.is_constructed
@@ -47,9 +83,16 @@ impl ParserNode {
     }
 }
 
+/// The flat output of the parser: every node of a document, with its depth and encoding.
+///
+/// This is a low-level view intended for custom traversals, indexes and zero-copy
+/// extraction. Most callers should use `der::parse`/`ber::parse` and the
+/// [`ASN1Node`] tree API instead. All byte buffers share the input's storage, so
+/// holding on to a node keeps the whole input alive.
 #[derive(Debug)]
-pub(crate) struct ParseResult {
-    pub nodes: Vec<ParserNode>,
+pub struct ParseResult {
+    pub(crate) nodes: Vec<ParserNode>,
+    data: Bytes,
 }
 
 impl ParseResult {
@@ -57,11 +100,35 @@ impl ParseResult {
     const MAXIMUM_TOTAL_NODES: usize = 100_000;
 
     pub fn parse(data: Bytes, rules: EncodingRules) -> Result<ParseResult, ASN1Error> {
+        Self::parse_with_options(data, rules, ParseOptions::default())
+    }
+
+    pub fn parse_with_options(
+        data: Bytes,
+        rules: EncodingRules,
+        options: ParseOptions,
+    ) -> Result<ParseResult, ASN1Error> {
         let mut nodes = Vec::with_capacity(16);
-        let mut current_data = data;
+        let mut current_data = data.clone();
         let mut node_count = 0;
+        let mut node_start = data.as_ptr() as usize;
+
+        let parsed = Self::_parse_node(
+            &mut current_data,
+            rules,
+            options,
+            1,
+            &mut nodes,
+            &mut node_count,
+            &mut node_start,
+        );
+        #[cfg(feature = "debug-errors")]
+        let parsed = parsed.map_err(|e| e.with_byte_context(&data, node_start - data.as_ptr() as usize));
+        parsed?;
 
-        Self::_parse_node(&mut current_data, rules, 1, &mut nodes, &mut node_count)?;
+        if options.reject_reserved_universal_tags && nodes[0].is_end_marker() {
+            return Err(end_of_contents_misplaced());
+        }
 
         if !current_data.is_empty() {
             return Err(ASN1Error::new(
@@ -72,16 +139,32 @@ impl ParseResult {
             ));
         }
 
-        Ok(ParseResult { nodes })
+        Ok(ParseResult { nodes, data })
+    }
+
+    /// All parsed nodes in document order.
+    pub fn nodes(&self) -> &[ParserNode] {
+        &self.nodes
+    }
+
+    /// The byte range of the node at `index` within the parsed input.
+    pub fn encoded_span(&self, index: usize) -> Option<Range<usize>> {
+        let node = self.nodes.get(index)?;
+        let start = node.encoded_bytes.as_ptr() as usize - self.data.as_ptr() as usize;
+        Some(start..start + node.encoded_bytes.len())
     }
 
     fn _parse_node(
         data: &mut Bytes,
         rules: EncodingRules,
+        options: ParseOptions,
         depth: usize,
         nodes: &mut Vec<ParserNode>,
         node_count: &mut usize,
+        node_start: &mut usize,
     ) -> Result<(), ASN1Error> {
+        // Tracks where the innermost node began so failures can be located in the input.
+        *node_start = data.as_ptr() as usize;
         *node_count += 1;
         if *node_count > Self::MAXIMUM_TOTAL_NODES {
             return Err(ASN1Error::new(
@@ -114,9 +197,7 @@ impl ParseResult {
         let raw_identifier = data.split_to(1)[0];
 
         let constructed = (raw_identifier & 0x20) != 0;
-        let identifier: ASN1Identifier;
-
-        if (raw_identifier & 0x1f) == 0x1f {
+        let identifier = if (raw_identifier & 0x1f) == 0x1f {
             let tag_class = TagClass::from_top_byte(raw_identifier);
             // Read UInt... implementation needed (readUIntUsing8BitBytesASN1Discipline)
             // For now simple implementation or need helper.
@@ -131,23 +212,32 @@ impl ParseResult {
                     line!(),
                 ));
             }
-            identifier = ASN1Identifier::new(tag_number, tag_class);
+            ASN1Identifier::new(tag_number, tag_class)
         } else {
-            identifier = ASN1Identifier::from_short_identifier(raw_identifier);
-        }
+            ASN1Identifier::from_short_identifier(raw_identifier)
+        };
 
         let wide_length = _read_asn1_length(data, !rules.non_minimal_encoded_lengths_allowed())?;
 
+        if options.reject_reserved_universal_tags && identifier.tag_class == TagClass::Universal {
+            let reserved = match identifier.tag_number {
+                0 => constructed || wide_length != ASN1Length::Definite(0),
+                15 => true,
+                number => number > 36,
+            };
+            if reserved {
+                return Err(ASN1Error::new(
+                    ErrorCode::InvalidASN1Object,
+                    format!("Reserved universal tag number {}", identifier.tag_number),
+                    file!().to_string(),
+                    line!(),
+                ));
+            }
+        }
+
         match wide_length {
             ASN1Length::Definite(length) => {
-                let length_usize = usize::try_from(length).map_err(|_| {
-                    ASN1Error::new(
-                        ErrorCode::InvalidASN1Object,
-                        "Field length exceeds platform address space".to_string(),
-                        file!().to_string(),
-                        line!(),
-                    )
-                })?;
+                let length_usize = checked_field_length(length)?;
                 if data.len() < length_usize {
                     return Err(ASN1Error::new(
                         ErrorCode::TruncatedASN1Field,
@@ -173,7 +263,12 @@ impl ParseResult {
 
                     let mut check_sub = sub_data;
                     while !check_sub.is_empty() {
-                        Self::_parse_node(&mut check_sub, rules, depth + 1, nodes, node_count)?;
+                        Self::_parse_node(&mut check_sub, rules, options, depth + 1, nodes, node_count, node_start)?;
+                        if options.reject_reserved_universal_tags
+                            && nodes.last().is_some_and(ParserNode::is_end_marker)
+                        {
+                            return Err(end_of_contents_misplaced());
+                        }
                     }
                 } else {
                     nodes.push(ParserNode {
@@ -221,7 +316,7 @@ impl ParseResult {
                             line!(),
                         ));
                     }
-                    Self::_parse_node(data, rules, depth + 1, nodes, node_count)?;
+                    Self::_parse_node(data, rules, options, depth + 1, nodes, node_count, node_start)?;
                     let found_end_marker =
                         matches!(nodes.last(), Some(node) if node.is_end_marker());
                     if found_end_marker {
@@ -240,6 +335,92 @@ impl ParseResult {
     }
 }
 
+fn end_of_contents_misplaced() -> ASN1Error {
+    ASN1Error::new(
+        ErrorCode::InvalidASN1Object,
+        "End-of-contents marker outside an indefinite-length encoding".to_string(),
+        file!().to_string(),
+        line!(),
+    )
+}
+
+/// Parses a single ASN.1 document under the given encoding rules.
+///
+/// This is what [`crate::der::parse`] and [`crate::ber::parse`] do with their rules fixed.
+pub fn parse_with_rules(data: &[u8], rules: EncodingRules) -> Result<ASN1Node, ASN1Error> {
+    parse_with_options(data, rules, ParseOptions::default())
+}
+
+/// Parses a single ASN.1 document under the given encoding rules with extra checks enabled.
+pub fn parse_with_options(
+    data: &[u8],
+    rules: EncodingRules,
+    options: ParseOptions,
+) -> Result<ASN1Node, ASN1Error> {
+    parse_bytes_with_options(Bytes::copy_from_slice(data), rules, options)
+}
+
+pub(crate) fn parse_bytes_with_rules(bytes: Bytes, rules: EncodingRules) -> Result<ASN1Node, ASN1Error> {
+    parse_bytes_with_options(bytes, rules, ParseOptions::default())
+}
+
+fn parse_bytes_with_options(
+    bytes: Bytes,
+    rules: EncodingRules,
+    options: ParseOptions,
+) -> Result<ASN1Node, ASN1Error> {
+    let result = ParseResult::parse_with_options(bytes, rules, options)?;
+
+    let first = result
+        .nodes
+        .first()
+        .ok_or_else(|| {
+            ASN1Error::new(
+                ErrorCode::InvalidASN1Object,
+                "No ASN.1 nodes parsed".to_string(),
+                file!().to_string(),
+                line!(),
+            )
+        })?
+        .clone();
+
+    let nodes_arc = Arc::new(result.nodes);
+    let root_depth = first.depth;
+
+    // Verify single root
+    let end_index = nodes_arc
+        .iter()
+        .enumerate()
+        .skip(1)
+        .find(|(_, node)| node.depth <= root_depth)
+        .map(|(idx, _)| idx)
+        .unwrap_or(nodes_arc.len());
+
+    if end_index != nodes_arc.len() {
+        return Err(ASN1Error::new(
+            ErrorCode::InvalidASN1Object,
+            "ASN1ParseResult unexpectedly allowed multiple root nodes".to_string(),
+            file!().to_string(),
+            line!(),
+        ));
+    }
+
+    if first.is_constructed {
+        let collection = ASN1NodeCollection::new(nodes_arc, 1..end_index, root_depth);
+        Ok(ASN1Node {
+            identifier: first.identifier,
+            content: Content::Constructed(collection),
+            encoded_bytes: first.encoded_bytes,
+        })
+    } else {
+        Ok(ASN1Node {
+            identifier: first.identifier,
+            content: Content::Primitive(first.data_bytes.unwrap()),
+            encoded_bytes: first.encoded_bytes,
+        })
+    }
+}
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 enum ASN1Length {
     Indefinite,
@@ -312,6 +493,68 @@ fn _read_asn1_length(data: &mut Bytes, minimal_encoding: bool) -> Result<ASN1Len
     }
 }
 
+/// Converts a decoded field length to `usize`.
+///
+/// Lengths are decoded as `u64`, so on 32-bit targets a length of 4 GiB or more cannot
+/// be represented and is rejected rather than truncated.
+fn checked_field_length(length: u64) -> Result<usize, ASN1Error> {
+    checked_field_length_within(length, usize::MAX)
+}
+
+fn checked_field_length_within(length: u64, limit: usize) -> Result<usize, ASN1Error> {
+    usize::try_from(length)
+        .ok()
+        .filter(|length| *length <= limit)
+        .ok_or_else(|| {
+            ASN1Error::new(
+                ErrorCode::UnsupportedFieldLength,
+                format!("Field length {} exceeds platform address space", length),
+                file!().to_string(),
+                line!(),
+            )
+        })
+}
+
+/// Returns the total encoded length of the first definite-length TLV in `data`,
+/// reading only its identifier and length octets.
+pub(crate) fn first_node_encoded_length(data: &Bytes) -> Result<usize, ASN1Error> {
+    let mut cursor = data.clone();
+    if cursor.is_empty() {
+        return Err(ASN1Error::new(
+            ErrorCode::TruncatedASN1Field,
+            "".to_string(),
+            file!().to_string(),
+            line!(),
+        ));
+    }
+    let raw_identifier = cursor.split_to(1)[0];
+    if (raw_identifier & 0x1f) == 0x1f {
+        read_asn1_discipline_uint(&mut cursor)?;
+    }
+    match _read_asn1_length(&mut cursor, true)? {
+        ASN1Length::Definite(length) => {
+            let header_len = data.len() - cursor.len();
+            checked_field_length(length)?
+                .checked_add(header_len)
+                .filter(|total| *total <= data.len())
+                .ok_or_else(|| {
+                    ASN1Error::new(
+                        ErrorCode::TruncatedASN1Field,
+                        "".to_string(),
+                        file!().to_string(),
+                        line!(),
+                    )
+                })
+        }
+        ASN1Length::Indefinite => Err(ASN1Error::new(
+            ErrorCode::UnsupportedFieldLength,
+            "Indefinite form of field length not supported in DER.".to_string(),
+            file!().to_string(),
+            line!(),
+        )),
+    }
+}
+
 fn read_asn1_discipline_uint(data: &mut Bytes) -> Result<(u64, usize), ASN1Error> {
     // Base 128
     let mut value: u64 = 0;
@@ -363,6 +606,26 @@ impl ASN1NodeCollection {
             depth,
         }
     }
+
+    /// Iterates over the direct children without consuming the collection.
+    ///
+    /// Nodes share the parsed buffers, so this is as cheap as iterating by value.
+    pub fn iter(&self) -> ASN1NodeCollectionIterator {
+        ASN1NodeCollectionIterator {
+            nodes: self.nodes.clone(),
+            range: self.range.clone(),
+            _depth: self.depth,
+        }
+    }
+}
+
+impl IntoIterator for &ASN1NodeCollection {
+    type Item = ASN1Node;
+    type IntoIter = ASN1NodeCollectionIterator;
+
+    fn into_iter(self) -> Self::IntoIter {
+        self.iter()
+    }
 }
 
 impl IntoIterator for ASN1NodeCollection {
@@ -448,6 +711,24 @@ impl Iterator for ASN1NodeCollectionIterator {
     }
 }
 
+impl DoubleEndedIterator for ASN1NodeCollectionIterator {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.range.start >= self.range.end {
+            return None;
+        }
+        // The first remaining node is always a direct child, so its depth identifies the
+        // start of the last direct child when scanning backwards.
+        let child_depth = self.nodes[self.range.start].depth;
+        let index = (self.range.start..self.range.end)
+            .rev()
+            .find(|&index| self.nodes[index].depth <= child_depth)
+            .expect("invariant: range.start is a direct child");
+        let end_index = self.range.end;
+        self.range.end = index;
+        Some(self.clone_node(index, end_index))
+    }
+}
+
 #[derive(Debug, Clone)]
 pub struct ASN1Node {
     pub identifier: ASN1Identifier,
@@ -459,6 +740,51 @@ impl ASN1Node {
     pub fn is_constructed(&self) -> bool {
         matches!(self.content, Content::Constructed(_))
     }
+
+    /// Describes the node tree as JSON for diagnostics.
+    ///
+    /// Every node becomes an object with `tag`, `class` and `constructed` keys, plus
+    /// `name` for assigned universal tags. Primitive nodes carry their content as a
+    /// lowercase hex string under `content`; constructed nodes list their `children`.
+    /// OBJECT IDENTIFIER nodes also carry their dotted form under `oid`, and with the
+    /// `oid-names` feature the short name of well-known OIDs under `oid_name`.
+    #[cfg(feature = "serde_json")]
+    pub fn to_json_value(&self) -> serde_json::Value {
+        let mut object = serde_json::Map::new();
+        object.insert("tag".to_string(), self.identifier.tag_number.into());
+        object.insert(
+            "class".to_string(),
+            format!("{:?}", self.identifier.tag_class).into(),
+        );
+        if let Some(tag) = self.identifier.universal_tag() {
+            object.insert("name".to_string(), format!("{:?}", tag).into());
+        }
+        object.insert("constructed".to_string(), self.is_constructed().into());
+        match &self.content {
+            Content::Primitive(bytes) => {
+                let hex: String = bytes.iter().map(|b| format!("{:02x}", b)).collect();
+                object.insert("content".to_string(), hex.into());
+                if self.identifier == ASN1Identifier::OBJECT_IDENTIFIER
+                    && let Ok(oid) = <crate::asn1_types::ASN1ObjectIdentifier as crate::ber::BERParseable>::from_ber_node(self.clone())
+                {
+                    object.insert("oid".to_string(), oid.to_string().into());
+                    #[cfg(feature = "oid-names")]
+                    if let Some(name) = oid.name() {
+                        object.insert("oid_name".to_string(), name.into());
+                    }
+                }
+            }
+            Content::Constructed(collection) => {
+                let children = collection
+                    .clone()
+                    .into_iter()
+                    .map(|child| child.to_json_value())
+                    .collect();
+                object.insert("children".to_string(), serde_json::Value::Array(children));
+            }
+        }
+        serde_json::Value::Object(object)
+    }
 }
 
 #[derive(Debug, Clone)]
@@ -470,7 +796,7 @@ pub enum Content {
 #[cfg(test)]
 mod tests {
     use super::*;
-    use bytes::BytesMut;
+    use crate::buffer::BytesMut;
     use std::sync::Arc;
 
     #[test]
@@ -738,10 +1064,27 @@ mod tests {
     #[test]
     fn test_der_allows_long_form_for_length_128() {
         let mut payload = BytesMut::from(&[0x04, 0x81, 0x80][..]);
-        payload.extend_from_slice(&vec![0u8; 128]);
+        payload.extend_from_slice(&[0u8; 128]);
         assert!(ParseResult::parse(payload.freeze(), EncodingRules::Distinguished).is_ok());
     }
 
+    #[test]
+    fn test_first_node_encoded_length() {
+        let data = Bytes::from(vec![0x02, 0x01, 0x05, 0x30, 0x00]);
+        assert_eq!(super::first_node_encoded_length(&data).unwrap(), 3);
+
+        let long_tag = Bytes::from(vec![0x1F, 0x81, 0x00, 0x01, 0xFF]);
+        assert_eq!(super::first_node_encoded_length(&long_tag).unwrap(), 5);
+
+        let truncated = Bytes::from(vec![0x04, 0x05, 0x00]);
+        let err = super::first_node_encoded_length(&truncated).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TruncatedASN1Field);
+
+        let indefinite = Bytes::from(vec![0x30, 0x80, 0x00, 0x00]);
+        let err = super::first_node_encoded_length(&indefinite).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnsupportedFieldLength);
+    }
+
     #[test]
     fn test_read_asn1_discipline_uint_multi_byte() {
         let mut data = Bytes::from(vec![0x81, 0x01]);
@@ -805,6 +1148,27 @@ mod tests {
         assert!(data.is_empty());
     }
 
+    #[test]
+    fn test_parse_result_public_view() {
+        // SEQUENCE { INTEGER 1, SEQUENCE { NULL } }
+        let data = vec![0x30, 0x07, 0x02, 0x01, 0x01, 0x30, 0x02, 0x05, 0x00];
+        let res = ParseResult::parse(Bytes::from(data), EncodingRules::Distinguished).unwrap();
+
+        let depths: Vec<usize> = res.nodes().iter().map(|n| n.depth()).collect();
+        assert_eq!(depths, vec![1, 2, 2, 3]);
+        assert!(res.nodes()[0].is_constructed());
+        assert!(res.nodes()[0].data_bytes().is_none());
+        assert_eq!(res.nodes()[1].identifier(), ASN1Identifier::INTEGER);
+        assert_eq!(res.nodes()[1].data_bytes().unwrap().as_ref(), &[0x01]);
+        assert_eq!(res.nodes()[2].encoded_bytes().as_ref(), &[0x30, 0x02, 0x05, 0x00]);
+
+        assert_eq!(res.encoded_span(0), Some(0..9));
+        assert_eq!(res.encoded_span(1), Some(2..5));
+        assert_eq!(res.encoded_span(2), Some(5..9));
+        assert_eq!(res.encoded_span(3), Some(7..9));
+        assert_eq!(res.encoded_span(4), None);
+    }
+
     #[test]
     fn test_node_collection_iterator_yields_children_in_order() {
         fn bytes(data: &[u8]) -> Bytes {
@@ -868,6 +1232,51 @@ mod tests {
         assert!(iter.next().is_none());
     }
 
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_node_to_json_value_describes_oids() {
+        let node = crate::der::parse(&[0x06, 0x03, 0x55, 0x04, 0x03]).unwrap();
+        let json = node.to_json_value();
+        assert_eq!(json["oid"], "2.5.4.3");
+        #[cfg(feature = "oid-names")]
+        assert_eq!(json["oid_name"], "commonName");
+
+        // Content that is not a valid OID is still shown as hex.
+        let node = crate::der::parse(&[0x06, 0x01, 0x80]).unwrap();
+        let json = node.to_json_value();
+        assert_eq!(json["content"], "80");
+        assert!(json.get("oid").is_none());
+    }
+
+    #[cfg(feature = "serde_json")]
+    #[test]
+    fn test_node_to_json_value() {
+        // SEQUENCE { INTEGER 1, [0] { OCTET STRING 0xAB } }
+        let data = [0x30, 0x08, 0x02, 0x01, 0x01, 0xA0, 0x03, 0x04, 0x01, 0xAB];
+        let node = crate::der::parse(&data).unwrap();
+        let json = node.to_json_value();
+        assert_eq!(
+            json,
+            serde_json::json!({
+                "tag": 16,
+                "class": "Universal",
+                "name": "Sequence",
+                "constructed": true,
+                "children": [
+                    {"tag": 2, "class": "Universal", "name": "Integer", "constructed": false, "content": "01"},
+                    {
+                        "tag": 0,
+                        "class": "ContextSpecific",
+                        "constructed": true,
+                        "children": [
+                            {"tag": 4, "class": "Universal", "name": "OctetString", "constructed": false, "content": "ab"}
+                        ]
+                    }
+                ]
+            })
+        );
+    }
+
     #[test]
     #[should_panic(expected = "invariant: primitive nodes have data_bytes")]
     fn test_panic_invariant_violation() {
@@ -880,7 +1289,7 @@ mod tests {
         }]);
 
         let mut iter = ASN1NodeCollectionIterator {
-            nodes: nodes,
+            nodes,
             range: 0..1,
             _depth: 0,
         };
@@ -888,4 +1297,122 @@ mod tests {
         // This call should trigger the panic
         iter.next();
     }
+
+    #[cfg(feature = "debug-errors")]
+    #[test]
+    fn test_parse_error_includes_byte_context() {
+        // The nested INTEGER claims two content bytes but only one is present.
+        let data = Bytes::from_static(&[0x30, 0x06, 0x01, 0x01, 0xFF, 0x02, 0x02, 0x01]);
+        let err = ParseResult::parse(data, EncodingRules::Distinguished).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TruncatedASN1Field);
+        assert_eq!(err.context(), Some("offset 5: 30 06 01 01 FF >02 02 01"));
+        assert!(err.to_string().ends_with("(offset 5: 30 06 01 01 FF >02 02 01)"));
+
+        let mut long = vec![0x04, 0x81, 0x80];
+        long.extend_from_slice(&[0xAA; 0x7F]);
+        let err = ParseResult::parse(Bytes::from(long), EncodingRules::Distinguished).unwrap_err();
+        assert_eq!(err.context(), Some("offset 0: >04 81 80 AA AA AA AA AA AA AA AA AA AA AA AA AA .."));
+    }
+
+    #[test]
+    fn test_error_context_absent_for_constructed_errors() {
+        let err = crate::asn1_err!(ErrorCode::InvalidASN1Object, "constructed directly");
+        assert_eq!(err.context(), None);
+    }
+
+    #[test]
+    fn test_borrowed_collection_iteration() {
+        let data = Bytes::from_static(&[0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02]);
+        let result = ParseResult::parse(data, EncodingRules::Distinguished).unwrap();
+        let collection = ASN1NodeCollection::new(Arc::new(result.nodes), 1..3, 1);
+
+        let mut first_pass = 0;
+        for node in &collection {
+            assert_eq!(node.identifier, ASN1Identifier::INTEGER);
+            first_pass += 1;
+        }
+        let second_pass: Vec<_> = collection.iter().map(|n| n.encoded_bytes).collect();
+
+        assert_eq!(first_pass, 2);
+        assert_eq!(second_pass[1].as_ref(), &[0x02, 0x01, 0x02]);
+        assert_eq!(collection.into_iter().count(), 2);
+    }
+
+    #[test]
+    fn test_parse_with_rules() {
+        let indefinite = [0x30, 0x80, 0x02, 0x01, 0x01, 0x00, 0x00];
+        let node = parse_with_rules(&indefinite, EncodingRules::Basic).unwrap();
+        assert!(node.is_constructed());
+        assert_eq!(node.encoded_bytes.as_ref(), &indefinite);
+        let err = parse_with_rules(&indefinite, EncodingRules::Distinguished).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnsupportedFieldLength);
+
+        let node = parse_with_rules(&[0x02, 0x01, 0x05], EncodingRules::Distinguished).unwrap();
+        assert!(matches!(node.content, Content::Primitive(ref b) if b.as_ref() == [0x05]));
+    }
+
+    #[test]
+    fn test_field_length_conversion_with_32_bit_limit() {
+        let limit = u32::MAX as usize;
+        assert_eq!(checked_field_length_within(u32::MAX as u64, limit).unwrap(), limit);
+        let err = checked_field_length_within(u32::MAX as u64 + 1, limit).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnsupportedFieldLength);
+
+        // A five-byte length only fits on 64-bit targets; either way it is never truncated.
+        let data = Bytes::from_static(&[0x04, 0x85, 0x01, 0x00, 0x00, 0x00, 0x00]);
+        let err = ParseResult::parse(data, EncodingRules::Distinguished).unwrap_err();
+        if cfg!(target_pointer_width = "32") {
+            assert_eq!(err.code(), ErrorCode::UnsupportedFieldLength);
+        } else {
+            assert_eq!(err.code(), ErrorCode::TruncatedASN1Field);
+        }
+    }
+
+    #[test]
+    fn test_parse_options_reject_reserved_universal_tags() {
+        let strict = ParseOptions { reject_reserved_universal_tags: true };
+        let rejected: [&[u8]; 6] = [
+            &[0x0F, 0x00],                   // tag 15
+            &[0x30, 0x02, 0x0F, 0x00],       // nested tag 15
+            &[0x1F, 0x25, 0x00],             // tag 37
+            &[0x00, 0x00],                   // EOC as the document
+            &[0x30, 0x02, 0x00, 0x00],       // EOC inside definite length
+            &[0x00, 0x01, 0x00],             // tag 0 with content
+        ];
+        for data in rejected {
+            let err = parse_with_options(data, EncodingRules::Basic, strict).unwrap_err();
+            assert_eq!(err.code(), ErrorCode::InvalidASN1Object, "{:02X?}", data);
+            assert!(parse_with_rules(data, EncodingRules::Basic).is_ok(), "{:02X?}", data);
+        }
+
+        // EOC terminating an indefinite length is still fine.
+        let indefinite = [0x30, 0x80, 0x1F, 0x24, 0x00, 0x00, 0x00];
+        assert!(parse_with_options(&indefinite, EncodingRules::Basic, strict).is_ok());
+        assert!(parse_with_options(&[0x02, 0x01, 0x00], EncodingRules::Distinguished, strict).is_ok());
+    }
+
+    #[test]
+    fn test_node_collection_iterator_double_ended() {
+        // SEQUENCE { INTEGER 1, SEQUENCE { INTEGER 2 }, INTEGER 3 }
+        let data = Bytes::from_static(&[
+            0x30, 0x0B, 0x02, 0x01, 0x01, 0x30, 0x03, 0x02, 0x01, 0x02, 0x02, 0x01, 0x03,
+        ]);
+        let node = parse_bytes_with_rules(data, EncodingRules::Distinguished).unwrap();
+        let Content::Constructed(collection) = node.content else {
+            panic!("expected constructed node");
+        };
+
+        let reversed: Vec<_> = collection.iter().rev().map(|n| n.encoded_bytes).collect();
+        assert_eq!(reversed[0].as_ref(), &[0x02, 0x01, 0x03]);
+        assert_eq!(reversed[1].as_ref(), &[0x30, 0x03, 0x02, 0x01, 0x02]);
+        assert_eq!(reversed[2].as_ref(), &[0x02, 0x01, 0x01]);
+
+        let mut iter = collection.iter();
+        assert_eq!(iter.next_back().unwrap().encoded_bytes.as_ref(), &[0x02, 0x01, 0x03]);
+        let middle = iter.next_back().unwrap();
+        assert!(matches!(middle.content, Content::Constructed(ref c) if c.iter().count() == 1));
+        assert_eq!(iter.next().unwrap().encoded_bytes.as_ref(), &[0x02, 0x01, 0x01]);
+        assert!(iter.next().is_none());
+        assert!(iter.next_back().is_none());
+    }
 }
@@ -0,0 +1,182 @@
+use crate::asn1_types::ASN1Identifier;
+use crate::asn1::{ASN1Node, EncodingRules};
+use crate::errors::ASN1Error;
+use crate::der::{DERParseable, DERSerializable, Serializer};
+use crate::ber::{BERParseable, BERSerializable};
+use crate::buffer::Bytes;
+use std::hash::{Hash, Hasher};
+
+/// An arbitrary ASN.1 value captured as-is, for open types and fields whose decoding is
+/// deferred until the surrounding context is known.
+///
+/// Serializing re-emits the original encoding byte for byte. Equality and hashing compare
+/// encodings.
+#[derive(Debug, Clone)]
+pub struct ASN1Any {
+    node: ASN1Node,
+    rules: EncodingRules,
+}
+
+impl ASN1Any {
+    /// Captures the DER encoding of `value`.
+    pub fn new<T: DERSerializable + ?Sized>(value: &T) -> Result<Self, ASN1Error> {
+        let mut serializer = Serializer::new();
+        serializer.serialize(value)?;
+        let node = crate::asn1::parse_bytes_with_rules(serializer.serialized_bytes(), EncodingRules::Distinguished)?;
+        Ok(ASN1Any { node, rules: EncodingRules::Distinguished })
+    }
+
+    pub fn identifier(&self) -> ASN1Identifier {
+        self.node.identifier
+    }
+
+    /// The complete encoding (identifier, length and contents) as it was captured.
+    pub fn encoded_bytes(&self) -> &Bytes {
+        &self.node.encoded_bytes
+    }
+
+    /// Decodes the value as `T` under DER. Shorthand for [`decode_as`](Self::decode_as).
+    pub fn decode<T: DERParseable>(&self) -> Result<T, ASN1Error> {
+        self.decode_as()
+    }
+
+    /// Decodes the value as `T` under DER, typically once the type of an open-type field is
+    /// known from its surrounding context. The captured node is decoded directly; the
+    /// top-level parse functions are not re-entered.
+    ///
+    /// A value captured by a BER parse is re-checked against the DER encoding rules first,
+    /// so non-DER lengths are rejected even though the outer document accepted them.
+    pub fn decode_as<T: DERParseable>(&self) -> Result<T, ASN1Error> {
+        match self.rules {
+            EncodingRules::Distinguished => T::from_der_node(self.node.clone()),
+            EncodingRules::Basic => T::from_der_node(crate::asn1::parse_bytes_with_rules(
+                self.node.encoded_bytes.clone(),
+                EncodingRules::Distinguished,
+            )?),
+        }
+    }
+
+    /// Decodes the value as `T` under BER, whichever rules it was captured with.
+    pub fn decode_as_ber<T: BERParseable>(&self) -> Result<T, ASN1Error> {
+        T::from_ber_node(self.node.clone())
+    }
+}
+
+impl PartialEq for ASN1Any {
+    fn eq(&self, other: &Self) -> bool {
+        self.node.encoded_bytes == other.node.encoded_bytes
+    }
+}
+
+impl Eq for ASN1Any {}
+
+impl Hash for ASN1Any {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.node.encoded_bytes.hash(state)
+    }
+}
+
+impl From<ASN1Node> for ASN1Any {
+    /// Wraps an already parsed node, which is assumed to satisfy DER.
+    fn from(node: ASN1Node) -> Self {
+        ASN1Any { node, rules: EncodingRules::Distinguished }
+    }
+}
+
+impl DERParseable for ASN1Any {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Ok(ASN1Any { node, rules: EncodingRules::Distinguished })
+    }
+}
+
+impl DERSerializable for ASN1Any {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        self.node.serialize(serializer)
+    }
+}
+
+impl BERParseable for ASN1Any {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Ok(ASN1Any { node, rules: EncodingRules::Basic })
+    }
+}
+impl BERSerializable for ASN1Any {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_types::{ASN1Integer, ASN1Null, ASN1ObjectIdentifier, ASN1OctetString};
+    use crate::ber;
+    use crate::der;
+    use crate::errors::ErrorCode;
+
+    #[test]
+    fn test_any_captures_field_for_later_decoding() {
+        // SEQUENCE { OID 2.5.4.3, UTF8String "hi" }
+        let data = [0x30, 0x09, 0x06, 0x03, 0x55, 0x04, 0x03, 0x0C, 0x02, 0x68, 0x69];
+        let (any, after) = der::sequence(der::parse(&data).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            let _ = iter.next();
+            let any = ASN1Any::from_der_iterator(iter)?;
+            Ok((any, iter.next()))
+        })
+        .unwrap();
+        assert!(after.is_none());
+        assert_eq!(any.identifier(), ASN1Identifier::UTF8_STRING);
+        assert_eq!(any.encoded_bytes().as_ref(), &data[7..]);
+        #[cfg(feature = "strings")]
+        assert_eq!(any.decode::<String>().unwrap(), "hi");
+        #[cfg(feature = "strings")]
+        assert_eq!(any.decode_as::<String>().unwrap(), "hi");
+        let err = any.decode_as::<ASN1OctetString>().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&any).unwrap();
+        assert_eq!(serializer.serialized_bytes().as_ref(), &data[7..]);
+    }
+
+    #[test]
+    fn test_any_new_and_equality() {
+        let any = ASN1Any::new(&ASN1Integer::from(300)).unwrap();
+        assert_eq!(any.encoded_bytes().as_ref(), &[0x02, 0x02, 0x01, 0x2C]);
+        assert_eq!(any.decode::<i64>().unwrap(), 300);
+        assert_eq!(any, ASN1Any::from(der::parse(&[0x02, 0x02, 0x01, 0x2C]).unwrap()));
+        assert_ne!(any, ASN1Any::new(&301i64).unwrap());
+    }
+
+    #[test]
+    fn test_any_decode_as_open_type() {
+        // AlgorithmIdentifier { OID 1.2.840.10045.2.1, parameters OID 1.2.840.10045.3.1.7 }, with
+        // the parameters captured until the algorithm is known.
+        let data = [
+            0x30, 0x13, 0x06, 0x07, 0x2A, 0x86, 0x48, 0xCE, 0x3D, 0x02, 0x01, 0x06, 0x08, 0x2A, 0x86, 0x48,
+            0xCE, 0x3D, 0x03, 0x01, 0x07,
+        ];
+        let (algorithm, parameters) = der::sequence(der::parse(&data).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            Ok((ASN1ObjectIdentifier::from_der_iterator(iter)?, ASN1Any::from_der_iterator(iter)?))
+        })
+        .unwrap();
+        assert_eq!(algorithm, ASN1ObjectIdentifier::new(&[1, 2, 840, 10045, 2, 1]).unwrap());
+        let curve = parameters.decode_as::<ASN1ObjectIdentifier>().unwrap();
+        assert_eq!(curve, ASN1ObjectIdentifier::new(&[1, 2, 840, 10045, 3, 1, 7]).unwrap());
+        assert_eq!(parameters.decode_as_ber::<ASN1ObjectIdentifier>().unwrap(), curve);
+
+        // The capture is reusable after a failed downcast.
+        assert_eq!(parameters.decode_as::<ASN1Null>().unwrap_err().code(), ErrorCode::UnexpectedFieldType);
+        assert_eq!(parameters.decode_as_ber::<ASN1Integer>().unwrap_err().code(), ErrorCode::UnexpectedFieldType);
+        assert_eq!(parameters.decode_as::<ASN1ObjectIdentifier>().unwrap(), curve);
+    }
+
+    #[test]
+    fn test_any_ber_capture_keeps_encoding_and_rechecks_der() {
+        // Indefinite-length constructed OCTET STRING.
+        let data = [0x24, 0x80, 0x04, 0x01, 0xAA, 0x04, 0x01, 0xBB, 0x00, 0x00];
+        let any = ASN1Any::from_ber_node(ber::parse(&data).unwrap()).unwrap();
+        assert_eq!(any.decode_as_ber::<ASN1OctetString>().unwrap(), ASN1OctetString::from(vec![0xAA, 0xBB]));
+        assert!(any.decode_as::<ASN1OctetString>().is_err());
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&any).unwrap();
+        assert_eq!(serializer.serialized_bytes().as_ref(), &data);
+    }
+}
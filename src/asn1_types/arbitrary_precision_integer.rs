@@ -0,0 +1,207 @@
+use crate::asn1::ASN1Node;
+use crate::asn1_types::enumerated::has_redundant_leading_octet;
+use crate::asn1_types::{ASN1Identifier, ASN1Integer};
+use crate::ber::{BERImplicitlyTaggable, BERParseable, BERSerializable};
+use crate::buffer::Bytes;
+use crate::der::{decode_i128, decode_u128, integer_content, DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
+use crate::errors::{ASN1Error, ErrorCode};
+use crate::asn1_err;
+
+/// An INTEGER kept as its minimal big-endian two's complement content octets.
+///
+/// Decoding shares the octets with the input instead of building a number, so key material
+/// and other large integers cost no allocation and lose nothing; conversion to `BigInt` or
+/// a machine integer happens only when asked for.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ArbitraryPrecisionInteger(Bytes);
+
+impl ArbitraryPrecisionInteger {
+    /// The integer encoded by big-endian two's complement `bytes`. Redundant leading
+    /// octets are sliced off without copying, and no bytes is zero.
+    pub fn from_signed_bytes_be(bytes: Bytes) -> Self {
+        if bytes.is_empty() {
+            return ArbitraryPrecisionInteger(Bytes::from_static(&[0x00]));
+        }
+        let mut start = 0;
+        while has_redundant_leading_octet(&bytes[start..]) {
+            start += 1;
+        }
+        ArbitraryPrecisionInteger(bytes.slice(start..))
+    }
+
+    /// The minimal big-endian two's complement encoding, as it appears in DER.
+    pub fn as_bytes(&self) -> &Bytes {
+        &self.0
+    }
+
+    pub fn into_bytes(self) -> Bytes {
+        self.0
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.0[0] & 0x80 != 0
+    }
+}
+
+macro_rules! impl_primitive_conversions {
+    ($($ty:ty => $decode:ident),+ $(,)?) => {
+        $(
+            impl From<$ty> for ArbitraryPrecisionInteger {
+                fn from(v: $ty) -> Self {
+                    // A spare sign octet keeps unsigned values positive.
+                    let value = v.to_be_bytes();
+                    let mut octets = [if v < (0 as $ty) { 0xFF } else { 0x00 }; 17];
+                    octets[17 - value.len()..].copy_from_slice(&value);
+                    ArbitraryPrecisionInteger::from_signed_bytes_be(Bytes::copy_from_slice(&octets[16 - value.len()..]))
+                }
+            }
+
+            impl TryFrom<&ArbitraryPrecisionInteger> for $ty {
+                type Error = ASN1Error;
+
+                fn try_from(v: &ArbitraryPrecisionInteger) -> Result<Self, ASN1Error> {
+                    $decode(&v.0)
+                        .and_then(|value| <$ty>::try_from(value).ok())
+                        .ok_or_else(|| asn1_err!(ErrorCode::ValueOutOfRange, concat!("ArbitraryPrecisionInteger does not fit into ", stringify!($ty))))
+                }
+            }
+        )+
+    };
+}
+
+impl_primitive_conversions!(
+    i8 => decode_i128,
+    i16 => decode_i128,
+    i32 => decode_i128,
+    i64 => decode_i128,
+    i128 => decode_i128,
+    isize => decode_i128,
+    u8 => decode_u128,
+    u16 => decode_u128,
+    u32 => decode_u128,
+    u64 => decode_u128,
+    u128 => decode_u128,
+    usize => decode_u128,
+);
+
+#[cfg(feature = "num-bigint")]
+impl From<&num_bigint::BigInt> for ArbitraryPrecisionInteger {
+    fn from(v: &num_bigint::BigInt) -> Self {
+        ArbitraryPrecisionInteger(Bytes::from(v.to_signed_bytes_be()))
+    }
+}
+
+#[cfg(feature = "num-bigint")]
+impl From<&ArbitraryPrecisionInteger> for num_bigint::BigInt {
+    fn from(v: &ArbitraryPrecisionInteger) -> Self {
+        num_bigint::BigInt::from_signed_bytes_be(&v.0)
+    }
+}
+
+impl From<&ASN1Integer> for ArbitraryPrecisionInteger {
+    fn from(v: &ASN1Integer) -> Self {
+        ArbitraryPrecisionInteger(Bytes::from(v.value.to_signed_bytes_be()))
+    }
+}
+
+impl From<&ArbitraryPrecisionInteger> for ASN1Integer {
+    fn from(v: &ArbitraryPrecisionInteger) -> Self {
+        ASN1Integer { value: crate::asn1_types::integer::IntegerValue::from_signed_bytes_be(&v.0) }
+    }
+}
+
+impl DERParseable for ArbitraryPrecisionInteger {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl DERSerializable for ArbitraryPrecisionInteger {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            buf.extend_from_slice(&self.0);
+            Ok(())
+        })
+    }
+}
+
+impl DERImplicitlyTaggable for ArbitraryPrecisionInteger {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::INTEGER
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        integer_content(node, identifier).map(ArbitraryPrecisionInteger)
+    }
+}
+
+impl BERParseable for ArbitraryPrecisionInteger {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_ber_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl BERSerializable for ArbitraryPrecisionInteger {}
+
+impl BERImplicitlyTaggable for ArbitraryPrecisionInteger {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        if node.identifier != identifier {
+            return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
+        }
+        match node.content {
+            crate::asn1::Content::Primitive(bytes) if bytes.is_empty() => {
+                Err(ASN1Error::new(ErrorCode::InvalidASN1Object, "Integer with 0 bytes".to_string(), file!().to_string(), line!()))
+            }
+            // BER allows redundant leading octets.
+            crate::asn1::Content::Primitive(bytes) => Ok(ArbitraryPrecisionInteger::from_signed_bytes_be(bytes)),
+            _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "Integer must be primitive".to_string(), file!().to_string(), line!())),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ber;
+    use crate::testing::assert_der_roundtrip;
+
+    #[test]
+    fn test_arbitrary_precision_integer_keeps_octets() {
+        // A 65-octet modulus-like value comes back octet for octet.
+        let mut modulus = vec![0x02, 0x41, 0x00];
+        modulus.extend(std::iter::repeat_n(0xC3, 64));
+        let value = ArbitraryPrecisionInteger::from_der_bytes(&modulus).unwrap();
+        assert_eq!(value.as_bytes().len(), 65);
+        assert!(!value.is_negative());
+        assert_eq!(assert_der_roundtrip(&value), modulus);
+        assert!(i128::try_from(&value).is_err());
+
+        let err = ArbitraryPrecisionInteger::from_der_bytes(&[0x02, 0x02, 0x00, 0x05]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1IntegerEncoding);
+        let err = ArbitraryPrecisionInteger::from_der_bytes(&[0x02, 0x00]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+
+        let node = ber::parse(&[0x02, 0x03, 0xFF, 0xFF, 0x80]).unwrap();
+        let value = ArbitraryPrecisionInteger::from_ber_node(node).unwrap();
+        assert_eq!(value.as_bytes().as_ref(), &[0x80]);
+        assert_eq!(i8::try_from(&value).unwrap(), -128);
+    }
+
+    #[test]
+    fn test_arbitrary_precision_integer_conversions() {
+        assert_eq!(ArbitraryPrecisionInteger::from(255u8).as_bytes().as_ref(), &[0x00, 0xFF]);
+        assert_eq!(ArbitraryPrecisionInteger::from(-1i64).as_bytes().as_ref(), &[0xFF]);
+        assert_eq!(ArbitraryPrecisionInteger::from(0u32).as_bytes().as_ref(), &[0x00]);
+        assert_eq!(u64::try_from(&ArbitraryPrecisionInteger::from(u64::MAX)).unwrap(), u64::MAX);
+        assert_eq!(u64::try_from(&ArbitraryPrecisionInteger::from(-1)).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(i16::try_from(&ArbitraryPrecisionInteger::from(i32::MAX)).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(ArbitraryPrecisionInteger::from_signed_bytes_be(Bytes::new()), ArbitraryPrecisionInteger::from(0));
+
+        let integer = ASN1Integer::from(-300);
+        let value = ArbitraryPrecisionInteger::from(&integer);
+        assert_eq!(value.as_bytes().as_ref(), &[0xFE, 0xD4]);
+        assert_eq!(ASN1Integer::from(&value), integer);
+        #[cfg(feature = "num-bigint")]
+        assert_eq!(num_bigint::BigInt::from(&value), num_bigint::BigInt::from(-300));
+    }
+}
@@ -1,9 +1,10 @@
 use crate::asn1_types::ASN1Identifier;
-use crate::asn1::ASN1Node;
+use crate::asn1::{ASN1Node, EncodingRules};
 use crate::errors::{ASN1Error, ErrorCode};
 use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
 use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
-use bytes::Bytes;
+use crate::buffer::Bytes;
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ASN1BitString {
@@ -34,6 +35,243 @@ impl ASN1BitString {
         }
         Ok(ASN1BitString { bytes, padding_bits })
     }
+
+    /// The whole bytes spelled by `hex`, pairs of hex digits in either case, with no
+    /// padding bits.
+    pub fn from_hex(hex: &str) -> Result<Self, ASN1Error> {
+        let bytes = crate::asn1_types::octet_string::decode_hex(hex)?;
+        Ok(ASN1BitString { bytes: Bytes::from(bytes), padding_bits: 0 })
+    }
+
+    /// Packs `bits` most significant bit first, padding the last byte with zero bits.
+    pub fn from_bits(bits: impl IntoIterator<Item = bool>) -> Self {
+        let mut builder = BitStringBuilder::new();
+        for (index, bit) in bits.into_iter().enumerate() {
+            builder.set_bit(index, bit);
+        }
+        builder.build()
+    }
+
+    /// The first `len_bits` bits of `bytes`, which must be exactly as long as they need.
+    /// Bits past `len_bits` in the last byte are cleared.
+    pub fn from_bytes_with_bit_len(bytes: Bytes, len_bits: usize) -> Result<Self, ASN1Error> {
+        if bytes.len() != len_bits.div_ceil(8) {
+            return Err(ASN1Error::new(
+                ErrorCode::InvalidASN1Object,
+                format!("{} bits need {} bytes, got {}", len_bits, len_bits.div_ceil(8), bytes.len()),
+                file!().to_string(),
+                line!(),
+            ));
+        }
+        let padding_bits = (bytes.len() * 8 - len_bits) as u8;
+        let mask = 0xFFu8 << padding_bits;
+        let bytes = match bytes.last() {
+            Some(&last) if last & !mask != 0 => {
+                let mut cleared = bytes.to_vec();
+                *cleared.last_mut().unwrap() &= mask;
+                Bytes::from(cleared)
+            }
+            _ => bytes,
+        };
+        Ok(ASN1BitString { bytes, padding_bits })
+    }
+
+    /// Decodes the contents as a complete DER encoding of `T`, as for the public key in a
+    /// SubjectPublicKeyInfo. The string must be a whole number of bytes.
+    pub fn decode_inner_der<T: DERParseable>(&self) -> Result<T, ASN1Error> {
+        if self.padding_bits != 0 {
+            return Err(ASN1Error::new(
+                ErrorCode::InvalidASN1Object,
+                format!("BIT STRING with {} padding bits does not hold DER", self.padding_bits),
+                file!().to_string(),
+                line!(),
+            ));
+        }
+        T::from_der_node(crate::asn1::parse_bytes_with_rules(self.bytes.clone(), EncodingRules::Distinguished)?)
+    }
+
+    /// The number of bits in the string, excluding padding.
+    pub fn len_bits(&self) -> usize {
+        (self.bytes.len() * 8).saturating_sub(usize::from(self.padding_bits))
+    }
+
+    /// The bit at `index`, counting from the most significant bit of the first byte. Bits
+    /// past the end read as zero, as for named bit lists whose trailing zeros were trimmed.
+    pub fn bit(&self, index: usize) -> bool {
+        index < self.len_bits() && self.bytes[index / 8] & (0x80 >> (index % 8)) != 0
+    }
+
+    /// The bits in order, excluding padding.
+    pub fn bits(&self) -> impl Iterator<Item = bool> + '_ {
+        (0..self.len_bits()).map(move |i| self.bit(i))
+    }
+
+    /// The bits in `range`, counted in bits rather than bytes, as a new bit string.
+    ///
+    /// Panics if the range is out of bounds or decreasing, as slicing does.
+    pub fn slice(&self, range: impl std::ops::RangeBounds<usize>) -> ASN1BitString {
+        use std::ops::Bound;
+        let start = match range.start_bound() {
+            Bound::Included(&i) => i,
+            Bound::Excluded(&i) => i + 1,
+            Bound::Unbounded => 0,
+        };
+        let end = match range.end_bound() {
+            Bound::Included(&i) => i + 1,
+            Bound::Excluded(&i) => i,
+            Bound::Unbounded => self.len_bits(),
+        };
+        assert!(start <= end, "bit range starts at {} but ends at {}", start, end);
+        assert!(end <= self.len_bits(), "bit range end {} out of range for {} bits", end, self.len_bits());
+        ASN1BitString::from_bits((start..end).map(|i| self.bit(i)))
+    }
+
+    /// A builder starting from a copy of these bits.
+    pub fn to_builder(&self) -> BitStringBuilder {
+        let bytes = self.masked_bytes(0..self.bytes.len()).collect();
+        BitStringBuilder { bytes, len_bits: self.len_bits() }
+    }
+
+    /// The number of bits set to one. Padding bits are ignored.
+    pub fn count_ones(&self) -> usize {
+        self.masked_bytes(0..self.bytes.len()).map(|b| b.count_ones() as usize).sum()
+    }
+
+    /// Bitwise AND. The shorter operand is treated as extended with trailing zero bits, as
+    /// for named bit lists, and the result has the length of the longer operand.
+    pub fn and(&self, other: &ASN1BitString) -> ASN1BitString {
+        self.combine(other, |a, b| a & b)
+    }
+
+    /// Bitwise OR, aligning lengths as for [`ASN1BitString::and`].
+    pub fn or(&self, other: &ASN1BitString) -> ASN1BitString {
+        self.combine(other, |a, b| a | b)
+    }
+
+    /// Bitwise XOR, aligning lengths as for [`ASN1BitString::and`].
+    pub fn xor(&self, other: &ASN1BitString) -> ASN1BitString {
+        self.combine(other, |a, b| a ^ b)
+    }
+
+    /// Whether every bit set in `self` is also set in `other`, treating missing bits as zero.
+    pub fn is_subset_of(&self, other: &ASN1BitString) -> bool {
+        let len = self.bytes.len().max(other.bytes.len());
+        self.masked_bytes(0..len)
+            .zip(other.masked_bytes(0..len))
+            .all(|(a, b)| a & !b == 0)
+    }
+
+    /// The bytes in `range`, with padding bits cleared and zeros past the end.
+    fn masked_bytes(&self, range: std::ops::Range<usize>) -> impl Iterator<Item = u8> + '_ {
+        let last = self.bytes.len().checked_sub(1);
+        range.map(move |i| match self.bytes.get(i) {
+            Some(&b) if Some(i) == last => b & (0xFF << self.padding_bits),
+            Some(&b) => b,
+            None => 0,
+        })
+    }
+
+    fn combine(&self, other: &ASN1BitString, op: impl Fn(u8, u8) -> u8) -> ASN1BitString {
+        let len_bits = self.len_bits().max(other.len_bits());
+        let len = len_bits.div_ceil(8);
+        let padding_bits = (len * 8 - len_bits) as u8;
+        let mut bytes: Vec<u8> = self
+            .masked_bytes(0..len)
+            .zip(other.masked_bytes(0..len))
+            .map(|(a, b)| op(a, b))
+            .collect();
+        if let Some(last) = bytes.last_mut() {
+            *last &= 0xFF << padding_bits;
+        }
+        ASN1BitString { bytes: Bytes::from(bytes), padding_bits }
+    }
+}
+
+/// Formats the bytes as hex, with padding bits cleared.
+impl fmt::LowerHex for ASN1BitString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.masked_bytes(0..self.bytes.len()).try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::UpperHex for ASN1BitString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.masked_bytes(0..self.bytes.len()).try_for_each(|b| write!(f, "{:02X}", b))
+    }
+}
+
+impl std::str::FromStr for ASN1BitString {
+    type Err = ASN1Error;
+
+    /// Parses either binary digits, one per bit (`"0101110"`), or hex digit pairs followed
+    /// by `/` and the length in bits (`"5c/7"`), with bits past that length cleared.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| {
+            ASN1Error::new(ErrorCode::InvalidStringRepresentation, format!("Invalid BIT STRING {:?}: {}", s, reason), file!().to_string(), line!())
+        };
+        match s.split_once('/') {
+            Some((hex, len_bits)) => {
+                let bytes = crate::asn1_types::octet_string::decode_hex(hex)?;
+                if len_bits.is_empty() || !len_bits.bytes().all(|b| b.is_ascii_digit()) {
+                    return Err(invalid("expected a decimal bit length after /"));
+                }
+                let len_bits = len_bits.parse().map_err(|_| invalid("bit length too large"))?;
+                ASN1BitString::from_bytes_with_bit_len(Bytes::from(bytes), len_bits)
+                    .map_err(|_| invalid("bit length does not match the hex digits"))
+            }
+            None if s.bytes().all(|b| b == b'0' || b == b'1') => Ok(ASN1BitString::from_bits(s.bytes().map(|b| b == b'1'))),
+            None => Err(invalid("expected binary digits, or hex digits and a bit length")),
+        }
+    }
+}
+
+/// Builds an [`ASN1BitString`] bit by bit, growing as bits past the end are set.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BitStringBuilder {
+    bytes: Vec<u8>,
+    len_bits: usize,
+}
+
+impl BitStringBuilder {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// A builder holding `len_bits` zero bits.
+    pub fn with_len(len_bits: usize) -> Self {
+        BitStringBuilder { bytes: vec![0; len_bits.div_ceil(8)], len_bits }
+    }
+
+    pub fn len_bits(&self) -> usize {
+        self.len_bits
+    }
+
+    /// Sets the bit at `index`, counting from the most significant bit of the first byte,
+    /// and extends the string with zero bits if `index` is past the end.
+    pub fn set_bit(&mut self, index: usize, value: bool) -> &mut Self {
+        if index >= self.len_bits {
+            self.len_bits = index + 1;
+            self.bytes.resize(self.len_bits.div_ceil(8), 0);
+        }
+        let mask = 0x80 >> (index % 8);
+        if value {
+            self.bytes[index / 8] |= mask;
+        } else {
+            self.bytes[index / 8] &= !mask;
+        }
+        self
+    }
+
+    pub fn build(&self) -> ASN1BitString {
+        let padding_bits = (self.bytes.len() * 8 - self.len_bits) as u8;
+        ASN1BitString { bytes: Bytes::from(self.bytes.clone()), padding_bits }
+    }
 }
 
 impl DERParseable for ASN1BitString {
@@ -0,0 +1,102 @@
+use crate::asn1::ASN1Node;
+use crate::asn1_types::ASN1Identifier;
+use crate::der::{DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
+use crate::errors::{ASN1Error, ErrorCode};
+use std::fmt;
+
+/// An INTEGER with a value range subtype constraint, such as `INTEGER (0..255)` written
+/// `BoundedInteger<0, 255>`. Both bounds are inclusive.
+///
+/// Values outside the range are rejected with `ValueOutOfRange` on construction, on parse
+/// and on encode.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct BoundedInteger<const MIN: i128, const MAX: i128>(i128);
+
+impl<const MIN: i128, const MAX: i128> BoundedInteger<MIN, MAX> {
+    pub fn new(value: i128) -> Result<Self, ASN1Error> {
+        Self::check(value).map(BoundedInteger)
+    }
+
+    pub fn value(&self) -> i128 {
+        self.0
+    }
+
+    fn check(value: i128) -> Result<i128, ASN1Error> {
+        if (MIN..=MAX).contains(&value) {
+            Ok(value)
+        } else {
+            Err(ASN1Error::new(
+                ErrorCode::ValueOutOfRange,
+                format!("INTEGER {} is outside the constraint ({}..{})", value, MIN, MAX),
+                file!().to_string(),
+                line!(),
+            ))
+        }
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> TryFrom<i128> for BoundedInteger<MIN, MAX> {
+    type Error = ASN1Error;
+
+    fn try_from(value: i128) -> Result<Self, ASN1Error> {
+        Self::new(value)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> From<BoundedInteger<MIN, MAX>> for i128 {
+    fn from(v: BoundedInteger<MIN, MAX>) -> Self {
+        v.0
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> fmt::Display for BoundedInteger<MIN, MAX> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> DERParseable for BoundedInteger<MIN, MAX> {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> DERSerializable for BoundedInteger<MIN, MAX> {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        Self::check(self.0)?.serialize(serializer)
+    }
+}
+
+impl<const MIN: i128, const MAX: i128> DERImplicitlyTaggable for BoundedInteger<MIN, MAX> {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::INTEGER
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        // Encodings too wide for i128 are out of any range these bounds can express.
+        Self::new(i128::from_der_node_with_identifier(node, identifier)?)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::assert_der_roundtrip;
+
+    type Octet = BoundedInteger<0, 255>;
+
+    #[test]
+    fn test_bounded_integer_enforces_range() {
+        assert_eq!(assert_der_roundtrip(&Octet::new(255).unwrap()), vec![0x02, 0x02, 0x00, 0xFF]);
+        assert_eq!(Octet::from_der_bytes(&[0x02, 0x01, 0x00]).unwrap().value(), 0);
+        assert_eq!(Octet::new(256).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(Octet::try_from(-1).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(Octet::from_der_bytes(&[0x02, 0x02, 0x01, 0x00]).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(Octet::from_der_bytes(&[0x02, 0x01, 0xFF]).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(Octet::from_der_bytes(&[0x02, 0x02, 0x00, 0x01]).unwrap_err().code(), ErrorCode::InvalidASN1IntegerEncoding);
+
+        let negative = BoundedInteger::<-5, -1>::from_der_bytes(&[0x02, 0x01, 0xFD]).unwrap();
+        assert_eq!(i128::from(negative), -3);
+        assert_eq!(negative.to_string(), "-3");
+    }
+}
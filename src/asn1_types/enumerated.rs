@@ -0,0 +1,170 @@
+use crate::asn1_err;
+use crate::asn1_types::ASN1Identifier;
+use crate::asn1::ASN1Node;
+use crate::errors::{ASN1Error, ErrorCode};
+use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
+use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
+use std::fmt;
+
+/// An ENUMERATED value.
+///
+/// Enumerations name a handful of small values in practice, so the value is held as an
+/// `i64`; encodings that do not fit are rejected with `ValueOutOfRange`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ASN1Enumerated(pub i64);
+
+impl From<i64> for ASN1Enumerated {
+    fn from(v: i64) -> Self {
+        ASN1Enumerated(v)
+    }
+}
+
+impl From<ASN1Enumerated> for i64 {
+    fn from(v: ASN1Enumerated) -> Self {
+        v.0
+    }
+}
+
+impl fmt::Display for ASN1Enumerated {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.0, f)
+    }
+}
+
+/// Whether the first octet of a two's complement encoding only repeats the sign of the next.
+pub(crate) fn has_redundant_leading_octet(bytes: &[u8]) -> bool {
+    match bytes {
+        [0x00, next, ..] => next & 0x80 == 0,
+        [0xFF, next, ..] => next & 0x80 != 0,
+        _ => false,
+    }
+}
+
+/// Decodes two's complement `bytes`, which must already be free of redundant leading octets.
+fn decode_value(bytes: &[u8]) -> Result<i64, ASN1Error> {
+    if bytes.len() > 8 {
+        return Err(asn1_err!(ErrorCode::ValueOutOfRange, "ENUMERATED value does not fit into i64"));
+    }
+    let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+    let mut octets = [fill; 8];
+    octets[8 - bytes.len()..].copy_from_slice(bytes);
+    Ok(i64::from_be_bytes(octets))
+}
+
+fn primitive_content(node: ASN1Node, identifier: ASN1Identifier) -> Result<crate::buffer::Bytes, ASN1Error> {
+    if node.identifier != identifier {
+        return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
+    }
+    match node.content {
+        crate::asn1::Content::Primitive(bytes) if bytes.is_empty() => {
+            Err(ASN1Error::new(ErrorCode::InvalidASN1Object, "Enumerated with 0 bytes".to_string(), file!().to_string(), line!()))
+        }
+        crate::asn1::Content::Primitive(bytes) => Ok(bytes),
+        _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "Enumerated must be primitive".to_string(), file!().to_string(), line!())),
+    }
+}
+
+impl DERParseable for ASN1Enumerated {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, ASN1Enumerated::default_identifier())
+    }
+}
+
+impl DERSerializable for ASN1Enumerated {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            let bytes = self.0.to_be_bytes();
+            let mut start = 0;
+            while has_redundant_leading_octet(&bytes[start..]) {
+                start += 1;
+            }
+            buf.extend_from_slice(&bytes[start..]);
+            Ok(())
+        })
+    }
+}
+
+impl DERImplicitlyTaggable for ASN1Enumerated {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::ENUMERATED
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        let bytes = primitive_content(node, identifier)?;
+        // DER requires minimal encoding, exactly as for INTEGER.
+        if has_redundant_leading_octet(&bytes) {
+            return Err(ASN1Error::new(
+                ErrorCode::InvalidASN1IntegerEncoding,
+                "Enumerated encoded with redundant leading octet".to_string(),
+                file!().to_string(),
+                line!(),
+            ));
+        }
+        decode_value(&bytes).map(ASN1Enumerated)
+    }
+}
+
+impl BERParseable for ASN1Enumerated {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_ber_node_with_identifier(node, ASN1Enumerated::default_identifier())
+    }
+}
+
+impl BERSerializable for ASN1Enumerated {}
+
+impl BERImplicitlyTaggable for ASN1Enumerated {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        let bytes = primitive_content(node, identifier)?;
+        // BER allows redundant leading octets; drop them before the range check.
+        let mut start = 0;
+        while has_redundant_leading_octet(&bytes[start..]) {
+            start += 1;
+        }
+        decode_value(&bytes[start..]).map(ASN1Enumerated)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ber;
+    use crate::testing::assert_der_roundtrip;
+
+    #[test]
+    fn test_enumerated_minimal_encoding_roundtrip() {
+        for (value, content) in [
+            (0i64, &[0x00][..]),
+            (127, &[0x7F]),
+            (128, &[0x00, 0x80]),
+            (-1, &[0xFF]),
+            (-129, &[0xFF, 0x7F]),
+            (i64::MIN, &[0x80, 0, 0, 0, 0, 0, 0, 0]),
+        ] {
+            let encoded = assert_der_roundtrip(&ASN1Enumerated(value));
+            assert_eq!(encoded[0], 0x0A);
+            assert_eq!(&encoded[2..], content, "{}", value);
+        }
+    }
+
+    #[test]
+    fn test_enumerated_der_rejects_non_minimal() {
+        let err = ASN1Enumerated::from_der_bytes(&[0x0A, 0x02, 0x00, 0x01]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1IntegerEncoding);
+        let err = ASN1Enumerated::from_der_bytes(&[0x0A, 0x02, 0xFF, 0x80]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1IntegerEncoding);
+        let err = ASN1Enumerated::from_der_bytes(&[0x0A, 0x00]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+        let err = ASN1Enumerated::from_der_bytes(&[0x02, 0x01, 0x01]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+        let err = ASN1Enumerated::from_der_bytes(&[0x0A, 0x09, 0x01, 0, 0, 0, 0, 0, 0, 0, 0]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+    }
+
+    #[test]
+    fn test_enumerated_ber_accepts_redundant_octets() {
+        let node = ber::parse(&[0x0A, 0x03, 0x00, 0x00, 0x05]).unwrap();
+        assert_eq!(ASN1Enumerated::from_ber_node(node).unwrap(), ASN1Enumerated(5));
+        let node = ber::parse(&[0x0A, 0x0A, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFE]).unwrap();
+        assert_eq!(ASN1Enumerated::from_ber_node(node).unwrap(), ASN1Enumerated(-2));
+    }
+}
@@ -1,6 +1,7 @@
+use crate::errors::{ASN1Error, ErrorCode};
 use std::fmt;
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[derive(Clone, Copy, PartialEq, Eq, Hash)]
 pub struct ASN1Identifier {
     pub tag_number: u64,
     pub tag_class: TagClass,
@@ -34,6 +35,161 @@ impl TagClass {
     }
 }
 
+/// The tag numbers assigned to the universal class by X.680.
+///
+/// Tag 15 is reserved and has no variant.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[repr(u8)]
+pub enum UniversalTag {
+    EndOfContent = 0,
+    Boolean = 1,
+    Integer = 2,
+    BitString = 3,
+    OctetString = 4,
+    Null = 5,
+    ObjectIdentifier = 6,
+    ObjectDescriptor = 7,
+    External = 8,
+    Real = 9,
+    Enumerated = 10,
+    EmbeddedPdv = 11,
+    UTF8String = 12,
+    RelativeOid = 13,
+    Time = 14,
+    Sequence = 16,
+    Set = 17,
+    NumericString = 18,
+    PrintableString = 19,
+    TeletexString = 20,
+    VideotexString = 21,
+    IA5String = 22,
+    UTCTime = 23,
+    GeneralizedTime = 24,
+    GraphicString = 25,
+    VisibleString = 26,
+    GeneralString = 27,
+    UniversalString = 28,
+    CharacterString = 29,
+    BMPString = 30,
+    Date = 31,
+    TimeOfDay = 32,
+    DateTime = 33,
+    Duration = 34,
+    OidIri = 35,
+    RelativeOidIri = 36,
+}
+
+impl UniversalTag {
+    pub fn from_tag_number(tag_number: u64) -> Option<Self> {
+        Some(match tag_number {
+            0 => UniversalTag::EndOfContent,
+            1 => UniversalTag::Boolean,
+            2 => UniversalTag::Integer,
+            3 => UniversalTag::BitString,
+            4 => UniversalTag::OctetString,
+            5 => UniversalTag::Null,
+            6 => UniversalTag::ObjectIdentifier,
+            7 => UniversalTag::ObjectDescriptor,
+            8 => UniversalTag::External,
+            9 => UniversalTag::Real,
+            10 => UniversalTag::Enumerated,
+            11 => UniversalTag::EmbeddedPdv,
+            12 => UniversalTag::UTF8String,
+            13 => UniversalTag::RelativeOid,
+            14 => UniversalTag::Time,
+            16 => UniversalTag::Sequence,
+            17 => UniversalTag::Set,
+            18 => UniversalTag::NumericString,
+            19 => UniversalTag::PrintableString,
+            20 => UniversalTag::TeletexString,
+            21 => UniversalTag::VideotexString,
+            22 => UniversalTag::IA5String,
+            23 => UniversalTag::UTCTime,
+            24 => UniversalTag::GeneralizedTime,
+            25 => UniversalTag::GraphicString,
+            26 => UniversalTag::VisibleString,
+            27 => UniversalTag::GeneralString,
+            28 => UniversalTag::UniversalString,
+            29 => UniversalTag::CharacterString,
+            30 => UniversalTag::BMPString,
+            31 => UniversalTag::Date,
+            32 => UniversalTag::TimeOfDay,
+            33 => UniversalTag::DateTime,
+            34 => UniversalTag::Duration,
+            35 => UniversalTag::OidIri,
+            36 => UniversalTag::RelativeOidIri,
+            _ => return None,
+        })
+    }
+
+    pub const fn tag_number(self) -> u64 {
+        self as u64
+    }
+
+    /// The type's name as written in ASN.1 notation, e.g. `SEQUENCE` or `UTF8String`.
+    pub const fn name(self) -> &'static str {
+        match self {
+            UniversalTag::EndOfContent => "END-OF-CONTENTS",
+            UniversalTag::Boolean => "BOOLEAN",
+            UniversalTag::Integer => "INTEGER",
+            UniversalTag::BitString => "BIT STRING",
+            UniversalTag::OctetString => "OCTET STRING",
+            UniversalTag::Null => "NULL",
+            UniversalTag::ObjectIdentifier => "OBJECT IDENTIFIER",
+            UniversalTag::ObjectDescriptor => "ObjectDescriptor",
+            UniversalTag::External => "EXTERNAL",
+            UniversalTag::Real => "REAL",
+            UniversalTag::Enumerated => "ENUMERATED",
+            UniversalTag::EmbeddedPdv => "EMBEDDED PDV",
+            UniversalTag::UTF8String => "UTF8String",
+            UniversalTag::RelativeOid => "RELATIVE-OID",
+            UniversalTag::Time => "TIME",
+            UniversalTag::Sequence => "SEQUENCE",
+            UniversalTag::Set => "SET",
+            UniversalTag::NumericString => "NumericString",
+            UniversalTag::PrintableString => "PrintableString",
+            UniversalTag::TeletexString => "TeletexString",
+            UniversalTag::VideotexString => "VideotexString",
+            UniversalTag::IA5String => "IA5String",
+            UniversalTag::UTCTime => "UTCTime",
+            UniversalTag::GeneralizedTime => "GeneralizedTime",
+            UniversalTag::GraphicString => "GraphicString",
+            UniversalTag::VisibleString => "VisibleString",
+            UniversalTag::GeneralString => "GeneralString",
+            UniversalTag::UniversalString => "UniversalString",
+            UniversalTag::CharacterString => "CHARACTER STRING",
+            UniversalTag::BMPString => "BMPString",
+            UniversalTag::Date => "DATE",
+            UniversalTag::TimeOfDay => "TIME-OF-DAY",
+            UniversalTag::DateTime => "DATE-TIME",
+            UniversalTag::Duration => "DURATION",
+            UniversalTag::OidIri => "OID-IRI",
+            UniversalTag::RelativeOidIri => "RELATIVE-OID-IRI",
+        }
+    }
+}
+
+impl From<UniversalTag> for ASN1Identifier {
+    fn from(tag: UniversalTag) -> Self {
+        ASN1Identifier::new(tag.tag_number(), TagClass::Universal)
+    }
+}
+
+impl TryFrom<ASN1Identifier> for UniversalTag {
+    type Error = ASN1Error;
+
+    fn try_from(identifier: ASN1Identifier) -> Result<Self, Self::Error> {
+        identifier.universal_tag().ok_or_else(|| {
+            ASN1Error::new(
+                ErrorCode::UnexpectedFieldType,
+                format!("{} is not an assigned universal tag", identifier),
+                file!().to_string(),
+                line!(),
+            )
+        })
+    }
+}
+
 impl ASN1Identifier {
     pub const fn new(tag_number: u64, tag_class: TagClass) -> Self {
         ASN1Identifier {
@@ -50,6 +206,14 @@ impl ASN1Identifier {
         }
     }
 
+    /// Returns the universal tag this identifier denotes, if it is in the universal class.
+    pub fn universal_tag(&self) -> Option<UniversalTag> {
+        match self.tag_class {
+            TagClass::Universal => UniversalTag::from_tag_number(self.tag_number),
+            _ => None,
+        }
+    }
+
     pub(crate) fn short_form(&self) -> Option<u8> {
         if self.tag_number < 0x1f {
             let mut base_number = self.tag_number as u8;
@@ -71,6 +235,7 @@ impl ASN1Identifier {
     pub const NULL: ASN1Identifier = ASN1Identifier::new(0x05, TagClass::Universal);
     pub const BOOLEAN: ASN1Identifier = ASN1Identifier::new(0x01, TagClass::Universal);
     pub const ENUMERATED: ASN1Identifier = ASN1Identifier::new(0x0a, TagClass::Universal);
+    pub const OBJECT_DESCRIPTOR: ASN1Identifier = ASN1Identifier::new(0x07, TagClass::Universal);
     pub const UTF8_STRING: ASN1Identifier = ASN1Identifier::new(0x0c, TagClass::Universal);
     pub const NUMERIC_STRING: ASN1Identifier = ASN1Identifier::new(0x12, TagClass::Universal);
     pub const PRINTABLE_STRING: ASN1Identifier = ASN1Identifier::new(0x13, TagClass::Universal);
@@ -84,6 +249,7 @@ impl ASN1Identifier {
     pub const BMP_STRING: ASN1Identifier = ASN1Identifier::new(0x1e, TagClass::Universal);
     pub const GENERALIZED_TIME: ASN1Identifier = ASN1Identifier::new(0x18, TagClass::Universal);
     pub const UTC_TIME: ASN1Identifier = ASN1Identifier::new(0x17, TagClass::Universal);
+    pub const TIME: ASN1Identifier = ASN1Identifier::new(0x0e, TagClass::Universal);
 }
 
 impl fmt::Display for ASN1Identifier {
@@ -95,22 +261,38 @@ impl fmt::Display for ASN1Identifier {
             TagClass::Private => "Private",
         };
 
+        write!(f, "ASN1Identifier(")?;
+        if let Some(tag) = self.universal_tag() {
+            write!(f, "{}, ", tag.name())?;
+        }
         if let Some(short) = self.short_form() {
             write!(
                 f,
-                "ASN1Identifier(tagNumber: {}, tagClass: {}, shortForm: 0x{:02X})",
+                "tagNumber: {}, tagClass: {}, shortForm: 0x{:02X})",
                 self.tag_number, class_str, short
             )
         } else {
             write!(
                 f,
-                "ASN1Identifier(tagNumber: {}, tagClass: {}, longForm)",
+                "tagNumber: {}, tagClass: {}, longForm)",
                 self.tag_number, class_str
             )
         }
     }
 }
 
+impl fmt::Debug for ASN1Identifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let mut s = f.debug_struct("ASN1Identifier");
+        if let Some(tag) = self.universal_tag() {
+            s.field("name", &tag.name());
+        }
+        s.field("tag_number", &self.tag_number)
+            .field("tag_class", &self.tag_class)
+            .finish()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -131,6 +313,27 @@ mod tests {
         assert_eq!(TagClass::Private.top_byte_flags(), 0xC0);
     }
 
+    #[test]
+    fn test_universal_tag_conversions() {
+        assert_eq!(ASN1Identifier::from(UniversalTag::Sequence), ASN1Identifier::SEQUENCE);
+        assert_eq!(ASN1Identifier::from(UniversalTag::UTF8String), ASN1Identifier::UTF8_STRING);
+        assert_eq!(UniversalTag::try_from(ASN1Identifier::INTEGER).unwrap(), UniversalTag::Integer);
+        assert_eq!(ASN1Identifier::BMP_STRING.universal_tag(), Some(UniversalTag::BMPString));
+
+        for number in 0..=36u64 {
+            match UniversalTag::from_tag_number(number) {
+                Some(tag) => assert_eq!(tag.tag_number(), number),
+                None => assert_eq!(number, 15),
+            }
+        }
+        assert_eq!(UniversalTag::from_tag_number(37), None);
+
+        let context = ASN1Identifier::new(2, TagClass::ContextSpecific);
+        assert_eq!(context.universal_tag(), None);
+        let err = UniversalTag::try_from(context).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+    }
+
     #[test]
     fn test_identifier_display_includes_fields() {
         let id = ASN1Identifier::new(42, TagClass::ContextSpecific);
@@ -146,4 +349,28 @@ mod tests {
             text
         );
     }
+
+    #[test]
+    fn test_identifier_display_and_debug_name_universal_tags() {
+        assert_eq!(
+            ASN1Identifier::SEQUENCE.to_string(),
+            "ASN1Identifier(SEQUENCE, tagNumber: 16, tagClass: Universal, shortForm: 0x10)"
+        );
+        assert!(ASN1Identifier::UTF8_STRING.to_string().contains("UTF8String"));
+        assert_eq!(
+            format!("{:?}", ASN1Identifier::OCTET_STRING),
+            "ASN1Identifier { name: \"OCTET STRING\", tag_number: 4, tag_class: Universal }"
+        );
+
+        let context = ASN1Identifier::new(16, TagClass::ContextSpecific);
+        assert!(!context.to_string().contains("SEQUENCE"));
+        assert_eq!(
+            format!("{:?}", context),
+            "ASN1Identifier { tag_number: 16, tag_class: ContextSpecific }"
+        );
+        assert_eq!(
+            ASN1Identifier::new(15, TagClass::Universal).to_string(),
+            "ASN1Identifier(tagNumber: 15, tagClass: Universal, shortForm: 0x0F)"
+        );
+    }
 }
@@ -3,27 +3,36 @@ use crate::asn1_types::ASN1Identifier;
 use crate::asn1::ASN1Node;
 use crate::errors::{ASN1Error, ErrorCode};
 use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
-use num_bigint::BigInt;
+#[cfg(feature = "num-bigint")]
 use num_traits::ToPrimitive;
+use std::fmt;
+use std::str::FromStr;
+
+/// The value type of [`ASN1Integer`]: `num_bigint::BigInt` with the `num-bigint` feature,
+/// and the dependency-free `RawInteger` without it.
+#[cfg(feature = "num-bigint")]
+pub type IntegerValue = num_bigint::BigInt;
+#[cfg(not(feature = "num-bigint"))]
+pub type IntegerValue = crate::asn1_types::RawInteger;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ASN1Integer {
-    pub value: BigInt,
+    pub value: IntegerValue,
 }
 
 impl From<i64> for ASN1Integer {
     fn from(v: i64) -> Self {
-        ASN1Integer { value: BigInt::from(v) }
+        ASN1Integer { value: IntegerValue::from(v) }
     }
 }
 
-impl From<BigInt> for ASN1Integer {
-    fn from(v: BigInt) -> Self {
+impl From<IntegerValue> for ASN1Integer {
+    fn from(v: IntegerValue) -> Self {
         ASN1Integer { value: v }
     }
 }
 
-impl From<ASN1Integer> for BigInt {
+impl From<ASN1Integer> for IntegerValue {
     fn from(v: ASN1Integer) -> Self {
         v.value
     }
@@ -37,6 +46,102 @@ impl ASN1Integer {
     pub fn to_u64(&self) -> Result<u64, ASN1Error> {
         self.value.to_u64().ok_or_else(|| asn1_err!(ErrorCode::ValueOutOfRange, "ASN1Integer does not fit into u64"))
     }
+
+    /// Builds the non-negative INTEGER whose magnitude is the big-endian `bytes`, as crypto
+    /// libraries produce them; a zero octet is added when needed to keep the sign positive.
+    /// No bytes is zero.
+    pub fn from_unsigned_bytes_be(bytes: &[u8]) -> Self {
+        let mut signed = Vec::with_capacity(bytes.len() + 1);
+        signed.push(0x00);
+        signed.extend_from_slice(bytes);
+        ASN1Integer { value: IntegerValue::from_signed_bytes_be(&signed) }
+    }
+
+    /// The magnitude of a non-negative value as big-endian octets, without the zero octet
+    /// the signed encoding adds when the top bit is set: the form RSA moduli and ECDSA
+    /// signature components take. Zero is a single zero octet.
+    pub fn to_unsigned_bytes_be(&self) -> Result<Vec<u8>, ASN1Error> {
+        let mut bytes = self.value.to_signed_bytes_be();
+        if bytes[0] & 0x80 != 0 {
+            return Err(asn1_err!(ErrorCode::ValueOutOfRange, "ASN1Integer is negative"));
+        }
+        if bytes.len() > 1 && bytes[0] == 0x00 {
+            bytes.remove(0);
+        }
+        Ok(bytes)
+    }
+}
+
+impl fmt::Display for ASN1Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.value, f)
+    }
+}
+
+/// Formats as a sign and the hex magnitude, e.g. `-ff`, with `0x` under `{:#x}`.
+impl fmt::LowerHex for ASN1Integer {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let signed = self.value.to_signed_bytes_be();
+        let negative = signed[0] & 0x80 != 0;
+        let magnitude = if negative { negate(&signed) } else { signed };
+        let digits: String = magnitude.iter().map(|b| format!("{:02x}", b)).collect();
+        let digits = digits.trim_start_matches('0');
+        f.pad_integral(!negative, "0x", if digits.is_empty() { "0" } else { digits })
+    }
+}
+
+impl FromStr for ASN1Integer {
+    type Err = ASN1Error;
+
+    /// Parses a decimal integer with an optional `+` or `-` sign.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let (negative, digits) = match s.as_bytes().first() {
+            Some(b'-') => (true, &s[1..]),
+            Some(b'+') => (false, &s[1..]),
+            _ => (false, s),
+        };
+        if digits.is_empty() || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ASN1Error::new(
+                ErrorCode::InvalidStringRepresentation,
+                format!("Invalid INTEGER {:?}: expected decimal digits", s),
+                file!().to_string(),
+                line!(),
+            ));
+        }
+        // Accumulate the magnitude in base 256, behind a zero octet that keeps it positive.
+        let mut signed = vec![0x00];
+        for digit in digits.bytes() {
+            let mut carry = u32::from(digit - b'0');
+            for octet in signed.iter_mut().rev() {
+                let value = u32::from(*octet) * 10 + carry;
+                *octet = value as u8;
+                carry = value >> 8;
+            }
+            if carry != 0 {
+                signed.insert(0, carry as u8);
+            }
+            if signed[0] & 0x80 != 0 {
+                signed.insert(0, 0x00);
+            }
+        }
+        if negative {
+            signed = negate(&signed);
+        }
+        Ok(ASN1Integer { value: IntegerValue::from_signed_bytes_be(&signed) })
+    }
+}
+
+/// The two's complement negation of big-endian `bytes`, of the same width.
+fn negate(bytes: &[u8]) -> Vec<u8> {
+    let mut negated: Vec<u8> = bytes.iter().map(|b| !b).collect();
+    for octet in negated.iter_mut().rev() {
+        let (sum, carry) = octet.overflowing_add(1);
+        *octet = sum;
+        if !carry {
+            break;
+        }
+    }
+    negated
 }
 
 impl DERParseable for ASN1Integer {
@@ -79,28 +184,24 @@ impl DERImplicitlyTaggable for ASN1Integer {
                 if bytes.len() > 1 {
                     let first = bytes[0];
                     let second = bytes[1];
-                    if first == 0x00 {
-                        if (second & 0x80) == 0 {
-                            return Err(ASN1Error::new(
-                                ErrorCode::InvalidASN1IntegerEncoding,
-                                "Integer encoded with redundant leading zero".to_string(),
-                                file!().to_string(),
-                                line!(),
-                            ));
-                        }
-                    } else if first == 0xFF {
-                        if (second & 0x80) == 0x80 {
-                            return Err(ASN1Error::new(
-                                ErrorCode::InvalidASN1IntegerEncoding,
-                                "Integer encoded with redundant leading FF".to_string(),
-                                file!().to_string(),
-                                line!(),
-                            ));
-                        }
+                    if first == 0x00 && (second & 0x80) == 0 {
+                        return Err(ASN1Error::new(
+                            ErrorCode::InvalidASN1IntegerEncoding,
+                            "Integer encoded with redundant leading zero".to_string(),
+                            file!().to_string(),
+                            line!(),
+                        ));
+                    } else if first == 0xFF && (second & 0x80) == 0x80 {
+                        return Err(ASN1Error::new(
+                            ErrorCode::InvalidASN1IntegerEncoding,
+                            "Integer encoded with redundant leading FF".to_string(),
+                            file!().to_string(),
+                            line!(),
+                        ));
                     }
                 }
                 
-                let val = BigInt::from_signed_bytes_be(&bytes);
+                let val = IntegerValue::from_signed_bytes_be(&bytes);
                 Ok(ASN1Integer { value: val })
             },
              _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "Integer must be primitive".to_string(), file!().to_string(), line!()))
@@ -134,7 +235,7 @@ impl BERImplicitlyTaggable for ASN1Integer {
                      return Err(ASN1Error::new(ErrorCode::InvalidASN1Object, "Integer with 0 bytes".to_string(), file!().to_string(), line!()));
                 }
                 // BER allows redundant bytes.
-                let val = BigInt::from_signed_bytes_be(&bytes);
+                let val = IntegerValue::from_signed_bytes_be(&bytes);
                 Ok(ASN1Integer { value: val })
             },
              _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "Integer must be primitive".to_string(), file!().to_string(), line!()))
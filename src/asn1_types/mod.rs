@@ -1,22 +1,54 @@
-pub use self::bit_string::ASN1BitString;
+pub use self::any::ASN1Any;
+pub use self::arbitrary_precision_integer::ArbitraryPrecisionInteger;
+pub use self::bit_string::{ASN1BitString, BitStringBuilder};
 pub use self::boolean::ASN1Boolean;
+pub use self::bounded_integer::BoundedInteger;
+pub use self::enumerated::ASN1Enumerated;
 pub use self::identifier::ASN1Identifier;
 pub use self::identifier::TagClass;
+pub use self::identifier::UniversalTag;
 pub use self::integer::ASN1Integer;
+pub use self::named_bit_list::{NamedBit, NamedBitList};
 pub use self::null::ASN1Null;
 pub use self::object_identifier::ASN1ObjectIdentifier;
-pub use self::octet_string::ASN1OctetString;
-pub use self::real::ASN1Real;
-pub use self::strings::{ASN1IA5String, ASN1NumericString, ASN1PrintableString, ASN1UTF8String};
-pub use self::time::{GeneralizedTime, UTCTime};
+pub use self::octet_string::{ASN1OctetString, OctetStringOf};
+#[cfg(not(feature = "num-bigint"))]
+pub use self::raw_integer::RawInteger;
+#[cfg(feature = "real")]
+pub use self::real::{ASN1Real, RealParts};
+pub use self::sequence_of::ASN1SequenceOf;
+pub use self::set_of::ASN1SetOf;
+#[cfg(feature = "strings")]
+pub use self::strings::{
+    ASN1GeneralString, ASN1GraphicString, ASN1IA5String, ASN1NumericString, ASN1ObjectDescriptor, ASN1PrintableString,
+    ASN1UTF8String, ASN1VideotexString,
+};
+pub use self::time::{ASN1Time, GeneralizedTime, RawTime, UTCTime};
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub use self::utc_date_time::UtcDateTime;
 
+pub mod any;
+pub mod arbitrary_precision_integer;
 pub mod bit_string;
 pub mod boolean;
+pub mod bounded_integer;
+pub mod enumerated;
 pub mod identifier;
 pub mod integer;
+pub mod named_bit_list;
 pub mod null;
 pub mod object_identifier;
+#[cfg(feature = "oid-names")]
+mod oid_names;
 pub mod octet_string;
+#[cfg(not(feature = "num-bigint"))]
+mod raw_integer;
+#[cfg(feature = "real")]
 pub mod real;
+pub mod sequence_of;
+pub mod set_of;
+#[cfg(feature = "strings")]
 pub mod strings;
 pub mod time;
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+mod utc_date_time;
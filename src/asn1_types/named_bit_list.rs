@@ -0,0 +1,214 @@
+use crate::asn1::ASN1Node;
+use crate::asn1_types::{ASN1BitString, ASN1Identifier};
+use crate::ber::{BERImplicitlyTaggable, BERParseable, BERSerializable};
+use crate::der::{DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
+use crate::errors::ASN1Error;
+use std::fmt;
+use std::hash::Hash;
+use std::marker::PhantomData;
+
+/// A flag enum whose variants name bits of a BIT STRING, such as X.509 `KeyUsage`.
+pub trait NamedBit: Copy + Eq + Hash + fmt::Debug + 'static {
+    /// Every variant, in any order.
+    const ALL: &'static [Self];
+
+    /// The bit this variant names, counting from zero.
+    fn position(self) -> usize;
+}
+
+/// A BIT STRING holding a set of [`NamedBit`] flags.
+///
+/// It encodes with the trailing zero bits trimmed, as DER requires for named bit lists
+/// (X.690 11.2.2), and decodes tolerantly: untrimmed encodings and bits no variant names
+/// are accepted, and the latter are kept so a re-encoding loses nothing.
+pub struct NamedBitList<F: NamedBit> {
+    bits: ASN1BitString,
+    flags: PhantomData<F>,
+}
+
+impl<F: NamedBit> NamedBitList<F> {
+    pub fn new() -> Self {
+        Self::from_bit_string(&ASN1BitString::from_bits(std::iter::empty()))
+    }
+
+    /// The flags set in `bits`, with any trailing zero bits dropped.
+    pub fn from_bit_string(bits: &ASN1BitString) -> Self {
+        let len = bits.bits().enumerate().filter(|&(_, bit)| bit).last().map_or(0, |(i, _)| i + 1);
+        NamedBitList { bits: ASN1BitString::from_bits(bits.bits().take(len)), flags: PhantomData }
+    }
+
+    pub fn contains(&self, flag: F) -> bool {
+        self.bits.bit(flag.position())
+    }
+
+    pub fn insert(&mut self, flag: F) {
+        let mut builder = self.bits.to_builder();
+        builder.set_bit(flag.position(), true);
+        self.bits = builder.build();
+    }
+
+    pub fn remove(&mut self, flag: F) {
+        if self.contains(flag) {
+            let mut builder = self.bits.to_builder();
+            builder.set_bit(flag.position(), false);
+            *self = Self::from_bit_string(&builder.build());
+        }
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.bits.bytes.is_empty()
+    }
+
+    /// The flags that are set, in the order of [`NamedBit::ALL`].
+    pub fn iter(&self) -> impl Iterator<Item = F> + '_ {
+        F::ALL.iter().copied().filter(move |&flag| self.contains(flag))
+    }
+
+    /// The trimmed BIT STRING, including any bits no variant names.
+    pub fn as_bit_string(&self) -> &ASN1BitString {
+        &self.bits
+    }
+}
+
+impl<F: NamedBit> Default for NamedBitList<F> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<F: NamedBit> Clone for NamedBitList<F> {
+    fn clone(&self) -> Self {
+        NamedBitList { bits: self.bits.clone(), flags: PhantomData }
+    }
+}
+
+impl<F: NamedBit> PartialEq for NamedBitList<F> {
+    fn eq(&self, other: &Self) -> bool {
+        self.bits == other.bits
+    }
+}
+
+impl<F: NamedBit> Eq for NamedBitList<F> {}
+
+impl<F: NamedBit> Hash for NamedBitList<F> {
+    fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+        self.bits.hash(state)
+    }
+}
+
+impl<F: NamedBit> fmt::Debug for NamedBitList<F> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_set().entries(self.iter()).finish()
+    }
+}
+
+impl<F: NamedBit> FromIterator<F> for NamedBitList<F> {
+    fn from_iter<I: IntoIterator<Item = F>>(iter: I) -> Self {
+        let mut list = Self::new();
+        for flag in iter {
+            list.insert(flag);
+        }
+        list
+    }
+}
+
+impl<F: NamedBit> From<NamedBitList<F>> for ASN1BitString {
+    fn from(v: NamedBitList<F>) -> Self {
+        v.bits
+    }
+}
+
+impl<F: NamedBit> DERParseable for NamedBitList<F> {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl<F: NamedBit> DERSerializable for NamedBitList<F> {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        self.bits.serialize(serializer)
+    }
+}
+
+impl<F: NamedBit> DERImplicitlyTaggable for NamedBitList<F> {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::BIT_STRING
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1BitString::from_der_node_with_identifier(node, identifier).map(|bits| Self::from_bit_string(&bits))
+    }
+}
+
+impl<F: NamedBit> BERParseable for NamedBitList<F> {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_ber_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl<F: NamedBit> BERSerializable for NamedBitList<F> {}
+
+impl<F: NamedBit> BERImplicitlyTaggable for NamedBitList<F> {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1BitString::from_ber_node_with_identifier(node, identifier).map(|bits| Self::from_bit_string(&bits))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ber;
+    use crate::testing::assert_der_roundtrip;
+
+    #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+    enum KeyUsage {
+        DigitalSignature,
+        KeyEncipherment,
+        KeyCertSign,
+        DecipherOnly,
+    }
+
+    impl NamedBit for KeyUsage {
+        const ALL: &'static [Self] = &[Self::DigitalSignature, Self::KeyEncipherment, Self::KeyCertSign, Self::DecipherOnly];
+
+        fn position(self) -> usize {
+            match self {
+                Self::DigitalSignature => 0,
+                Self::KeyEncipherment => 2,
+                Self::KeyCertSign => 5,
+                Self::DecipherOnly => 8,
+            }
+        }
+    }
+
+    #[test]
+    fn test_named_bit_list_trims_on_encode() {
+        let usage: NamedBitList<KeyUsage> = [KeyUsage::KeyCertSign, KeyUsage::DigitalSignature].into_iter().collect();
+        assert_eq!(usage.iter().collect::<Vec<_>>(), vec![KeyUsage::DigitalSignature, KeyUsage::KeyCertSign]);
+        assert_eq!(assert_der_roundtrip(&usage), vec![0x03, 0x02, 0x02, 0x84]);
+
+        let mut usage = usage;
+        usage.insert(KeyUsage::DecipherOnly);
+        assert_eq!(assert_der_roundtrip(&usage), vec![0x03, 0x03, 0x07, 0x84, 0x80]);
+        usage.remove(KeyUsage::DecipherOnly);
+        usage.remove(KeyUsage::KeyCertSign);
+        assert_eq!(assert_der_roundtrip(&usage), vec![0x03, 0x02, 0x07, 0x80]);
+        usage.remove(KeyUsage::DigitalSignature);
+        assert!(usage.is_empty());
+        assert_eq!(assert_der_roundtrip(&usage), vec![0x03, 0x01, 0x00]);
+    }
+
+    #[test]
+    fn test_named_bit_list_decodes_tolerantly() {
+        // Untrimmed, with bit 3 named by no variant.
+        let usage = NamedBitList::<KeyUsage>::from_der_bytes(&[0x03, 0x03, 0x00, 0x30, 0x00]).unwrap();
+        assert_eq!(usage.iter().collect::<Vec<_>>(), vec![KeyUsage::KeyEncipherment]);
+        assert!(usage.as_bit_string().bit(3));
+        assert_eq!(usage.as_bit_string().len_bits(), 4);
+        assert_eq!(format!("{:?}", usage), "{KeyEncipherment}");
+
+        let node = ber::parse(&[0x03, 0x02, 0x04, 0x8F]).unwrap();
+        let usage = NamedBitList::<KeyUsage>::from_ber_node(node).unwrap();
+        assert_eq!(usage, [KeyUsage::DigitalSignature].into_iter().collect());
+    }
+}
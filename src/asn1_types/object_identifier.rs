@@ -3,11 +3,72 @@ use crate::asn1::ASN1Node;
 use crate::errors::{ASN1Error, ErrorCode};
 use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
 use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
-use bytes::Bytes;
+use crate::buffer::Bytes;
+use std::fmt;
+use std::hash::{Hash, Hasher};
+
+/// Encoded OIDs up to this many bytes are stored inline rather than on the heap.
+const INLINE_CAPACITY: usize = 23;
+
+#[derive(Clone)]
+enum Storage {
+    Inline { len: u8, buf: [u8; INLINE_CAPACITY] },
+    Shared(Bytes),
+    Static(&'static [u8]),
+}
+
+impl Storage {
+    fn from_slice(slice: &[u8]) -> Option<Self> {
+        if slice.len() > INLINE_CAPACITY {
+            return None;
+        }
+        let mut buf = [0u8; INLINE_CAPACITY];
+        buf[..slice.len()].copy_from_slice(slice);
+        Some(Storage::Inline { len: slice.len() as u8, buf })
+    }
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+    fn from_bytes(bytes: Bytes) -> Self {
+        Self::from_slice(&bytes).unwrap_or(Storage::Shared(bytes))
+    }
+
+    fn from_vec(vec: Vec<u8>) -> Self {
+        Self::from_slice(&vec).unwrap_or_else(|| Storage::Shared(Bytes::from(vec)))
+    }
+
+    fn as_slice(&self) -> &[u8] {
+        match self {
+            Storage::Inline { len, buf } => &buf[..*len as usize],
+            Storage::Shared(bytes) => bytes,
+            Storage::Static(bytes) => bytes,
+        }
+    }
+}
+
+#[derive(Clone)]
 pub struct ASN1ObjectIdentifier {
-    bytes: Bytes,
+    storage: Storage,
+}
+
+impl PartialEq for ASN1ObjectIdentifier {
+    fn eq(&self, other: &Self) -> bool {
+        self.as_bytes() == other.as_bytes()
+    }
+}
+
+impl Eq for ASN1ObjectIdentifier {}
+
+impl Hash for ASN1ObjectIdentifier {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.as_bytes().hash(state);
+    }
+}
+
+impl fmt::Debug for ASN1ObjectIdentifier {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ASN1ObjectIdentifier")
+            .field("bytes", &self.as_bytes())
+            .finish()
+    }
 }
 
 impl ASN1ObjectIdentifier {
@@ -34,104 +95,155 @@ impl ASN1ObjectIdentifier {
             write_oid_subidentifier(c, &mut buffer);
         }
         
-        Ok(ASN1ObjectIdentifier { bytes: Bytes::from(buffer) })
+        Ok(ASN1ObjectIdentifier { storage: Storage::from_vec(buffer) })
+    }
+
+    /// Wraps pre-encoded content octets, e.g. `&[0x55, 0x04, 0x03]` for 2.5.4.3, without
+    /// copying them. Being `const`, this can define `static` OID tables:
+    ///
+    /// ```
+    /// # use rust_asn1::asn1_types::ASN1ObjectIdentifier;
+    /// static COMMON_NAME: ASN1ObjectIdentifier = ASN1ObjectIdentifier::from_encoded_const(&[0x55, 0x04, 0x03]);
+    /// assert_eq!(COMMON_NAME, ASN1ObjectIdentifier::new(&[2, 5, 4, 3]).unwrap());
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if `encoded` is not a valid OID encoding, which fails compilation when
+    /// evaluated in a const context.
+    pub const fn from_encoded_const(encoded: &'static [u8]) -> Self {
+        assert!(!encoded.is_empty(), "OID encoding must not be empty");
+        assert!(encoded[encoded.len() - 1] & 0x80 == 0, "OID encoding ends inside a subidentifier");
+        let mut index = 0;
+        let mut value: u64 = 0;
+        let mut at_start = true;
+        while index < encoded.len() {
+            let byte = encoded[index];
+            assert!(!(at_start && byte == 0x80), "OID subidentifier encoded with leading 0 byte");
+            value = match value.checked_mul(128) {
+                Some(shifted) => shifted | (byte & 0x7F) as u64,
+                None => panic!("OID subidentifier exceeds u64 capacity"),
+            };
+            at_start = byte & 0x80 == 0;
+            if at_start {
+                value = 0;
+            }
+            index += 1;
+        }
+        ASN1ObjectIdentifier { storage: Storage::Static(encoded) }
+    }
+
+    /// The encoded content octets of the OID (without identifier and length).
+    pub fn as_bytes(&self) -> &[u8] {
+        self.storage.as_slice()
     }
 
     pub fn oid_components(&self) -> Result<Vec<u64>, ASN1Error> {
-        let mut components = Vec::new();
-        let mut data = self.bytes.clone();
-        
-        // Read first subidentifier
-        if data.is_empty() {
+        if self.as_bytes().is_empty() {
              return Err(ASN1Error::new(ErrorCode::InvalidASN1Object, "Zero components in OID".to_string(), file!().to_string(), line!()));
         }
-        
-        let before_first = data.len();
-        let first_val = read_oid_subidentifier(&mut data)?;
-        if data.len() == before_first {
-            return Err(ASN1Error::new(
-                ErrorCode::InvalidASN1Object,
-                "OID decoder failed to consume first subidentifier".to_string(),
-                file!().to_string(),
-                line!(),
-            ));
+        Ok(self.arcs().collect())
+    }
+
+    /// Iterates over the arcs of the OID, decoding them from the stored bytes as it goes.
+    ///
+    /// Unlike [`oid_components`](Self::oid_components) this never allocates, so it suits
+    /// comparisons such as `oid.arcs().eq([2, 5, 4, 3])`.
+    pub fn arcs(&self) -> Arcs<'_> {
+        Arcs { data: self.as_bytes(), second: None, started: false }
+    }
+
+    /// The OID with `arc` appended, e.g. `2.5.4` → `2.5.4.3`.
+    pub fn child(&self, arc: u64) -> Self {
+        let mut buffer = self.as_bytes().to_vec();
+        write_oid_subidentifier(arc, &mut buffer);
+        ASN1ObjectIdentifier { storage: Storage::from_vec(buffer) }
+    }
+
+    /// The OID without its last arc, or `None` for a two-arc OID, whose parent cannot be
+    /// encoded.
+    pub fn parent(&self) -> Option<Self> {
+        let bytes = self.as_bytes();
+        // The last subidentifier starts after the previous byte without the continuation bit.
+        let (_, init) = bytes.split_last()?;
+        let start = init.iter().rposition(|byte| byte & 0x80 == 0)? + 1;
+        Some(ASN1ObjectIdentifier { storage: Storage::from_vec(bytes[..start].to_vec()) })
+    }
+
+    /// Whether `other` is this OID or lies beneath it in the arc hierarchy.
+    ///
+    /// Every subidentifier ends in a byte without the continuation bit, so a byte prefix
+    /// always ends on an arc boundary.
+    pub fn is_prefix_of(&self, other: &ASN1ObjectIdentifier) -> bool {
+        other.as_bytes().starts_with(self.as_bytes())
+    }
+
+    /// The conventional short name of a well-known OID, e.g. `"sha256WithRSAEncryption"`.
+    #[cfg(feature = "oid-names")]
+    pub fn name(&self) -> Option<&'static str> {
+        crate::asn1_types::oid_names::OID_NAMES
+            .iter()
+            .find(|(arcs, _)| self.arcs().eq(arcs.iter().copied()))
+            .map(|(_, name)| *name)
+    }
+
+    /// Looks up a well-known OID by its short name; the reverse of [`name`](Self::name).
+    #[cfg(feature = "oid-names")]
+    pub fn from_name(name: &str) -> Option<Self> {
+        let (arcs, _) = crate::asn1_types::oid_names::OID_NAMES.iter().find(|(_, n)| *n == name)?;
+        ASN1ObjectIdentifier::new(arcs).ok()
+    }
+
+    /// Whether this OID is `prefix` or lies beneath it; the reverse of
+    /// [`is_prefix_of`](Self::is_prefix_of).
+    pub fn starts_with(&self, prefix: &ASN1ObjectIdentifier) -> bool {
+        prefix.is_prefix_of(self)
+    }
+}
+
+/// Iterator over the arcs of an [`ASN1ObjectIdentifier`], created by
+/// [`ASN1ObjectIdentifier::arcs`].
+#[derive(Debug, Clone)]
+pub struct Arcs<'a> {
+    data: &'a [u8],
+    second: Option<u64>,
+    started: bool,
+}
+
+impl Iterator for Arcs<'_> {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        if let Some(second) = self.second.take() {
+            return Some(second);
         }
-        
-        let first = first_val / 40;
-        let second = first_val % 40;
-        components.push(first);
-        components.push(second); // This might be wrong if first=2 and second > 39?
-        // Spec: "The numerical value of the first subidentifier is derived from ... (X*40) + Y"
-        // If X=2, Y can be large. So first_val can be > 119.
-        // If first_val >= 80, then X=2.
-        // Wait, if X=0 or 1, Y<=39. Max 79.
-        // So if val < 80, X = val/40, Y = val%40.
-        // If val >= 80, X = 2, Y = val - 80.
-        // Let's refine.
-        // Swift uses `dividingBy: 40`.
-        // If first_val = 120 (2.40). 120/40 = 3. Remainder 0. -> 3.0. Wrong. X must be 0,1,2.
-        
-        // Correct logic:
-        // if val < 80: X = val / 40, Y = val % 40.
-        // if val >= 80: X = 2, Y = val - 80.
-        
-        // Re-checking Swift:
-        // `let (firstSubcomponent, secondSubcomponent) = firstEncodedSubcomponent.quotientAndRemainder(dividingBy: 40)`
-        // If `firstEncodedSubcomponent` is 120, Swift returns (3, 0).
-        // Does Swift OID support X > 2?
-        // RFC says: "The first octet has value 40 * value1 + value2. (This is unambiguous, since value1 is limited to 0, 1, and 2; value2 is limited to 0 to 39 when value1 is 0 or 1; and, according to X.208, n is always at least 2.)"
-        // Wait, if value1=2, value2 can be anything. (2 * 40) + Y = 80 + Y.
-        // If encoded is 80, 80/40 = 2, rem 0. -> 2.0. Correct.
-        // If encoded is 120. 120/40 = 3. rem 0. -> 3.0. X=3? Invalid.
-        
-        // So Swift implementation assumes valid OID input where X encoded is correct.
-        // But if I decode 120, I get 3.0.
-        // If X is limited to 2, then 120 means X=2, Y=40.
-        // 2*40 + 40 = 120.
-        // So strictly speaking, X = min(val / 40, 2)?
-        // No, if val >= 80, X is 2.
-        // Implement correct logic over Swift's simple division?
-        // Or assume Swift is right and I should match it?
-        // Note: Swift's `oidComponents` implementation simply divides. 
-        // `let (firstSubcomponent, secondSubcomponent) = firstEncodedSubcomponent.quotientAndRemainder(dividingBy: 40)`
-        // This implies Swift `ASN1ObjectIdentifier` might return X=3.
-        // But `init` with array checks `first > 2`.
-        // So it seems passing an encoded OID that results in X=3 is possible via `derEncoded`.
-        // I will stick to simple division to match Swift behavior, assuming encoded data is usually valid.
-        // BUT strict OID decoding usually handles X=2 specially.
-        // Given "Maximal type similarity", matching behavior (even if simplistic) is good.
-        // But `ASN1ObjectIdentifier` in Swift is a struct.
-        // I'll replicate Swift's logic: simple division.
-        
-        // But wait, if X=2, Y=40 -> 120. 120/40 = 3. 
-        // This means Swift would return [3, 0].
-        // Is that valid? Maybe not. But that's what the code does.
-        
-        // Actually, checking `ASN1ObjectIdentifier.swift`:
-        // It validates in `validateObjectIdentifierInEncodedForm`. But that only checks `readUIntUsing8BitBytesASN1Discipline`.
-        // It does not check range of first component.
-        
-        // Use Swift logic.
-        
-        // Fix for first component extraction from `components` vec which handles this.
-        // Already pushed
-        components[0] = first;
-        components[1] = second;
-
-        while !data.is_empty() {
-            let before = data.len();
-            components.push(read_oid_subidentifier(&mut data)?);
-            if data.len() == before {
-                return Err(ASN1Error::new(
-                    ErrorCode::InvalidASN1Object,
-                    "OID decoder failed to consume subidentifier bytes".to_string(),
-                    file!().to_string(),
-                    line!(),
-                ));
+        if self.data.is_empty() {
+            return None;
+        }
+        // Stored bytes were validated on construction, so decoding cannot fail.
+        let value = read_oid_subidentifier(&mut self.data).ok()?;
+        if self.started {
+            return Some(value);
+        }
+        self.started = true;
+        // The first subidentifier packs two arcs as X * 40 + Y. X is at most 2 and only
+        // arc 2 may have Y >= 40, so anything from 80 up belongs to arc 2.
+        let first = (value / 40).min(2);
+        self.second = Some(value - first * 40);
+        Some(first)
+    }
+}
+
+impl fmt::Display for ASN1ObjectIdentifier {
+    /// Formats the OID in dotted form, e.g. `1.2.840.113549`.
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        for (index, arc) in self.arcs().enumerate() {
+            if index > 0 {
+                f.write_str(".")?;
             }
+            write!(f, "{}", arc)?;
         }
-        
-        Ok(components)
+        Ok(())
     }
 }
 
@@ -144,7 +256,7 @@ impl DERParseable for ASN1ObjectIdentifier {
 impl DERSerializable for ASN1ObjectIdentifier {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
          serializer.append_primitive_node(Self::default_identifier(), |buf| {
-             buf.extend_from_slice(&self.bytes);
+             buf.extend_from_slice(self.as_bytes());
              Ok(())
          })
     }
@@ -167,7 +279,7 @@ impl DERImplicitlyTaggable for ASN1ObjectIdentifier {
                 }
                 
                 // Validate VLQ
-                let mut check = bytes.clone();
+                let mut check: &[u8] = &bytes;
                 while !check.is_empty() {
                     let before = check.len();
                     read_oid_subidentifier(&mut check)?;
@@ -181,7 +293,7 @@ impl DERImplicitlyTaggable for ASN1ObjectIdentifier {
                     }
                 }
                 
-                Ok(ASN1ObjectIdentifier { bytes })
+                Ok(ASN1ObjectIdentifier { storage: Storage::from_bytes(bytes) })
             },
              _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "OID must be primitive".to_string(), file!().to_string(), line!()))
         }
@@ -233,19 +345,19 @@ fn write_oid_subidentifier(mut value: u64, buf: &mut Vec<u8>) {
     }
 }
 
-fn read_oid_subidentifier(data: &mut Bytes) -> Result<u64, ASN1Error> {
+fn read_oid_subidentifier(data: &mut &[u8]) -> Result<u64, ASN1Error> {
     let mut value: u64 = 0;
     let mut first_byte = true;
     loop {
-        if data.is_empty() {
+        let Some((&byte, rest)) = data.split_first() else {
             return Err(ASN1Error::new(
                 ErrorCode::TruncatedASN1Field,
                 "".to_string(),
                 file!().to_string(),
                 line!(),
             ));
-        }
-        let byte = data.split_to(1)[0];
+        };
+        *data = rest;
 
         if first_byte && byte == 0x80 {
             return Err(ASN1Error::new(
@@ -283,7 +395,6 @@ mod tests {
     use crate::asn1_types::ASN1Identifier;
     use crate::ber;
     use crate::der;
-    use bytes::Bytes;
 
     #[test]
     fn test_oid_new_errors() {
@@ -308,7 +419,7 @@ mod tests {
 
     #[test]
     fn test_oid_components_empty_bytes_error() {
-        let oid = ASN1ObjectIdentifier { bytes: Bytes::new() };
+        let oid = ASN1ObjectIdentifier { storage: Storage::from_bytes(Bytes::new()) };
         assert!(oid.oid_components().is_err());
     }
 
@@ -316,7 +427,7 @@ mod tests {
     fn test_oid_new_zero_first_subidentifier_hits_zero_write_path() {
         // firstByteVal = 0 * 40 + 0 => write_oid_subidentifier(0, ...)
         let oid = ASN1ObjectIdentifier::new(&[0, 0]).unwrap();
-        assert_eq!(oid.bytes.as_ref(), [0x00]);
+        assert_eq!(oid.as_bytes(), [0x00]);
         let comps = oid.oid_components().unwrap();
         assert_eq!(comps, vec![0, 0]);
     }
@@ -366,9 +477,138 @@ mod tests {
         assert!(res.is_err());
     }
 
+    #[test]
+    fn test_short_oids_are_stored_inline() {
+        // 1.2.840.113549.1.1.11 (sha256WithRSAEncryption)
+        let data = [0x06, 0x09, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B];
+        let parsed = ASN1ObjectIdentifier::from_der_bytes(&data).unwrap();
+        assert!(matches!(parsed.storage, Storage::Inline { len: 9, .. }));
+
+        let built = ASN1ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]).unwrap();
+        assert!(matches!(built.storage, Storage::Inline { .. }));
+        assert_eq!(parsed, built);
+        assert_eq!(parsed.as_bytes(), &data[2..]);
+    }
+
+    #[test]
+    fn test_long_oids_share_parsed_bytes() {
+        let mut components = vec![1, 3];
+        components.extend(std::iter::repeat_n(u64::from(u32::MAX), 5));
+        let oid = ASN1ObjectIdentifier::new(&components).unwrap();
+        assert!(oid.as_bytes().len() > INLINE_CAPACITY);
+        assert!(matches!(oid.storage, Storage::Shared(_)));
+        assert_eq!(oid.oid_components().unwrap(), components);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&oid).unwrap();
+        let reparsed = ASN1ObjectIdentifier::from_der_bytes(&serializer.serialized_bytes()).unwrap();
+        assert!(matches!(reparsed.storage, Storage::Shared(_)));
+        assert_eq!(reparsed, oid);
+    }
+
+    #[test]
+    fn test_oid_equality_and_hash_ignore_storage() {
+        use std::collections::hash_map::DefaultHasher;
+
+        let inline = ASN1ObjectIdentifier::new(&[2, 5, 4, 3]).unwrap();
+        let shared = ASN1ObjectIdentifier {
+            storage: Storage::Shared(Bytes::copy_from_slice(inline.as_bytes())),
+        };
+        assert_eq!(inline, shared);
+
+        let hash = |oid: &ASN1ObjectIdentifier| {
+            let mut hasher = DefaultHasher::new();
+            oid.hash(&mut hasher);
+            hasher.finish()
+        };
+        assert_eq!(hash(&inline), hash(&shared));
+        assert_eq!(format!("{:?}", inline), format!("{:?}", shared));
+    }
+
+    #[test]
+    fn test_arcs_decode_lazily() {
+        let oid = ASN1ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]).unwrap();
+        assert!(oid.arcs().eq([1, 2, 840, 113549, 1, 1, 11]));
+        assert_eq!(oid.arcs().take(2).collect::<Vec<_>>(), vec![1, 2]);
+        assert_eq!(oid.to_string(), "1.2.840.113549.1.1.11");
+
+        let oid = ASN1ObjectIdentifier::new(&[2, 5]).unwrap();
+        assert_eq!(oid.arcs().collect::<Vec<_>>(), oid.oid_components().unwrap());
+        assert_eq!(oid.to_string(), "2.5");
+
+        // Under arc 2 the second arc can be 40 or more.
+        for arcs in [&[2, 40, 1][..], &[2, 999, 3], &[2, 47]] {
+            let oid = ASN1ObjectIdentifier::new(arcs).unwrap();
+            assert!(oid.arcs().eq(arcs.iter().copied()));
+            assert_eq!(oid.oid_components().unwrap(), arcs);
+        }
+        assert_eq!(ASN1ObjectIdentifier::new(&[2, 40, 1]).unwrap().to_string(), "2.40.1");
+        assert_eq!(ASN1ObjectIdentifier::new(&[1, 39]).unwrap().to_string(), "1.39");
+
+        let empty = ASN1ObjectIdentifier { storage: Storage::from_bytes(Bytes::new()) };
+        assert_eq!(empty.arcs().next(), None);
+        assert_eq!(empty.to_string(), "");
+    }
+
+    #[test]
+    fn test_oid_hierarchy() {
+        let rsa = ASN1ObjectIdentifier::new(&[1, 2, 840, 113549]).unwrap();
+        let sha256_with_rsa = rsa.child(1).child(1).child(11);
+        assert_eq!(sha256_with_rsa, ASN1ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]).unwrap());
+        assert_eq!(sha256_with_rsa.parent().unwrap().parent().unwrap().parent().unwrap(), rsa);
+        assert_eq!(rsa.parent().unwrap(), ASN1ObjectIdentifier::new(&[1, 2, 840]).unwrap());
+        assert_eq!(ASN1ObjectIdentifier::new(&[2, 999]).unwrap().parent(), None);
+        assert_eq!(ASN1ObjectIdentifier::new(&[2, 5, 4]).unwrap().parent().unwrap().to_string(), "2.5");
+
+        assert!(rsa.is_prefix_of(&sha256_with_rsa));
+        assert!(rsa.is_prefix_of(&rsa));
+        assert!(!sha256_with_rsa.is_prefix_of(&rsa));
+        assert!(sha256_with_rsa.starts_with(&rsa));
+        // Siblings and arcs that only share leading digits are not prefixes.
+        let sibling = ASN1ObjectIdentifier::new(&[1, 2, 840, 1]).unwrap();
+        assert!(!sibling.is_prefix_of(&rsa));
+        assert!(!ASN1ObjectIdentifier::new(&[2, 1]).unwrap().is_prefix_of(&ASN1ObjectIdentifier::new(&[2, 100]).unwrap()));
+    }
+
+    #[cfg(feature = "oid-names")]
+    #[test]
+    fn test_oid_names() {
+        let oid = ASN1ObjectIdentifier::new(&[1, 2, 840, 113549, 1, 1, 11]).unwrap();
+        assert_eq!(oid.name(), Some("sha256WithRSAEncryption"));
+        assert_eq!(ASN1ObjectIdentifier::from_name("sha256WithRSAEncryption"), Some(oid.clone()));
+        assert_eq!(oid.child(1).name(), None);
+        assert_eq!(oid.parent().unwrap().name(), None);
+        assert_eq!(ASN1ObjectIdentifier::from_name("SHA256WithRSAEncryption"), None);
+
+        for (arcs, name) in crate::asn1_types::oid_names::OID_NAMES {
+            let oid = ASN1ObjectIdentifier::from_name(name).unwrap();
+            assert!(oid.arcs().eq(arcs.iter().copied()));
+            assert_eq!(oid.name(), Some(*name));
+        }
+    }
+
+    #[test]
+    fn test_from_encoded_const() {
+        static SHA256_WITH_RSA: ASN1ObjectIdentifier =
+            ASN1ObjectIdentifier::from_encoded_const(&[0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D, 0x01, 0x01, 0x0B]);
+        assert!(matches!(SHA256_WITH_RSA.storage, Storage::Static(_)));
+        assert_eq!(SHA256_WITH_RSA.to_string(), "1.2.840.113549.1.1.11");
+        assert_eq!(SHA256_WITH_RSA.parent().unwrap().to_string(), "1.2.840.113549.1.1");
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&SHA256_WITH_RSA).unwrap();
+        let parsed = ASN1ObjectIdentifier::from_der_bytes(&serializer.serialized_bytes()).unwrap();
+        assert_eq!(parsed, SHA256_WITH_RSA);
+
+        for invalid in [&[][..], &[0x2A, 0x86], &[0x2A, 0x80, 0x01], &[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x7F]] {
+            let leaked: &'static [u8] = Box::leak(invalid.to_vec().into_boxed_slice());
+            assert!(std::panic::catch_unwind(|| ASN1ObjectIdentifier::from_encoded_const(leaked)).is_err());
+        }
+    }
+
     #[test]
     fn test_read_oid_subidentifier_empty_error() {
-        let mut data = Bytes::new();
+        let mut data: &[u8] = &[];
         let res = read_oid_subidentifier(&mut data);
         assert!(res.is_err());
     }
@@ -384,7 +624,7 @@ mod tests {
     fn test_read_oid_subidentifier_round_trip_large_value() {
         let mut buf = Vec::new();
         write_oid_subidentifier(9_876_543, &mut buf);
-        let mut bytes = Bytes::from(buf.clone());
+        let mut bytes = &buf[..];
         let parsed = read_oid_subidentifier(&mut bytes).unwrap();
         assert_eq!(parsed, 9_876_543);
         assert!(bytes.is_empty());
@@ -397,7 +637,7 @@ mod tests {
         let limit = u64::MAX / 128;
         let mut buf = Vec::new();
         write_oid_subidentifier(limit, &mut buf);
-        let mut bytes = Bytes::from(buf);
+        let mut bytes = &buf[..];
         let parsed = read_oid_subidentifier(&mut bytes).unwrap();
         assert_eq!(parsed, limit);
         assert!(bytes.is_empty());
@@ -405,7 +645,7 @@ mod tests {
 
     #[test]
     fn test_read_oid_subidentifier_rejects_leading_zero_encoding() {
-        let mut data = Bytes::from_static(&[0x80, 0x01]);
+        let mut data: &[u8] = &[0x80, 0x01];
         let err = read_oid_subidentifier(&mut data).unwrap_err();
         assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
     }
@@ -414,7 +654,7 @@ mod tests {
     fn test_read_oid_subidentifier_overflow_detected() {
         let mut encoded = vec![0xFF; 10];
         encoded.push(0x7F);
-        let mut data = Bytes::from(encoded);
+        let mut data = &encoded[..];
         let err = read_oid_subidentifier(&mut data).unwrap_err();
         assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
     }
@@ -1,9 +1,10 @@
 use crate::asn1_types::ASN1Identifier;
-use crate::asn1::ASN1Node;
+use crate::asn1::{ASN1Node, EncodingRules};
 use crate::errors::{ASN1Error, ErrorCode};
 use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
 use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
-use bytes::Bytes;
+use crate::buffer::Bytes;
+use std::fmt;
 
 #[derive(Debug, Clone, PartialEq, Eq, Hash)]
 pub struct ASN1OctetString(pub Bytes);
@@ -20,6 +21,103 @@ impl From<&[u8]> for ASN1OctetString {
     }
 }
 
+impl AsRef<[u8]> for ASN1OctetString {
+    fn as_ref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl std::ops::Deref for ASN1OctetString {
+    type Target = [u8];
+
+    fn deref(&self) -> &[u8] {
+        &self.0
+    }
+}
+
+impl PartialEq<[u8]> for ASN1OctetString {
+    fn eq(&self, other: &[u8]) -> bool {
+        self.0 == other
+    }
+}
+
+impl PartialEq<&[u8]> for ASN1OctetString {
+    fn eq(&self, other: &&[u8]) -> bool {
+        self.0 == *other
+    }
+}
+
+impl<const N: usize> PartialEq<[u8; N]> for ASN1OctetString {
+    fn eq(&self, other: &[u8; N]) -> bool {
+        self.0 == other[..]
+    }
+}
+
+impl PartialEq<Vec<u8>> for ASN1OctetString {
+    fn eq(&self, other: &Vec<u8>) -> bool {
+        self.0 == other[..]
+    }
+}
+
+impl ASN1OctetString {
+    /// The bytes spelled by `hex`, pairs of hex digits in either case.
+    pub fn from_hex(hex: &str) -> Result<Self, ASN1Error> {
+        decode_hex(hex).map(ASN1OctetString::from)
+    }
+
+    /// Decodes the contents as a complete DER encoding of `T`.
+    pub fn decode_inner_der<T: DERParseable>(&self) -> Result<T, ASN1Error> {
+        T::from_der_node(crate::asn1::parse_bytes_with_rules(self.0.clone(), EncodingRules::Distinguished)?)
+    }
+
+    /// Decodes the contents as a complete BER encoding of `T`, regardless of the rules the
+    /// OCTET STRING itself was parsed with (PKCS#12, for one, wraps BER inside DER).
+    pub fn decode_inner_ber<T: BERParseable>(&self) -> Result<T, ASN1Error> {
+        T::from_ber_node(crate::asn1::parse_bytes_with_rules(self.0.clone(), EncodingRules::Basic)?)
+    }
+}
+
+impl fmt::LowerHex for ASN1OctetString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.0.iter().try_for_each(|b| write!(f, "{:02x}", b))
+    }
+}
+
+impl fmt::UpperHex for ASN1OctetString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if f.alternate() {
+            f.write_str("0x")?;
+        }
+        self.0.iter().try_for_each(|b| write!(f, "{:02X}", b))
+    }
+}
+
+/// Decodes a string of hex digit pairs, in either case.
+pub(crate) fn decode_hex(s: &str) -> Result<Vec<u8>, ASN1Error> {
+    let invalid = || {
+        ASN1Error::new(
+            ErrorCode::InvalidStringRepresentation,
+            format!("Invalid hex string {:?}: expected pairs of hex digits", s),
+            file!().to_string(),
+            line!(),
+        )
+    };
+    if !s.len().is_multiple_of(2) || !s.bytes().all(|b| b.is_ascii_hexdigit()) {
+        return Err(invalid());
+    }
+    (0..s.len()).step_by(2).map(|i| u8::from_str_radix(&s[i..i + 2], 16).map_err(|_| invalid())).collect()
+}
+
+/// Displays the contents as lowercase hex, e.g. for key identifiers.
+impl fmt::Display for ASN1OctetString {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::LowerHex::fmt(self, f)
+    }
+}
+
 impl DERParseable for ASN1OctetString {
      fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
         Self::from_der_node_with_identifier(node, ASN1OctetString::default_identifier())
@@ -81,3 +179,59 @@ impl BERImplicitlyTaggable for ASN1OctetString {
         }
     }
 }
+
+/// Adapts any byte container to encode as an OCTET STRING.
+///
+/// `Vec<u8>` on its own serializes as a SEQUENCE OF INTEGER; wrap it as
+/// `OctetStringOf<Vec<u8>>` to get byte-string semantics instead.
+#[derive(Debug, Clone, Default, PartialEq, Eq, Hash)]
+pub struct OctetStringOf<T>(pub T);
+
+impl<T> OctetStringOf<T> {
+    pub fn into_inner(self) -> T {
+        self.0
+    }
+}
+
+impl<T> From<T> for OctetStringOf<T> {
+    fn from(value: T) -> Self {
+        OctetStringOf(value)
+    }
+}
+
+impl<T: From<Bytes>> DERParseable for OctetStringOf<T> {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_der_node(node).map(|s| OctetStringOf(T::from(s.0)))
+    }
+}
+
+impl<T: AsRef<[u8]>> DERSerializable for OctetStringOf<T> {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(ASN1Identifier::OCTET_STRING, |buf| {
+            buf.extend_from_slice(self.0.as_ref());
+            Ok(())
+        })
+    }
+}
+
+impl<T: AsRef<[u8]> + From<Bytes>> DERImplicitlyTaggable for OctetStringOf<T> {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::OCTET_STRING
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_der_node_with_identifier(node, identifier).map(|s| OctetStringOf(T::from(s.0)))
+    }
+}
+
+impl<T: From<Bytes>> BERParseable for OctetStringOf<T> {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_ber_node(node).map(|s| OctetStringOf(T::from(s.0)))
+    }
+}
+impl<T: AsRef<[u8]>> BERSerializable for OctetStringOf<T> {}
+impl<T: AsRef<[u8]> + From<Bytes>> BERImplicitlyTaggable for OctetStringOf<T> {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_ber_node_with_identifier(node, identifier).map(|s| OctetStringOf(T::from(s.0)))
+    }
+}
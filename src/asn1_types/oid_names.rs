@@ -0,0 +1,75 @@
+//! Short names for well-known OIDs, following the spelling used by the defining RFCs and
+//! by OpenSSL where they agree.
+
+/// `(arcs, name)` pairs. Names are unique, so the table also serves reverse lookups.
+pub(crate) static OID_NAMES: &[(&[u64], &str)] = &[
+    // PKCS #1
+    (&[1, 2, 840, 113549, 1, 1, 1], "rsaEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 4], "md5WithRSAEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 5], "sha1WithRSAEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 10], "rsassaPss"),
+    (&[1, 2, 840, 113549, 1, 1, 11], "sha256WithRSAEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 12], "sha384WithRSAEncryption"),
+    (&[1, 2, 840, 113549, 1, 1, 13], "sha512WithRSAEncryption"),
+    // PKCS #7 and #9
+    (&[1, 2, 840, 113549, 1, 7, 1], "data"),
+    (&[1, 2, 840, 113549, 1, 7, 2], "signedData"),
+    (&[1, 2, 840, 113549, 1, 9, 1], "emailAddress"),
+    // Elliptic curves (RFC 5480, RFC 8410)
+    (&[1, 2, 840, 10045, 2, 1], "ecPublicKey"),
+    (&[1, 2, 840, 10045, 3, 1, 7], "prime256v1"),
+    (&[1, 3, 132, 0, 34], "secp384r1"),
+    (&[1, 3, 132, 0, 35], "secp521r1"),
+    (&[1, 2, 840, 10045, 4, 3, 2], "ecdsa-with-SHA256"),
+    (&[1, 2, 840, 10045, 4, 3, 3], "ecdsa-with-SHA384"),
+    (&[1, 2, 840, 10045, 4, 3, 4], "ecdsa-with-SHA512"),
+    (&[1, 3, 101, 110], "X25519"),
+    (&[1, 3, 101, 112], "Ed25519"),
+    (&[1, 3, 101, 113], "Ed448"),
+    // Hash algorithms
+    (&[1, 3, 14, 3, 2, 26], "sha1"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 1], "sha256"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 2], "sha384"),
+    (&[2, 16, 840, 1, 101, 3, 4, 2, 3], "sha512"),
+    // X.520 attribute types
+    (&[2, 5, 4, 3], "commonName"),
+    (&[2, 5, 4, 5], "serialNumber"),
+    (&[2, 5, 4, 6], "countryName"),
+    (&[2, 5, 4, 7], "localityName"),
+    (&[2, 5, 4, 8], "stateOrProvinceName"),
+    (&[2, 5, 4, 10], "organizationName"),
+    (&[2, 5, 4, 11], "organizationalUnitName"),
+    // X.509 certificate extensions
+    (&[2, 5, 29, 14], "subjectKeyIdentifier"),
+    (&[2, 5, 29, 15], "keyUsage"),
+    (&[2, 5, 29, 17], "subjectAltName"),
+    (&[2, 5, 29, 19], "basicConstraints"),
+    (&[2, 5, 29, 31], "cRLDistributionPoints"),
+    (&[2, 5, 29, 32], "certificatePolicies"),
+    (&[2, 5, 29, 35], "authorityKeyIdentifier"),
+    (&[2, 5, 29, 37], "extKeyUsage"),
+    (&[1, 3, 6, 1, 5, 5, 7, 1, 1], "authorityInfoAccess"),
+    // Extended key usages and access methods (RFC 5280)
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 1], "serverAuth"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 2], "clientAuth"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 3], "codeSigning"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 4], "emailProtection"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 8], "timeStamping"),
+    (&[1, 3, 6, 1, 5, 5, 7, 3, 9], "OCSPSigning"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 1], "ocsp"),
+    (&[1, 3, 6, 1, 5, 5, 7, 48, 2], "caIssuers"),
+];
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashSet;
+
+    #[test]
+    fn test_names_and_arcs_are_unique() {
+        let names: HashSet<_> = OID_NAMES.iter().map(|(_, name)| name).collect();
+        let arcs: HashSet<_> = OID_NAMES.iter().map(|(arcs, _)| arcs).collect();
+        assert_eq!(names.len(), OID_NAMES.len());
+        assert_eq!(arcs.len(), OID_NAMES.len());
+    }
+}
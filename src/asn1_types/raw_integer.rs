@@ -0,0 +1,162 @@
+use crate::asn1_types::enumerated::has_redundant_leading_octet;
+use std::fmt;
+
+/// An arbitrary-precision integer held as its minimal big-endian two's complement octets,
+/// the value behind [`ASN1Integer`](crate::asn1_types::ASN1Integer) in builds without the
+/// `num-bigint` feature.
+///
+/// It does no arithmetic: it converts to and from the encoding and the machine integer
+/// types, and prints in decimal. The octets are kept minimal, so the derived equality and
+/// hashing are those of the value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawInteger {
+    octets: Vec<u8>,
+}
+
+impl RawInteger {
+    /// The integer encoded by big-endian two's complement `bytes`; no bytes is zero.
+    pub fn from_signed_bytes_be(bytes: &[u8]) -> Self {
+        let mut start = 0;
+        while has_redundant_leading_octet(&bytes[start..]) {
+            start += 1;
+        }
+        let octets = if bytes.is_empty() { vec![0x00] } else { bytes[start..].to_vec() };
+        RawInteger { octets }
+    }
+
+    /// The minimal big-endian two's complement encoding.
+    pub fn to_signed_bytes_be(&self) -> Vec<u8> {
+        self.octets.clone()
+    }
+
+    pub fn is_negative(&self) -> bool {
+        self.octets[0] & 0x80 != 0
+    }
+
+    pub fn to_i64(&self) -> Option<i64> {
+        self.to_i128().and_then(|v| i64::try_from(v).ok())
+    }
+
+    pub fn to_u64(&self) -> Option<u64> {
+        self.to_u128().and_then(|v| u64::try_from(v).ok())
+    }
+
+    pub fn to_i128(&self) -> Option<i128> {
+        if self.octets.len() > 16 {
+            return None;
+        }
+        let fill = if self.is_negative() { 0xFF } else { 0x00 };
+        let mut octets = [fill; 16];
+        octets[16 - self.octets.len()..].copy_from_slice(&self.octets);
+        Some(i128::from_be_bytes(octets))
+    }
+
+    pub fn to_u128(&self) -> Option<u128> {
+        if self.is_negative() {
+            return None;
+        }
+        let bytes = self.octets.strip_prefix(&[0x00]).filter(|rest| !rest.is_empty()).unwrap_or(&self.octets);
+        if bytes.len() > 16 {
+            return None;
+        }
+        let mut octets = [0x00; 16];
+        octets[16 - bytes.len()..].copy_from_slice(bytes);
+        Some(u128::from_be_bytes(octets))
+    }
+
+    /// The magnitude as unsigned big-endian octets.
+    fn magnitude(&self) -> Vec<u8> {
+        if !self.is_negative() {
+            return self.octets.clone();
+        }
+        // Negate: invert every octet, then add one.
+        let mut magnitude: Vec<u8> = self.octets.iter().map(|b| !b).collect();
+        for octet in magnitude.iter_mut().rev() {
+            let (sum, carry) = octet.overflowing_add(1);
+            *octet = sum;
+            if !carry {
+                break;
+            }
+        }
+        magnitude
+    }
+}
+
+macro_rules! impl_from_int {
+    ($($ty:ty),+) => {
+        $(
+            impl From<$ty> for RawInteger {
+                fn from(v: $ty) -> Self {
+                    // Widen to i128 bytes with a spare sign octet so unsigned values stay positive.
+                    let value = v.to_be_bytes();
+                    let mut octets = [if v < (0 as $ty) { 0xFF } else { 0x00 }; 17];
+                    octets[17 - value.len()..].copy_from_slice(&value);
+                    RawInteger::from_signed_bytes_be(&octets)
+                }
+            }
+        )+
+    };
+}
+
+impl_from_int!(i8, i16, i32, i64, i128, isize, u8, u16, u32, u64, u128, usize);
+
+impl fmt::Display for RawInteger {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Long division of the magnitude by 10^9, collecting nine-digit groups from the right.
+        let mut magnitude = self.magnitude();
+        let mut groups = Vec::new();
+        while magnitude.iter().any(|&b| b != 0) {
+            let mut remainder = 0u64;
+            for octet in magnitude.iter_mut() {
+                let current = (remainder << 8) | u64::from(*octet);
+                *octet = (current / 1_000_000_000) as u8;
+                remainder = current % 1_000_000_000;
+            }
+            groups.push(remainder as u32);
+        }
+        let mut digits = match groups.pop() {
+            Some(first) => first.to_string(),
+            None => "0".to_string(),
+        };
+        for group in groups.iter().rev() {
+            digits.push_str(&format!("{:09}", group));
+        }
+        f.pad_integral(!self.is_negative(), "", &digits)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_raw_integer_is_minimal() {
+        assert_eq!(RawInteger::from_signed_bytes_be(&[0x00, 0x00, 0x7F]).to_signed_bytes_be(), vec![0x7F]);
+        assert_eq!(RawInteger::from_signed_bytes_be(&[0xFF, 0xFF, 0x80]).to_signed_bytes_be(), vec![0x80]);
+        assert_eq!(RawInteger::from_signed_bytes_be(&[]), RawInteger::from(0));
+        assert_eq!(RawInteger::from(255u8).to_signed_bytes_be(), vec![0x00, 0xFF]);
+        assert_eq!(RawInteger::from(-129i64).to_signed_bytes_be(), vec![0xFF, 0x7F]);
+        assert_eq!(RawInteger::from(u128::MAX).to_signed_bytes_be().len(), 17);
+    }
+
+    #[test]
+    fn test_raw_integer_conversions() {
+        assert_eq!(RawInteger::from(i64::MIN).to_i64(), Some(i64::MIN));
+        assert_eq!(RawInteger::from(u64::MAX).to_i64(), None);
+        assert_eq!(RawInteger::from(u64::MAX).to_u64(), Some(u64::MAX));
+        assert_eq!(RawInteger::from(-1).to_u64(), None);
+        assert_eq!(RawInteger::from(u128::MAX).to_u128(), Some(u128::MAX));
+        assert_eq!(RawInteger::from(u128::MAX).to_i128(), None);
+    }
+
+    #[test]
+    fn test_raw_integer_display() {
+        for value in [0i128, 7, -7, 1_000_000_000, -999_999_999, i128::MIN, i128::MAX] {
+            assert_eq!(RawInteger::from(value).to_string(), value.to_string());
+        }
+        assert_eq!(RawInteger::from(u128::MAX).to_string(), u128::MAX.to_string());
+        let big = RawInteger::from_signed_bytes_be(&[0x01, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(big.to_string(), "340282366920938463463374607431768211456");
+        assert_eq!(format!("{:>5}", RawInteger::from(-3)), "   -3");
+    }
+}
@@ -1,11 +1,33 @@
 use crate::asn1::ASN1Node;
 use crate::asn1_types::ASN1Identifier;
+use crate::asn1_types::enumerated::has_redundant_leading_octet;
+use crate::buffer::Bytes;
 use crate::der::{DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
 use crate::errors::{ASN1Error, ErrorCode};
 
+/// An ASN.1 REAL held as an `f64`.
+///
+/// Every `f64` survives a DER round trip bit for bit: finite values are written as their
+/// exact binary mantissa and exponent, subnormals included, and the infinities, NaN and
+/// minus zero have their own encodings. Decoding values with more precision than an `f64`
+/// rounds to nearest, ties to even; use [`RealParts`] to keep them exact.
 #[derive(Debug, Clone, Copy, PartialEq)]
 pub struct ASN1Real(pub f64);
 
+/// A finite REAL exactly as encoded: `mantissa × base^exponent`, negated if `negative`.
+///
+/// Binary encodings give base 2, 8 or 16, with the scale factor F folded into the mantissa,
+/// and decimal encodings give base 10. Unlike [`ASN1Real`], decoding into `RealParts` never
+/// rounds. Serializing writes the DER form of the same value: binary for bases 2, 8 and 16
+/// and decimal NR3 for base 10.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct RealParts {
+    pub negative: bool,
+    pub mantissa: u64,
+    pub base: u32,
+    pub exponent: i64,
+}
+
 impl From<f64> for ASN1Real {
     fn from(v: f64) -> Self {
         ASN1Real(v)
@@ -18,6 +40,97 @@ impl From<ASN1Real> for f64 {
     }
 }
 
+impl From<f32> for ASN1Real {
+    fn from(v: f32) -> Self {
+        ASN1Real(v.into())
+    }
+}
+
+impl ASN1Real {
+    /// The value as an `f32`, or an error if it cannot be represented exactly. The
+    /// infinities and NaN convert to their `f32` counterparts.
+    pub fn to_f32(&self) -> Result<f32, ASN1Error> {
+        let narrowed = self.0 as f32;
+        if self.0.is_nan() || f64::from(narrowed) == self.0 {
+            Ok(narrowed)
+        } else {
+            Err(ASN1Error::new(
+                ErrorCode::ValueOutOfRange,
+                format!("REAL {} is not representable as f32", self.0),
+                file!().to_string(),
+                line!(),
+            ))
+        }
+    }
+
+    /// The exact base 2 parts of a finite value, with an odd mantissa unless it is zero, or
+    /// `None` for the infinities and NaN.
+    pub fn parts(&self) -> Option<RealParts> {
+        if !self.0.is_finite() {
+            return None;
+        }
+        let (negative, mantissa, exponent) = binary_parts(self.0);
+        Some(RealParts { negative, mantissa, base: 2, exponent: exponent.into() })
+    }
+
+    /// Serializes the value with the decimal NR3 encoding in its DER form, the shortest
+    /// decimal that reads back as the same `f64`. Zero and the special values use their
+    /// usual encodings.
+    pub fn serialize_decimal(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        if self.0 == 0.0 || self.0.is_infinite() || self.0.is_nan() {
+            return self.serialize(serializer);
+        }
+        let text = canonical_decimal(self.0);
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            buf.push(0x03);
+            buf.extend_from_slice(text.as_bytes());
+            Ok(())
+        })
+    }
+}
+
+impl RealParts {
+    /// The nearest `f64`, ties to even, or an error if the magnitude is too large for one.
+    pub fn to_f64(&self) -> Result<f64, ASN1Error> {
+        let out_of_range = || {
+            ASN1Error::new(ErrorCode::ValueOutOfRange, format!("{:?} is too large for f64", self), file!().to_string(), line!())
+        };
+        if self.base == 10 {
+            let sign = if self.negative { "-" } else { "" };
+            let value: f64 = format!("{}{}e{}", sign, self.mantissa, self.exponent).parse().map_err(|_| out_of_range())?;
+            return if value.is_infinite() { Err(out_of_range()) } else { Ok(value) };
+        }
+        let log2_base = log2_base(self.base)?;
+        compose_f64(self.negative, self.mantissa, false, i128::from(self.exponent) * log2_base).ok_or_else(out_of_range)
+    }
+
+    /// The same value with the mantissa stripped of factors of the base, so that it is
+    /// zero or not a multiple of the base, or an error if the exponent overflows.
+    fn normalized(self) -> Result<Self, ASN1Error> {
+        if self.mantissa == 0 {
+            return Ok(RealParts { exponent: 0, ..self });
+        }
+        let mut parts = self;
+        while parts.mantissa.is_multiple_of(u64::from(parts.base)) {
+            parts.mantissa /= u64::from(parts.base);
+            parts.exponent = parts.exponent.checked_add(1).ok_or_else(|| {
+                ASN1Error::new(ErrorCode::ValueOutOfRange, "REAL exponent too large".to_string(), file!().to_string(), line!())
+            })?;
+        }
+        Ok(parts)
+    }
+}
+
+/// The number of bits in a power-of-two REAL base.
+fn log2_base(base: u32) -> Result<i128, ASN1Error> {
+    match base {
+        2 => Ok(1),
+        8 => Ok(3),
+        16 => Ok(4),
+        _ => Err(ASN1Error::new(ErrorCode::ValueOutOfRange, format!("REAL base must be 2, 8, 10 or 16, got {}", base), file!().to_string(), line!())),
+    }
+}
+
 impl DERParseable for ASN1Real {
     fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
         Self::from_der_node_with_identifier(node, ASN1Real::default_identifier())
@@ -29,7 +142,10 @@ impl DERSerializable for ASN1Real {
         serializer.append_primitive_node(Self::default_identifier(), |buf| {
             // Handle special cases
             if self.0 == 0.0 {
-                // Zero is encoded as zero-length content
+                // Zero is encoded as zero-length content, minus zero as 0x43
+                if self.0.is_sign_negative() {
+                    buf.push(0x43);
+                }
                 return Ok(());
             }
 
@@ -45,41 +161,13 @@ impl DERSerializable for ASN1Real {
             }
 
             if self.0.is_nan() {
-                // NaN not supported in DER
-                return Err(ASN1Error::new(
-                    ErrorCode::InvalidASN1Object,
-                    "NaN cannot be encoded in DER REAL".to_string(),
-                    file!().to_string(),
-                    line!(),
-                ));
-            }
-
-            // Binary encoding (IEEE 754 double)
-            // Format: 0x80 | sign_bit | exponent_length | mantissa
-            let bits = self.0.to_bits();
-            let sign = ((bits >> 63) & 1) as u8;
-            let exponent = ((bits >> 52) & 0x7FF) as i16 - 1023;
-            let mantissa = bits & 0x000FFFFFFFFFFFFF;
-
-            // First octet: binary encoding, base 2
-            buf.push(0x80 | (sign << 6));
-
-            // Exponent (minimal encoding)
-            if exponent >= -128 && exponent <= 127 {
-                buf.push(exponent as u8);
-            } else {
-                buf.push(((exponent >> 8) & 0xFF) as u8);
-                buf.push((exponent & 0xFF) as u8);
-            }
-
-            // Mantissa (remove trailing zeros)
-            let mantissa_bytes = mantissa.to_be_bytes();
-            let mut last_nonzero = 7;
-            while last_nonzero > 0 && mantissa_bytes[last_nonzero] == 0 {
-                last_nonzero -= 1;
+                // NOT-A-NUMBER: 0x42
+                buf.push(0x42);
+                return Ok(());
             }
-            buf.extend_from_slice(&mantissa_bytes[0..=last_nonzero]);
 
+            let (negative, mantissa, exponent) = binary_parts(self.0);
+            encode_binary(negative, mantissa, exponent.into(), buf);
             Ok(())
         })
     }
@@ -94,86 +182,404 @@ impl DERImplicitlyTaggable for ASN1Real {
         node: ASN1Node,
         identifier: ASN1Identifier,
     ) -> Result<Self, ASN1Error> {
-        if node.identifier != identifier {
+        decode(&real_content(node, identifier)?, true).map(ASN1Real)
+    }
+}
+
+impl DERParseable for RealParts {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl DERSerializable for RealParts {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        let parts = if self.base == 10 {
+            self.normalized()?
+        } else {
+            // DER only has base 2.
+            let exponent = i128::from(self.exponent) * log2_base(self.base)?;
+            let exponent = i64::try_from(exponent).map_err(|_| {
+                ASN1Error::new(ErrorCode::ValueOutOfRange, "REAL exponent too large".to_string(), file!().to_string(), line!())
+            })?;
+            RealParts { base: 2, exponent, ..*self }.normalized()?
+        };
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            if parts.mantissa == 0 {
+                if parts.negative {
+                    buf.push(0x43);
+                }
+            } else if parts.base == 10 {
+                let exponent = if parts.exponent == 0 { "+0".to_string() } else { parts.exponent.to_string() };
+                buf.push(0x03);
+                buf.extend_from_slice(format!("{}{}.E{}", if parts.negative { "-" } else { "" }, parts.mantissa, exponent).as_bytes());
+            } else {
+                encode_binary(parts.negative, parts.mantissa, parts.exponent, buf);
+            }
+            Ok(())
+        })
+    }
+}
+
+impl DERImplicitlyTaggable for RealParts {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::REAL
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Encoded::parse(&real_content(node, identifier)?, true)?.parts()
+    }
+}
+
+/// The content octets of a primitive REAL node, checked against `identifier`.
+fn real_content(node: ASN1Node, identifier: ASN1Identifier) -> Result<Bytes, ASN1Error> {
+    if node.identifier != identifier {
+        return Err(ASN1Error::new(
+            ErrorCode::UnexpectedFieldType,
+            format!("Expected {}, got {}", identifier, node.identifier),
+            file!().to_string(),
+            line!(),
+        ));
+    }
+    match node.content {
+        crate::asn1::Content::Primitive(bytes) => Ok(bytes),
+        _ => Err(ASN1Error::new(
+            ErrorCode::UnexpectedFieldType,
+            "REAL must be primitive".to_string(),
+            file!().to_string(),
+            line!(),
+        )),
+    }
+}
+
+/// REAL content octets as encoded, before any rounding.
+enum Encoded<'a> {
+    /// Zero, minus zero, the infinities and NaN.
+    Special(f64),
+    /// `mantissa × 2^scale × 2^(log2_base × exponent)`, with `mantissa` big-endian and free
+    /// of leading zero octets.
+    Binary { negative: bool, log2_base: u32, scale: u32, exponent: i64, mantissa: &'a [u8] },
+    /// `digits × 10^exponent`.
+    Decimal { negative: bool, digits: String, exponent: i64 },
+}
+
+/// Decodes REAL content octets. `canonical` applies the DER restrictions.
+fn decode(bytes: &[u8], canonical: bool) -> Result<f64, ASN1Error> {
+    Encoded::parse(bytes, canonical)?.to_f64()
+}
+
+impl<'a> Encoded<'a> {
+    /// Parses REAL content octets. `canonical` applies the DER restrictions.
+    fn parse(bytes: &'a [u8], canonical: bool) -> Result<Self, ASN1Error> {
+        // Zero-length means zero
+        let Some(&first) = bytes.first() else {
+            return Ok(Encoded::Special(0.0));
+        };
+
+        // Special values
+        if first & 0xC0 == 0x40 && bytes.len() != 1 {
             return Err(ASN1Error::new(
-                ErrorCode::UnexpectedFieldType,
-                format!("Expected {}, got {}", identifier, node.identifier),
+                ErrorCode::InvalidASN1Object,
+                "Special REAL values are a single octet".to_string(),
                 file!().to_string(),
                 line!(),
             ));
         }
+        match first {
+            0x40 => return Ok(Encoded::Special(f64::INFINITY)),
+            0x41 => return Ok(Encoded::Special(f64::NEG_INFINITY)),
+            0x42 => return Ok(Encoded::Special(f64::NAN)),
+            0x43 => return Ok(Encoded::Special(-0.0)),
+            _ => {}
+        }
 
-        match node.content {
-            crate::asn1::Content::Primitive(bytes) => {
-                // Zero-length means zero
-                if bytes.is_empty() {
-                    return Ok(ASN1Real(0.0));
-                }
+        if (first & 0x80) != 0 {
+            return parse_binary(bytes, canonical);
+        }
 
-                let first = bytes[0];
+        if first & 0xC0 == 0 {
+            return parse_decimal(first, &bytes[1..], canonical);
+        }
 
-                // Special values
-                if first == 0x40 {
-                    return Ok(ASN1Real(f64::INFINITY));
-                }
-                if first == 0x41 {
-                    return Ok(ASN1Real(f64::NEG_INFINITY));
-                }
+        Err(ASN1Error::new(
+            ErrorCode::InvalidASN1Object,
+            format!("Unsupported special REAL value 0x{:02X}", first),
+            file!().to_string(),
+            line!(),
+        ))
+    }
 
-                // Binary encoding
-                if (first & 0x80) != 0 {
-                    let sign = if (first & 0x40) != 0 { -1.0 } else { 1.0 };
-                    let exp_len = ((first & 0x03) + 1) as usize;
-
-                    if bytes.len() < 1 + exp_len {
-                        return Err(ASN1Error::new(
-                            ErrorCode::InvalidASN1Object,
-                            "REAL encoding too short".to_string(),
-                            file!().to_string(),
-                            line!(),
-                        ));
-                    }
-
-                    // Read exponent
-                    let mut exponent: i64 = 0;
-                    for i in 0..exp_len {
-                        exponent = (exponent << 8) | (bytes[1 + i] as i64);
-                    }
-                    // Sign extend
-                    if bytes[1] & 0x80 != 0 {
-                        exponent |= !0i64 << (exp_len * 8);
-                    }
-
-                    // Read mantissa
-                    let mut mantissa: u64 = 0;
-                    for &byte in &bytes[1 + exp_len..] {
-                        mantissa = (mantissa << 8) | (byte as u64);
-                    }
-
-                    // Reconstruct IEEE 754
-                    let value = sign * (mantissa as f64) * 2.0f64.powi(exponent as i32);
-                    return Ok(ASN1Real(value));
-                }
+    /// The nearest `f64`, ties to even.
+    fn to_f64(&self) -> Result<f64, ASN1Error> {
+        let out_of_range = || {
+            ASN1Error::new(ErrorCode::InvalidASN1Object, "REAL magnitude too large for f64".to_string(), file!().to_string(), line!())
+        };
+        match self {
+            Encoded::Special(value) => Ok(*value),
+            Encoded::Binary { negative, log2_base, scale, exponent, mantissa } => {
+                // Keep the top 64 bits of N and whether anything non-zero was dropped below them.
+                let dropped = mantissa.len().saturating_sub(8);
+                let top = mantissa[..mantissa.len() - dropped].iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+                let sticky = mantissa[mantissa.len() - dropped..].iter().any(|&b| b != 0);
+                let binary_exponent = i128::from(*exponent) * i128::from(*log2_base) + i128::from(*scale) + 8 * dropped as i128;
+                compose_f64(*negative, top, sticky, binary_exponent).ok_or_else(out_of_range)
+            }
+            Encoded::Decimal { negative, digits, exponent } => {
+                // Rust's parser rounds correctly; hand it the same number in its own syntax.
+                let sign = if *negative { "-" } else { "" };
+                let value: f64 = format!("{}{}e{}", sign, digits, exponent).parse().map_err(|_| out_of_range())?;
+                if value.is_infinite() { Err(out_of_range()) } else { Ok(value) }
+            }
+        }
+    }
 
-                // Decimal encoding not supported for now
-                Err(ASN1Error::new(
-                    ErrorCode::InvalidASN1Object,
-                    "Decimal REAL encoding not supported".to_string(),
-                    file!().to_string(),
-                    line!(),
-                ))
+    /// The exact parts of a finite value.
+    fn parts(&self) -> Result<RealParts, ASN1Error> {
+        let too_wide = || {
+            ASN1Error::new(ErrorCode::ValueOutOfRange, "REAL mantissa wider than 64 bits".to_string(), file!().to_string(), line!())
+        };
+        match self {
+            Encoded::Special(value) if *value == 0.0 => {
+                Ok(RealParts { negative: value.is_sign_negative(), mantissa: 0, base: 2, exponent: 0 })
             }
-            _ => Err(ASN1Error::new(
-                ErrorCode::UnexpectedFieldType,
-                "REAL must be primitive".to_string(),
+            Encoded::Special(value) => Err(ASN1Error::new(
+                ErrorCode::ValueOutOfRange,
+                format!("REAL {} has no mantissa and exponent", value),
                 file!().to_string(),
                 line!(),
             )),
+            Encoded::Binary { negative, log2_base, scale, exponent, mantissa } => {
+                if mantissa.len() > 8 {
+                    return Err(too_wide());
+                }
+                let mantissa = mantissa.iter().fold(0u64, |acc, &b| (acc << 8) | u64::from(b));
+                if mantissa.leading_zeros() < *scale {
+                    return Err(too_wide());
+                }
+                Ok(RealParts { negative: *negative, mantissa: mantissa << scale, base: 1 << log2_base, exponent: *exponent })
+            }
+            Encoded::Decimal { negative, digits, exponent } => {
+                let mantissa = digits.parse().map_err(|_| too_wide())?;
+                Ok(RealParts { negative: *negative, mantissa, base: 10, exponent: *exponent })
+            }
+        }
+    }
+}
+
+/// The sign, mantissa and base 2 exponent of a finite `value`, with the mantissa odd unless
+/// it is zero.
+fn binary_parts(value: f64) -> (bool, u64, i32) {
+    let bits = value.to_bits();
+    let negative = bits >> 63 != 0;
+    let biased = ((bits >> 52) & 0x7FF) as i32;
+    let fraction = bits & 0x000F_FFFF_FFFF_FFFF;
+    let (mantissa, exponent) = if biased == 0 { (fraction, -1074) } else { (fraction | 1 << 52, biased - 1075) };
+    if mantissa == 0 {
+        return (negative, 0, 0);
+    }
+    let zeros = mantissa.trailing_zeros();
+    (negative, mantissa >> zeros, exponent + zeros as i32)
+}
+
+/// Appends the DER binary form (X.690 11.3.1) of a non-zero value: base 2, no scale
+/// factor, an odd mantissa and the shortest exponent.
+fn encode_binary(negative: bool, mantissa: u64, exponent: i64, buf: &mut Vec<u8>) {
+    let exponent_bytes = exponent.to_be_bytes();
+    let mut start = 0;
+    while has_redundant_leading_octet(&exponent_bytes[start..]) {
+        start += 1;
+    }
+    let exponent_bytes = &exponent_bytes[start..];
+    let sign = if negative { 0x40 } else { 0x00 };
+    if exponent_bytes.len() <= 3 {
+        buf.push(0x80 | sign | (exponent_bytes.len() as u8 - 1));
+    } else {
+        buf.push(0x80 | sign | 0x03);
+        buf.push(exponent_bytes.len() as u8);
+    }
+    buf.extend_from_slice(exponent_bytes);
+    let mantissa_bytes = mantissa.to_be_bytes();
+    buf.extend_from_slice(&mantissa_bytes[mantissa.leading_zeros() as usize / 8..]);
+}
+
+/// Parses the binary form S × N × 2^F × B^E (X.690 8.5.7): the first octet holds the sign,
+/// the base B (2, 8 or 16), the scale factor F and the format of the exponent E, which is
+/// followed by the mantissa N.
+///
+/// DER only admits the form [`encode_binary`] produces.
+fn parse_binary(bytes: &[u8], canonical: bool) -> Result<Encoded<'_>, ASN1Error> {
+    let invalid = |reason: &str| {
+        ASN1Error::new(ErrorCode::InvalidASN1Object, format!("Invalid binary REAL: {}", reason), file!().to_string(), line!())
+    };
+    let first = bytes[0];
+    let negative = first & 0x40 != 0;
+    let log2_base = match (first >> 4) & 0x03 {
+        0 => 1,
+        1 => 3,
+        2 => 4,
+        _ => return Err(invalid("reserved base")),
+    };
+    let scale = u32::from((first >> 2) & 0x03);
+    let (exponent_octets, mantissa) = match first & 0x03 {
+        0x03 => {
+            let length = usize::from(*bytes.get(1).ok_or_else(|| invalid("missing exponent length"))?);
+            if length == 0 {
+                return Err(invalid("empty exponent"));
+            }
+            let exponent = bytes.get(2..2 + length).ok_or_else(|| invalid("encoding too short"))?;
+            (exponent, &bytes[2 + length..])
+        }
+        format => {
+            let length = usize::from(format) + 1;
+            let exponent = bytes.get(1..1 + length).ok_or_else(|| invalid("encoding too short"))?;
+            (exponent, &bytes[1 + length..])
         }
+    };
+    if exponent_octets.len() > 8 {
+        return Err(invalid("exponent does not fit in 64 bits"));
     }
+    if canonical {
+        if first & 0x3C != 0 {
+            return Err(invalid("DER requires base 2 and no scale factor"));
+        }
+        if exponent_octets.len() <= 3 && first & 0x03 == 0x03 || has_redundant_leading_octet(exponent_octets) {
+            return Err(invalid("DER requires the shortest exponent"));
+        }
+        if mantissa.first().is_none_or(|&b| b == 0) || mantissa.last().is_some_and(|&b| b & 1 == 0) {
+            return Err(invalid("DER requires an odd mantissa without leading zero octets"));
+        }
+    }
+    let exponent = exponent_octets.iter().fold(if exponent_octets[0] & 0x80 != 0 { -1i64 } else { 0 }, |acc, &b| (acc << 8) | i64::from(b));
+    let mantissa = &mantissa[mantissa.iter().take_while(|&&b| b == 0).count()..];
+    Ok(Encoded::Binary { negative, log2_base, scale, exponent, mantissa })
+}
+
+/// `±mantissa × 2^exponent` rounded to the nearest `f64`, ties to even, where `sticky` marks
+/// non-zero bits below the mantissa. `None` if the result overflows.
+fn compose_f64(negative: bool, mantissa: u64, sticky: bool, exponent: i128) -> Option<f64> {
+    let sign = if negative { 1u64 << 63 } else { 0 };
+    if mantissa == 0 {
+        return Some(f64::from_bits(sign));
+    }
+    // Normalize so the leading bit is bit 63; `top` is then the exponent of that bit.
+    let shift = mantissa.leading_zeros();
+    let normalized = u128::from(mantissa << shift) << 64;
+    let top = exponent + 63 - i128::from(shift);
+    if top > 1023 {
+        return None;
+    }
+    // Keep 53 bits for normal results and fewer for subnormal ones.
+    let dropped_bits = 128 - 53 + (-1022 - top).max(0);
+    let (kept, rest, half) = if dropped_bits >= 128 {
+        (0, normalized, if dropped_bits == 128 { 1u128 << 127 } else { u128::MAX })
+    } else {
+        (normalized >> dropped_bits, normalized & ((1u128 << dropped_bits) - 1), 1u128 << (dropped_bits - 1))
+    };
+    let round_up = rest > half || (rest == half && (sticky || kept & 1 == 1));
+    let kept = (kept + u128::from(round_up)) as u64;
+    // A carry out of the 53 bits moves into the exponent field, up to infinity.
+    let bits = if top >= -1022 { (((top + 1022) as u64) << 52) + kept } else { kept };
+    if bits >= 0x7FF0_0000_0000_0000 {
+        return None;
+    }
+    Some(f64::from_bits(sign | bits))
+}
+
+/// Parses the ISO 6093 decimal forms: NR1 (`-12`), NR2 (`-1.25`) and NR3 (`-125E-2`),
+/// with leading spaces, an optional sign and a full stop or comma as decimal mark.
+///
+/// DER (X.690 11.3.1) only admits NR3 with no spaces or `+` in the mantissa, which is an
+/// integer without leading or trailing zeros followed by `.`, and an `E` exponent without
+/// leading zeros that is written `+0` when zero.
+fn parse_decimal(first: u8, text: &[u8], canonical: bool) -> Result<Encoded<'static>, ASN1Error> {
+    let invalid = |reason: &str| {
+        ASN1Error::new(
+            ErrorCode::InvalidASN1Object,
+            format!("Invalid decimal REAL {:?}: {}", String::from_utf8_lossy(text), reason),
+            file!().to_string(),
+            line!(),
+        )
+    };
+    let form = first & 0x3F;
+    if !(1..=3).contains(&form) {
+        return Err(invalid("unknown number representation"));
+    }
+    if canonical && form != 3 {
+        return Err(invalid("DER requires the NR3 form"));
+    }
+    let text = std::str::from_utf8(text).map_err(|_| invalid("not ASCII"))?;
+
+    let unspaced = text.trim_start_matches(' ');
+    if canonical && unspaced.len() != text.len() {
+        return Err(invalid("DER forbids leading spaces"));
+    }
+    let (negative, unsigned) = match unspaced.as_bytes().first() {
+        Some(b'-') => (true, &unspaced[1..]),
+        Some(b'+') if canonical => return Err(invalid("DER forbids a + sign on the mantissa")),
+        Some(b'+') => (false, &unspaced[1..]),
+        _ => (false, unspaced),
+    };
+    let (mantissa, exponent) = match unsigned.find(['E', 'e']) {
+        Some(_) if form != 3 => return Err(invalid("only NR3 has an exponent")),
+        Some(i) => (&unsigned[..i], Some(&unsigned[i + 1..])),
+        None if form == 3 => return Err(invalid("NR3 needs an exponent")),
+        None => (unsigned, None),
+    };
+    if canonical && unsigned.contains('e') {
+        return Err(invalid("DER requires an upper-case E"));
+    }
+
+    let (integer, fraction) = match mantissa.find(['.', ',']) {
+        Some(_) if form == 1 => return Err(invalid("NR1 has no decimal mark")),
+        Some(i) => (&mantissa[..i], &mantissa[i + 1..]),
+        None if form == 2 => return Err(invalid("NR2 needs a decimal mark")),
+        None => (mantissa, ""),
+    };
+    let all_digits = |s: &str| s.bytes().all(|b| b.is_ascii_digit());
+    if integer.len() + fraction.len() == 0 || !all_digits(integer) || !all_digits(fraction) {
+        return Err(invalid("malformed mantissa"));
+    }
+    if canonical
+        && (!mantissa.ends_with('.') || integer.starts_with('0') || integer.ends_with('0') || integer.is_empty())
+    {
+        return Err(invalid("DER requires an integer mantissa without leading or trailing zeros, followed by ."));
+    }
+
+    let exponent: i64 = match exponent {
+        None => 0,
+        Some(exponent) => {
+            let digits = exponent.strip_prefix(['+', '-']).unwrap_or(exponent);
+            if digits.is_empty() || !all_digits(digits) {
+                return Err(invalid("malformed exponent"));
+            }
+            if canonical
+                && (digits.len() > 1 && digits.starts_with('0')
+                    || (digits == "0") != exponent.starts_with('+'))
+            {
+                return Err(invalid("DER requires an exponent without leading zeros, +0 when zero"));
+            }
+            exponent.parse().map_err(|_| invalid("exponent does not fit in 64 bits"))?
+        }
+    };
+
+    let digits = format!("{}{}", integer, fraction);
+    let exponent = exponent.checked_sub(fraction.len() as i64).ok_or_else(|| invalid("exponent does not fit in 64 bits"))?;
+    Ok(Encoded::Decimal { negative, digits, exponent })
+}
+
+/// The DER (X.690 11.3.1) NR3 form of a finite, non-zero `value`, e.g. `15.E-1` for 1.5.
+fn canonical_decimal(value: f64) -> String {
+    // `{:e}` gives the shortest digits that round-trip, as `1.5e0`.
+    let formatted = format!("{:e}", value);
+    let (mantissa, exponent) = formatted.split_once('e').expect("exponent notation");
+    let (sign, mantissa) = mantissa.strip_prefix('-').map_or(("", mantissa), |m| ("-", m));
+    let (integer, fraction) = mantissa.split_once('.').unwrap_or((mantissa, ""));
+    let exponent: i64 = exponent.parse::<i64>().expect("integer exponent") - fraction.len() as i64;
+    let exponent = if exponent == 0 { "+0".to_string() } else { exponent.to_string() };
+    format!("{}{}{}.E{}", sign, integer, fraction, exponent)
 }
 
-// BER support (same as DER for REAL)
+// BER support: as DER, plus the non-canonical binary and decimal forms
 use crate::ber::{BERImplicitlyTaggable, BERParseable, BERSerializable};
 
 impl BERParseable for ASN1Real {
@@ -189,8 +595,21 @@ impl BERImplicitlyTaggable for ASN1Real {
         node: ASN1Node,
         identifier: ASN1Identifier,
     ) -> Result<Self, ASN1Error> {
-        // BER allows same encoding as DER for REAL
-        Self::from_der_node_with_identifier(node, identifier)
+        decode(&real_content(node, identifier)?, false).map(ASN1Real)
+    }
+}
+
+impl BERParseable for RealParts {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_ber_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl BERSerializable for RealParts {}
+
+impl BERImplicitlyTaggable for RealParts {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Encoded::parse(&real_content(node, identifier)?, false)?.parts()
     }
 }
 
@@ -214,4 +633,263 @@ mod tests {
         pos_inf.serialize(&mut serializer).unwrap();
         neg_inf.serialize(&mut serializer).unwrap();
     }
+
+    fn real(content: &[u8]) -> Vec<u8> {
+        [&[0x09, content.len() as u8][..], content].concat()
+    }
+
+    #[test]
+    fn test_real_decimal_forms() {
+        use crate::ber::BERParseable;
+
+        for (content, expected) in [
+            (&b"\x01  -12"[..], -12.0),
+            (b"\x01+7", 7.0),
+            (b"\x02 1,25", 1.25),
+            (b"\x02-.5", -0.5),
+            (b"\x024.", 4.0),
+            (b"\x03 125e-2", 1.25),
+            (b"\x03-0.15E+1", -1.5),
+            (b"\x031.E300", 1e300),
+        ] {
+            let node = crate::ber::parse(&real(content)).unwrap();
+            assert_eq!(ASN1Real::from_ber_node(node).unwrap().0, expected, "{:?}", content);
+        }
+        for bad in [&b"\x01"[..], b"\x011.5", b"\x0215", b"\x03125", b"\x031.5E", b"\x04 1", b"\x02.", b"\x031x.E1"] {
+            let node = crate::ber::parse(&real(bad)).unwrap();
+            assert!(ASN1Real::from_ber_node(node).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_real_decimal_der_form() {
+        for (content, expected) in [(&b"\x0315.E-1"[..], 1.5), (b"\x031.E+0", 1.0), (b"\x03-1.E2", -100.0)] {
+            assert_eq!(ASN1Real::from_der_bytes(&real(content)).unwrap().0, expected, "{:?}", content);
+        }
+        for bad in [
+            &b"\x0215."[..],  // NR2
+            b"\x03 15.E-1",   // space
+            b"\x03+15.E-1",   // + on the mantissa
+            b"\x03015.E-1",   // leading zero
+            b"\x03150.E-2",   // trailing zero
+            b"\x031.5E0",     // fractional mantissa
+            b"\x0315E-1",     // no decimal mark
+            b"\x0315.e-1",    // lowercase exponent mark
+            b"\x031.E0",      // zero exponent without +
+            b"\x031.E-0",
+            b"\x031.E+2",     // + on a non-zero exponent
+            b"\x031.E02",     // leading zero in the exponent
+            b"\x030.E+0",     // zero
+        ] {
+            assert!(ASN1Real::from_der_bytes(&real(bad)).is_err(), "{:?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_real_decimal_serialization() {
+        for (value, text) in [(1.5, "15.E-1"), (-100.0, "-1.E2"), (1.0, "1.E+0"), (0.1, "1.E-1"), (123456.789, "123456789.E-3")] {
+            let mut serializer = Serializer::new();
+            ASN1Real(value).serialize_decimal(&mut serializer).unwrap();
+            let encoded = serializer.serialized_bytes();
+            assert_eq!(&encoded[3..], text.as_bytes(), "{}", value);
+            assert_eq!(ASN1Real::from_der_bytes(&encoded).unwrap().0, value);
+        }
+        for value in [f64::MIN_POSITIVE, f64::MAX, -5e-324, std::f64::consts::PI] {
+            let mut serializer = Serializer::new();
+            ASN1Real(value).serialize_decimal(&mut serializer).unwrap();
+            assert_eq!(ASN1Real::from_der_bytes(&serializer.serialized_bytes()).unwrap().0.to_bits(), value.to_bits());
+        }
+    }
+
+    fn decode_ber(content: &[u8]) -> Result<f64, ASN1Error> {
+        use crate::ber::BERParseable;
+        ASN1Real::from_ber_node(crate::ber::parse(&real(content))?).map(|r| r.0)
+    }
+
+    #[test]
+    fn test_real_binary_bases_and_scale() {
+        for (content, expected) in [
+            (&[0x80, 0x01, 0x03][..], 6.0),             // 3 × 2^1
+            (&[0x90, 0x01, 0x01][..], 8.0),             // base 8
+            (&[0xA0, 0xFF, 0x10][..], 1.0),             // base 16, 16 × 16^-1
+            (&[0x88, 0x00, 0x01][..], 4.0),             // F = 2
+            (&[0xDC, 0x01, 0x03][..], -3.0 * 8.0 * 8.0), // -, base 8, F = 3
+            (&[0x81, 0xFF, 0xFE, 0x01][..], 0.5f64.powi(2)), // two exponent octets
+            (&[0x83, 0x01, 0x02, 0x03][..], 12.0),      // exponent length octet
+            (&[0x80, 0x00, 0x00, 0x00, 0x05][..], 5.0), // leading zero mantissa octets
+        ] {
+            assert_eq!(decode_ber(content).unwrap(), expected, "{:02X?}", content);
+        }
+        for bad in [&[0xB0, 0x00, 0x01][..], &[0x81, 0x00][..], &[0x83][..], &[0x83, 0x00, 0x01], &[0x83, 0x02, 0x01]] {
+            assert!(decode_ber(bad).is_err(), "{:02X?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_real_binary_rounding() {
+        // 2^64 + 1 needs more than 64 bits of mantissa and rounds to 2^64.
+        let content = [0x80, 0x00, 0x01, 0, 0, 0, 0, 0, 0, 0, 0x01];
+        assert_eq!(decode_ber(&content).unwrap(), 2f64.powi(64));
+        // (2^53 + 1) × 2^0 is a tie and rounds to even; (2^53 + 3) rounds up.
+        let tie = (1u64 << 53) + 1;
+        let content = [&[0x80, 0x00][..], &tie.to_be_bytes()[1..]].concat();
+        assert_eq!(decode_ber(&content).unwrap(), (1u64 << 53) as f64);
+        let content = [&[0x80, 0x00][..], &(tie + 2).to_be_bytes()[1..]].concat();
+        assert_eq!(decode_ber(&content).unwrap(), ((1u64 << 53) + 4) as f64);
+
+        // Subnormals: 1 × 2^-1074 is the smallest, 3 × 2^-1075 ties to 2 × 2^-1074.
+        assert_eq!(decode_ber(&[0x81, 0xFB, 0xCE, 0x01]).unwrap(), 5e-324);
+        assert_eq!(decode_ber(&[0x81, 0xFB, 0xCD, 0x03]).unwrap(), 1e-323);
+        assert_eq!(decode_ber(&[0x81, 0xF0, 0x00, 0x01]).unwrap(), 0.0);
+
+        // 2^1023 is fine, 2^1024 is not an f64.
+        assert_eq!(decode_ber(&[0x81, 0x03, 0xFF, 0x01]).unwrap(), 2f64.powi(1023));
+        assert!(decode_ber(&[0x81, 0x04, 0x00, 0x01]).is_err());
+        assert!(decode_ber(&[0x81, 0x03, 0xFF, 0x02]).is_err());
+    }
+
+    #[test]
+    fn test_real_der_canonical_binary() {
+        for (value, content) in [
+            (1.0, &[0x80, 0x00, 0x01][..]),
+            (-6.0, &[0xC0, 0x01, 0x03][..]),
+            (0.375, &[0x80, 0xFD, 0x03][..]),
+            (1e300, &[0x81, 0x03, 0xB2, 0x05, 0xF9, 0x0F, 0x22, 0x00, 0x1D, 0x67][..]),
+            (5e-324, &[0x81, 0xFB, 0xCE, 0x01][..]),
+        ] {
+            let mut serializer = Serializer::new();
+            ASN1Real(value).serialize(&mut serializer).unwrap();
+            let encoded = serializer.serialized_bytes();
+            assert_eq!(&encoded[2..], content, "{}", value);
+            assert_eq!(ASN1Real::from_der_bytes(&encoded).unwrap().0, value);
+        }
+        for value in [f64::MAX, f64::MIN_POSITIVE, -std::f64::consts::E, 1.0 / 3.0, 2f64.powi(-1060) * 3.0] {
+            let mut serializer = Serializer::new();
+            ASN1Real(value).serialize(&mut serializer).unwrap();
+            assert_eq!(ASN1Real::from_der_bytes(&serializer.serialized_bytes()).unwrap().0.to_bits(), value.to_bits());
+        }
+
+        for bad in [
+            &[0x90, 0x00, 0x01][..],       // base 8
+            &[0x84, 0x00, 0x01][..],       // F = 1
+            &[0x80, 0x00, 0x02][..],       // even mantissa
+            &[0x80, 0x00, 0x00, 0x01][..], // leading zero octet in the mantissa
+            &[0x80, 0x00][..],             // no mantissa
+            &[0x81, 0x00, 0x01, 0x01][..], // long exponent
+            &[0x83, 0x01, 0x01, 0x01][..], // long exponent form for one octet
+        ] {
+            assert!(ASN1Real::from_der_bytes(&real(bad)).is_err(), "{:02X?}", bad);
+            assert!(decode_ber(bad).is_ok(), "{:02X?}", bad);
+        }
+    }
+
+    #[test]
+    fn test_real_nan_and_minus_zero() {
+        let mut serializer = Serializer::new();
+        ASN1Real(f64::NAN).serialize(&mut serializer).unwrap();
+        ASN1Real(-0.0).serialize(&mut serializer).unwrap();
+        ASN1Real(0.0).serialize(&mut serializer).unwrap();
+        assert_eq!(serializer.serialized_bytes().to_vec(), vec![0x09, 0x01, 0x42, 0x09, 0x01, 0x43, 0x09, 0x00]);
+
+        assert!(decode_ber(&[0x42]).unwrap().is_nan());
+        let minus_zero = decode_ber(&[0x43]).unwrap();
+        assert!(minus_zero == 0.0 && minus_zero.is_sign_negative());
+        assert!(decode_ber(&[]).unwrap().is_sign_positive());
+        assert!(ASN1Real::from_der_bytes(&real(&[0x43])).unwrap().0.is_sign_negative());
+
+        assert!(decode_ber(&[0x43, 0x00]).is_err());
+        assert!(decode_ber(&[0x44]).is_err());
+    }
+
+    #[test]
+    fn test_real_parts() {
+        use crate::ber::BERParseable;
+        let ber_parts = |content: &[u8]| RealParts::from_ber_node(crate::ber::parse(&real(content))?);
+
+        // Base 8, F = 1: 3 × 2 × 8^1, kept exact rather than rounded to 48.0.
+        let parts = ber_parts(&[0x94, 0x01, 0x03]).unwrap();
+        assert_eq!(parts, RealParts { negative: false, mantissa: 6, base: 8, exponent: 1 });
+        assert_eq!(parts.to_f64().unwrap(), 48.0);
+        assert_eq!(ber_parts(&[0x03, b'-', b'1', b'2', b'5', b'E', b'-', b'2']).unwrap(), RealParts { negative: true, mantissa: 125, base: 10, exponent: -2 });
+        assert_eq!(ber_parts(&[0x02, b'1', b'.', b'2', b'5']).unwrap(), RealParts { negative: false, mantissa: 125, base: 10, exponent: -2 });
+        assert_eq!(ber_parts(&[0x43]).unwrap(), RealParts { negative: true, mantissa: 0, base: 2, exponent: 0 });
+        assert!(ber_parts(&[0x40]).is_err());
+        assert!(ber_parts(&[0x80, 0x00, 1, 0, 0, 0, 0, 0, 0, 0, 1]).is_err());
+        assert!(ber_parts(&[0x8C, 0x00, 0x80, 0, 0, 0, 0, 0, 0, 0]).is_err());
+
+        // A decimal too precise for f64 keeps every digit.
+        let content = b"\x0312345678901234567891.E-2";
+        assert_eq!(ber_parts(content).unwrap().mantissa, 12345678901234567891);
+        assert!(RealParts { negative: false, mantissa: 1, base: 10, exponent: 400 }.to_f64().is_err());
+
+        assert_eq!(ASN1Real(1.5).parts(), Some(RealParts { negative: false, mantissa: 3, base: 2, exponent: -1 }));
+        assert!(ASN1Real(-0.0).parts().unwrap().negative);
+        assert_eq!(ASN1Real(f64::NAN).parts(), None);
+
+        // Serializing gives DER: base 2 with an odd mantissa, or NR3.
+        let mut serializer = Serializer::new();
+        RealParts { negative: false, mantissa: 6, base: 8, exponent: 1 }.serialize(&mut serializer).unwrap();
+        RealParts { negative: false, mantissa: 1200, base: 10, exponent: 0 }.serialize(&mut serializer).unwrap();
+        RealParts { negative: false, mantissa: 0, base: 10, exponent: 7 }.serialize(&mut serializer).unwrap();
+        let encoded = serializer.serialized_bytes();
+        let mut expected = vec![0x09, 0x03, 0x80, 0x04, 0x03, 0x09, 0x06, 0x03];
+        expected.extend_from_slice(b"12.E2");
+        expected.extend_from_slice(&[0x09, 0x00]);
+        assert_eq!(encoded.to_vec(), expected);
+        let parser = crate::der::parse(&encoded[..5]).unwrap();
+        assert_eq!(RealParts::from_der_node(parser).unwrap(), RealParts { negative: false, mantissa: 3, base: 2, exponent: 4 });
+        assert_eq!(RealParts::from_der_bytes(&encoded[5..13]).unwrap(), RealParts { negative: false, mantissa: 12, base: 10, exponent: 2 });
+
+        // Normalizing an even mantissa can push the exponent past i64::MAX.
+        let binary = ber_parts(&[0x83, 0x08, 0x7F, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0x02]).unwrap();
+        let decimal = ber_parts(b"\x0320E9223372036854775807").unwrap();
+        for parts in [binary, decimal] {
+            assert_eq!(parts.exponent, i64::MAX);
+            let err = parts.serialize(&mut Serializer::new()).unwrap_err();
+            assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+        }
+    }
+
+    #[test]
+    fn test_real_f32() {
+        for value in [1.5f32, -0.1, f32::MAX, f32::MIN_POSITIVE, f32::from_bits(1), f32::INFINITY] {
+            let mut serializer = Serializer::new();
+            ASN1Real::from(value).serialize(&mut serializer).unwrap();
+            let decoded = ASN1Real::from_der_bytes(&serializer.serialized_bytes()).unwrap();
+            assert_eq!(decoded.to_f32().unwrap().to_bits(), value.to_bits());
+        }
+        assert!(ASN1Real(f64::NAN).to_f32().unwrap().is_nan());
+        assert!(ASN1Real(-0.0).to_f32().unwrap().is_sign_negative());
+        assert!(ASN1Real(0.1).to_f32().is_err());
+        assert!(ASN1Real(1e300).to_f32().is_err());
+        assert!(ASN1Real(1e-50).to_f32().is_err());
+    }
+
+    #[test]
+    fn test_real_f64_round_trip_is_bit_exact() {
+        let round_trip = |value: f64| {
+            let mut serializer = Serializer::new();
+            ASN1Real(value).serialize(&mut serializer).unwrap();
+            ASN1Real::from_der_bytes(&serializer.serialized_bytes()).unwrap().0
+        };
+        let mut values = vec![
+            f64::MAX, f64::MIN, f64::MIN_POSITIVE, f64::EPSILON, f64::from_bits(1), f64::from_bits(0x000F_FFFF_FFFF_FFFF),
+            -f64::from_bits(1), 0.0, -0.0, f64::INFINITY, f64::NEG_INFINITY, 1.0, -1.0, 0.1, 1.0 / 3.0,
+        ];
+        // A spread of bit patterns, from a fixed xorshift sequence.
+        let mut state = 0x9E37_79B9_7F4A_7C15u64;
+        for _ in 0..2000 {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            values.push(f64::from_bits(state));
+        }
+        for value in values {
+            if value.is_nan() {
+                assert!(round_trip(value).is_nan());
+            } else {
+                assert_eq!(round_trip(value).to_bits(), value.to_bits(), "{:e}", value);
+            }
+        }
+    }
 }
@@ -0,0 +1,202 @@
+use crate::asn1_types::ASN1Identifier;
+use crate::asn1::ASN1Node;
+use crate::errors::{ASN1Error, ErrorCode};
+use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
+use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
+use crate::buffer::Bytes;
+use std::hash::{Hash, Hasher};
+
+/// A SEQUENCE OF that keeps every element's original encoding next to its decoded value.
+///
+/// Serializing re-emits the stored encodings verbatim, so a parsed sequence round-trips
+/// byte-exactly, BER quirks included, when re-emitting signed structures. Equality and
+/// hashing look only at the encodings, which is cheap and needs nothing from `T`.
+#[derive(Debug, Clone)]
+pub struct ASN1SequenceOf<T> {
+    values: Vec<T>,
+    encodings: Vec<Bytes>,
+}
+
+impl<T: DERSerializable> ASN1SequenceOf<T> {
+    /// Builds a sequence from values, encoding each one in DER.
+    pub fn from_values(values: Vec<T>) -> Result<Self, ASN1Error> {
+        let encodings = values
+            .iter()
+            .map(|value| {
+                let mut serializer = Serializer::new();
+                serializer.serialize(value)?;
+                Ok(serializer.serialized_bytes())
+            })
+            .collect::<Result<Vec<_>, ASN1Error>>()?;
+        Ok(ASN1SequenceOf { values, encodings })
+    }
+
+    /// Appends a value, encoding it in DER.
+    pub fn push(&mut self, value: T) -> Result<(), ASN1Error> {
+        let mut serializer = Serializer::new();
+        serializer.serialize(&value)?;
+        self.encodings.push(serializer.serialized_bytes());
+        self.values.push(value);
+        Ok(())
+    }
+}
+
+impl<T> ASN1SequenceOf<T> {
+    pub fn new() -> Self {
+        ASN1SequenceOf { values: Vec::new(), encodings: Vec::new() }
+    }
+
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The encoded bytes of each element, in the same order as [`values`](Self::values).
+    pub fn encodings(&self) -> &[Bytes] {
+        &self.encodings
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &Bytes)> {
+        self.values.iter().zip(self.encodings.iter())
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+
+    fn from_node_with<F>(node: ASN1Node, identifier: ASN1Identifier, decode: F) -> Result<Self, ASN1Error>
+    where
+        F: Fn(ASN1Node) -> Result<T, ASN1Error>,
+    {
+        if node.identifier != identifier {
+            return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
+        }
+        match node.content {
+            crate::asn1::Content::Constructed(collection) => {
+                let mut values = Vec::new();
+                let mut encodings = Vec::new();
+                for child in collection {
+                    encodings.push(child.encoded_bytes.clone());
+                    values.push(decode(child)?);
+                }
+                Ok(ASN1SequenceOf { values, encodings })
+            }
+            _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "SEQUENCE OF must be constructed".to_string(), file!().to_string(), line!())),
+        }
+    }
+}
+
+impl<T> Default for ASN1SequenceOf<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> PartialEq for ASN1SequenceOf<T> {
+    fn eq(&self, other: &Self) -> bool {
+        self.encodings == other.encodings
+    }
+}
+
+impl<T> Eq for ASN1SequenceOf<T> {}
+
+impl<T> Hash for ASN1SequenceOf<T> {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.encodings.hash(state)
+    }
+}
+
+impl<T: DERParseable> DERParseable for ASN1SequenceOf<T> {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, ASN1Identifier::SEQUENCE, T::from_der_node)
+    }
+}
+
+impl<T> DERSerializable for ASN1SequenceOf<T> {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_constructed_node(ASN1Identifier::SEQUENCE, |nested| {
+            for encoding in &self.encodings {
+                nested.append_encoded_node(encoding)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<T: DERParseable> DERImplicitlyTaggable for ASN1SequenceOf<T> {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::SEQUENCE
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, identifier, T::from_der_node)
+    }
+}
+
+impl<T: BERParseable> BERParseable for ASN1SequenceOf<T> {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, ASN1Identifier::SEQUENCE, T::from_ber_node)
+    }
+}
+
+impl<T> BERSerializable for ASN1SequenceOf<T> {}
+
+impl<T: BERParseable> BERImplicitlyTaggable for ASN1SequenceOf<T> {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, identifier, T::from_ber_node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_types::ASN1Integer;
+    use crate::ber;
+
+    #[test]
+    fn test_sequence_of_ber_round_trips_original_bytes() {
+        // SEQUENCE { INTEGER 1 with a non-minimal length, INTEGER 2 }
+        let data = vec![0x30, 0x07, 0x02, 0x81, 0x01, 0x01, 0x02, 0x01, 0x02];
+        let node = ber::parse(&data).unwrap();
+        let sequence = ASN1SequenceOf::<ASN1Integer>::from_ber_node(node).unwrap();
+        assert_eq!(sequence.values(), &[ASN1Integer::from(1), ASN1Integer::from(2)]);
+        assert_eq!(sequence.encodings()[0].as_ref(), &[0x02, 0x81, 0x01, 0x01]);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&sequence).unwrap();
+        assert_eq!(serializer.serialized_bytes(), data);
+
+        // Same values, different bytes: not equal.
+        let canonical = ASN1SequenceOf::from_values(vec![ASN1Integer::from(1), ASN1Integer::from(2)]).unwrap();
+        assert_ne!(sequence, canonical);
+    }
+
+    #[test]
+    fn test_sequence_of_from_values_keeps_order() {
+        let mut sequence = ASN1SequenceOf::from_values(vec![300i64, 5]).unwrap();
+        sequence.push(-1).unwrap();
+        assert_eq!(sequence.values(), &[300, 5, -1]);
+        assert_eq!(sequence.len(), 3);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&sequence).unwrap();
+        let encoded = serializer.serialized_bytes();
+        assert_eq!(encoded, vec![0x30, 0x0A, 0x02, 0x02, 0x01, 0x2C, 0x02, 0x01, 0x05, 0x02, 0x01, 0xFF]);
+        assert_eq!(ASN1SequenceOf::<i64>::from_der_bytes(&encoded).unwrap(), sequence);
+        assert!(ASN1SequenceOf::<i64>::new().is_empty());
+    }
+
+    #[test]
+    fn test_sequence_of_rejects_wrong_identifier_and_primitive() {
+        assert!(ASN1SequenceOf::<ASN1Integer>::from_der_bytes(&[0x31, 0x00]).is_err());
+        let node = crate::der::parse(&[0x10, 0x00]).unwrap();
+        assert!(ASN1SequenceOf::<ASN1Integer>::from_der_node(node).is_err());
+    }
+}
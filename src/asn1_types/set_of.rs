@@ -0,0 +1,187 @@
+use crate::asn1_types::ASN1Identifier;
+use crate::asn1::ASN1Node;
+use crate::errors::{ASN1Error, ErrorCode};
+use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
+use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
+use crate::buffer::Bytes;
+
+/// A SET OF that keeps every element's original encoding next to its decoded value.
+///
+/// Serializing re-emits the stored encodings verbatim and in their original order, so a
+/// parsed SET round-trips byte-exactly even if it was not canonically encoded. This
+/// matters when a signature covers the encoding rather than the abstract value.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ASN1SetOf<T> {
+    values: Vec<T>,
+    encodings: Vec<Bytes>,
+}
+
+impl<T: DERSerializable> ASN1SetOf<T> {
+    /// Builds a set from values, encoding each one and ordering the elements by their
+    /// DER encodings as X.690 11.6 requires.
+    pub fn from_values(values: Vec<T>) -> Result<Self, ASN1Error> {
+        let mut elements = values
+            .into_iter()
+            .map(|value| {
+                let mut serializer = Serializer::new();
+                serializer.serialize(&value)?;
+                Ok((value, serializer.serialized_bytes()))
+            })
+            .collect::<Result<Vec<_>, ASN1Error>>()?;
+        elements.sort_by(|a, b| a.1.cmp(&b.1));
+        let (values, encodings) = elements.into_iter().unzip();
+        Ok(ASN1SetOf { values, encodings })
+    }
+}
+
+impl<T> ASN1SetOf<T> {
+    pub fn values(&self) -> &[T] {
+        &self.values
+    }
+
+    /// The encoded bytes of each element, in the same order as [`values`](Self::values).
+    pub fn encodings(&self) -> &[Bytes] {
+        &self.encodings
+    }
+
+    pub fn iter(&self) -> impl Iterator<Item = (&T, &Bytes)> {
+        self.values.iter().zip(self.encodings.iter())
+    }
+
+    pub fn len(&self) -> usize {
+        self.values.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.values.is_empty()
+    }
+
+    pub fn into_values(self) -> Vec<T> {
+        self.values
+    }
+
+    fn from_node_with<F>(node: ASN1Node, identifier: ASN1Identifier, decode: F) -> Result<Self, ASN1Error>
+    where
+        F: Fn(ASN1Node) -> Result<T, ASN1Error>,
+    {
+        if node.identifier != identifier {
+            return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
+        }
+        match node.content {
+            crate::asn1::Content::Constructed(collection) => {
+                let mut values = Vec::new();
+                let mut encodings = Vec::new();
+                for child in collection {
+                    encodings.push(child.encoded_bytes.clone());
+                    values.push(decode(child)?);
+                }
+                Ok(ASN1SetOf { values, encodings })
+            }
+            _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "SET OF must be constructed".to_string(), file!().to_string(), line!())),
+        }
+    }
+}
+
+impl<T: DERParseable> DERParseable for ASN1SetOf<T> {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, ASN1Identifier::SET, T::from_der_node)
+    }
+}
+
+impl<T> DERSerializable for ASN1SetOf<T> {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_constructed_node(ASN1Identifier::SET, |nested| {
+            for encoding in &self.encodings {
+                nested.append_encoded_node(encoding)?;
+            }
+            Ok(())
+        })
+    }
+}
+
+impl<T: DERParseable> DERImplicitlyTaggable for ASN1SetOf<T> {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::SET
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, identifier, T::from_der_node)
+    }
+}
+
+impl<T: BERParseable> BERParseable for ASN1SetOf<T> {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, ASN1Identifier::SET, T::from_ber_node)
+    }
+}
+
+impl<T> BERSerializable for ASN1SetOf<T> {}
+
+impl<T: BERParseable> BERImplicitlyTaggable for ASN1SetOf<T> {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Self::from_node_with(node, identifier, T::from_ber_node)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_types::{ASN1Integer, TagClass};
+    use crate::ber;
+
+    #[test]
+    fn test_set_of_preserves_original_order_and_bytes() {
+        // SET { INTEGER 2, INTEGER 1 } is not canonically ordered, but must round-trip.
+        let data = vec![0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01];
+        let set = ASN1SetOf::<ASN1Integer>::from_der_bytes(&data).unwrap();
+        assert_eq!(set.values(), &[ASN1Integer::from(2), ASN1Integer::from(1)]);
+        assert_eq!(set.encodings()[1].as_ref(), &[0x02, 0x01, 0x01]);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&set).unwrap();
+        assert_eq!(serializer.serialized_bytes(), data);
+    }
+
+    #[test]
+    fn test_set_of_from_values_sorts_encodings() {
+        let set = ASN1SetOf::from_values(vec![300i64, 5, -1]).unwrap();
+        // 02 01 05 < 02 01 FF < 02 02 01 2C
+        assert_eq!(set.values(), &[5, -1, 300]);
+        assert_eq!(set.len(), 3);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&set).unwrap();
+        assert_eq!(
+            serializer.serialized_bytes(),
+            vec![0x31, 0x0A, 0x02, 0x01, 0x05, 0x02, 0x01, 0xFF, 0x02, 0x02, 0x01, 0x2C]
+        );
+    }
+
+    #[test]
+    fn test_set_of_ber_keeps_non_minimal_encodings() {
+        // SET { INTEGER 1 with a non-minimal length }
+        let data = vec![0x31, 0x04, 0x02, 0x81, 0x01, 0x01];
+        let node = ber::parse(&data).unwrap();
+        let set = ASN1SetOf::<ASN1Integer>::from_ber_node(node).unwrap();
+        assert_eq!(set.encodings()[0].as_ref(), &[0x02, 0x81, 0x01, 0x01]);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&set).unwrap();
+        assert_eq!(serializer.serialized_bytes(), data);
+    }
+
+    #[test]
+    fn test_set_of_rejects_wrong_identifier_and_primitive() {
+        assert!(ASN1SetOf::<ASN1Integer>::from_der_bytes(&[0x30, 0x00]).is_err());
+        let node = crate::der::parse(&[0x11, 0x00]).unwrap();
+        assert!(ASN1SetOf::<ASN1Integer>::from_der_node(node).is_err());
+
+        let tagged = crate::der::parse(&[0xA0, 0x03, 0x02, 0x01, 0x07]).unwrap();
+        let set = ASN1SetOf::<i64>::from_der_node_with_identifier(
+            tagged,
+            ASN1Identifier::new(0, TagClass::ContextSpecific),
+        )
+        .unwrap();
+        assert_eq!(set.into_values(), vec![7]);
+    }
+}
@@ -95,6 +95,9 @@ macro_rules! impl_string_type {
     };
 }
 
+#[cfg(feature = "kerberos")]
+pub(crate) use impl_string_type;
+
 impl_string_type!(ASN1UTF8String, ASN1Identifier::UTF8_STRING, |_s: &str| true); // UTF-8 check done by String::from_utf8
 impl_string_type!(ASN1PrintableString, ASN1Identifier::PRINTABLE_STRING, |s: &str| {
     s.chars().all(|c| {
@@ -104,5 +107,16 @@ impl_string_type!(ASN1PrintableString, ASN1Identifier::PRINTABLE_STRING, |s: &st
 impl_string_type!(ASN1IA5String, ASN1Identifier::IA5_STRING, |s: &str| s.is_ascii());
 impl_string_type!(ASN1NumericString, ASN1Identifier::NUMERIC_STRING, |s: &str| s.chars().all(|c| c.is_ascii_digit() || c == ' '));
 
-// Teletex, Videotex, Graphics, etc?
-// Implement as needed. These are the commons.
+// The ISO 2022 based types below switch character sets with escape sequences. Their
+// content is held as text, so only encodings that are valid UTF-8 can be decoded.
+// GraphicString excludes control characters other than ESC.
+fn is_graphic_string(s: &str) -> bool {
+    s.chars().all(|c| !c.is_control() || c == '\u{1b}')
+}
+
+impl_string_type!(ASN1GraphicString, ASN1Identifier::GRAPHIC_STRING, is_graphic_string);
+impl_string_type!(ASN1GeneralString, ASN1Identifier::GENERAL_STRING, |_s: &str| true);
+impl_string_type!(ASN1VideotexString, ASN1Identifier::VIDEOTEX_STRING, |_s: &str| true);
+
+// ObjectDescriptor ::= [UNIVERSAL 7] IMPLICIT GraphicString
+impl_string_type!(ASN1ObjectDescriptor, ASN1Identifier::OBJECT_DESCRIPTOR, is_graphic_string);
@@ -1,21 +1,504 @@
 use crate::asn1_types::ASN1Identifier;
 use crate::asn1::ASN1Node;
+use crate::buffer::Bytes;
 use crate::errors::{ASN1Error, ErrorCode};
 use crate::der::{DERParseable, DERSerializable, Serializer, DERImplicitlyTaggable};
 use crate::ber::{BERParseable, BERSerializable, BERImplicitlyTaggable};
-use chrono::{DateTime, Utc, TimeZone, NaiveDateTime, Datelike};
+use std::fmt;
+use std::str::FromStr;
+use std::time::SystemTime;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct GeneralizedTime(pub DateTime<Utc>);
+/// The timestamp the time types are built on: `chrono::DateTime<Utc>` with the `chrono`
+/// feature, `time::OffsetDateTime` with only the `time` feature, and the dependency-free
+/// `UtcDateTime` with neither.
+#[cfg(feature = "chrono")]
+pub type Timestamp = chrono::DateTime<chrono::Utc>;
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+pub type Timestamp = ::time::OffsetDateTime;
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+pub type Timestamp = crate::asn1_types::UtcDateTime;
 
-#[derive(Debug, Clone, PartialEq, Eq, Hash)]
-pub struct UTCTime(pub DateTime<Utc>);
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct GeneralizedTime(pub Timestamp);
+
+#[derive(Debug, Clone, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UTCTime(pub Timestamp);
+
+/// The calendar fields of a timestamp in UTC.
+struct Civil {
+    year: i32,
+    month: u32,
+    day: u32,
+    hour: u32,
+    minute: u32,
+    second: u32,
+    nanosecond: u32,
+}
+
+#[cfg(feature = "chrono")]
+mod backend {
+    use super::{Civil, Timestamp};
+    use chrono::{Datelike, NaiveDate, TimeDelta, TimeZone, Timelike, Utc};
+    use std::time::SystemTime;
+
+    pub(super) fn from_civil(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Option<Timestamp> {
+        let naive = NaiveDate::from_ymd_opt(year, month, day)?.and_hms_opt(hour, minute, second)?;
+        Some(Utc.from_utc_datetime(&naive))
+    }
+
+    pub(super) fn civil(time: &Timestamp) -> Civil {
+        Civil {
+            year: time.year(),
+            month: time.month(),
+            day: time.day(),
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            nanosecond: time.nanosecond(),
+        }
+    }
+
+    pub(super) fn with_nanosecond(time: Timestamp, nanos: u32) -> Option<Timestamp> {
+        time.with_nanosecond(nanos)
+    }
+
+    pub(super) fn minus_minutes(time: Timestamp, minutes: i32) -> Option<Timestamp> {
+        time.checked_sub_signed(TimeDelta::minutes(i64::from(minutes)))
+    }
+
+    pub(super) fn from_system_time(time: SystemTime) -> Option<Timestamp> {
+        let (seconds, nanosecond) = super::unix_parts(time)?;
+        Timestamp::from_timestamp(seconds, nanosecond)
+    }
+}
+
+#[cfg(all(feature = "time", not(feature = "chrono")))]
+mod backend {
+    use super::{Civil, Timestamp};
+    use ::time::{Date, Duration, Month, PrimitiveDateTime, Time, UtcOffset};
+    use std::time::SystemTime;
+
+    pub(super) fn from_civil(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Option<Timestamp> {
+        let month = Month::try_from(u8::try_from(month).ok()?).ok()?;
+        let date = Date::from_calendar_date(year, month, u8::try_from(day).ok()?).ok()?;
+        let time = Time::from_hms(u8::try_from(hour).ok()?, u8::try_from(minute).ok()?, u8::try_from(second).ok()?).ok()?;
+        Some(PrimitiveDateTime::new(date, time).assume_utc())
+    }
+
+    pub(super) fn civil(time: &Timestamp) -> Civil {
+        // A value with a foreign offset is described by its fields in UTC.
+        let time = time.checked_to_offset(UtcOffset::UTC).unwrap_or(*time);
+        Civil {
+            year: time.year(),
+            month: u8::from(time.month()).into(),
+            day: time.day().into(),
+            hour: time.hour().into(),
+            minute: time.minute().into(),
+            second: time.second().into(),
+            nanosecond: time.nanosecond(),
+        }
+    }
+
+    pub(super) fn with_nanosecond(time: Timestamp, nanos: u32) -> Option<Timestamp> {
+        time.replace_nanosecond(nanos).ok()
+    }
+
+    pub(super) fn minus_minutes(time: Timestamp, minutes: i32) -> Option<Timestamp> {
+        time.checked_sub(Duration::minutes(i64::from(minutes)))
+    }
+
+    pub(super) fn from_system_time(time: SystemTime) -> Option<Timestamp> {
+        let (seconds, nanosecond) = super::unix_parts(time)?;
+        Timestamp::from_unix_timestamp_nanos(i128::from(seconds) * 1_000_000_000 + i128::from(nanosecond)).ok()
+    }
+}
+
+#[cfg(not(any(feature = "chrono", feature = "time")))]
+mod backend {
+    use super::{Civil, Timestamp};
+    use std::time::SystemTime;
+
+    pub(super) fn from_civil(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32) -> Option<Timestamp> {
+        Timestamp::new(year, month, day, hour, minute, second, 0)
+    }
+
+    pub(super) fn civil(time: &Timestamp) -> Civil {
+        Civil {
+            year: time.year(),
+            month: time.month(),
+            day: time.day(),
+            hour: time.hour(),
+            minute: time.minute(),
+            second: time.second(),
+            nanosecond: time.nanosecond(),
+        }
+    }
+
+    pub(super) fn with_nanosecond(time: Timestamp, nanos: u32) -> Option<Timestamp> {
+        time.with_nanosecond(nanos)
+    }
+
+    pub(super) fn minus_minutes(time: Timestamp, minutes: i32) -> Option<Timestamp> {
+        time.checked_add_seconds(-i64::from(minutes) * 60)
+    }
+
+    pub(super) fn from_system_time(time: SystemTime) -> Option<Timestamp> {
+        Timestamp::try_from(time).ok()
+    }
+}
+
+/// Splits `time` into whole seconds since the Unix epoch, rounded down, and the nanoseconds
+/// past them, or `None` if the seconds do not fit in an `i64`.
+pub(crate) fn unix_parts(time: SystemTime) -> Option<(i64, u32)> {
+    match time.duration_since(std::time::UNIX_EPOCH) {
+        Ok(after) => Some((i64::try_from(after.as_secs()).ok()?, after.subsec_nanos())),
+        Err(before) => {
+            let before = before.duration();
+            let seconds = i64::try_from(before.as_secs()).ok()?;
+            match before.subsec_nanos() {
+                0 => Some((-seconds, 0)),
+                nanos => Some((-seconds - 1, 1_000_000_000 - nanos)),
+            }
+        }
+    }
+}
+
+/// Converts `time` to a [`Timestamp`], failing with `ValueOutOfRange` when the backend
+/// cannot represent it.
+fn timestamp_from_system_time(time: SystemTime) -> Result<Timestamp, ASN1Error> {
+    backend::from_system_time(time).ok_or_else(|| {
+        ASN1Error::new(ErrorCode::ValueOutOfRange, format!("{:?} is out of range for a timestamp", time), file!().to_string(), line!())
+    })
+}
+
+/// The instant behind a time representation, for comparisons across representations.
+trait Instant {
+    fn instant(&self) -> Timestamp;
+}
+
+impl Instant for GeneralizedTime {
+    fn instant(&self) -> Timestamp {
+        self.0
+    }
+}
+
+impl Instant for UTCTime {
+    fn instant(&self) -> Timestamp {
+        self.0
+    }
+}
+
+impl Instant for Timestamp {
+    fn instant(&self) -> Timestamp {
+        *self
+    }
+}
+
+/// Compares two time representations by instant, so that validity checks such as
+/// `not_before <= now && now <= not_after` work across them.
+macro_rules! impl_time_comparisons {
+    ($lhs:ty, $rhs:ty) => {
+        impl PartialEq<$rhs> for $lhs {
+            fn eq(&self, other: &$rhs) -> bool {
+                Instant::instant(self) == Instant::instant(other)
+            }
+        }
+
+        impl PartialOrd<$rhs> for $lhs {
+            fn partial_cmp(&self, other: &$rhs) -> Option<std::cmp::Ordering> {
+                Some(Instant::instant(self).cmp(&Instant::instant(other)))
+            }
+        }
+    };
+}
+
+impl_time_comparisons!(GeneralizedTime, UTCTime);
+impl_time_comparisons!(UTCTime, GeneralizedTime);
+impl_time_comparisons!(GeneralizedTime, Timestamp);
+impl_time_comparisons!(Timestamp, GeneralizedTime);
+impl_time_comparisons!(UTCTime, Timestamp);
+impl_time_comparisons!(Timestamp, UTCTime);
+
+impl From<Timestamp> for GeneralizedTime {
+    fn from(dt: Timestamp) -> Self { GeneralizedTime(dt) }
+}
+impl From<Timestamp> for UTCTime {
+    fn from(dt: Timestamp) -> Self { UTCTime(dt) }
+}
+
+impl TryFrom<SystemTime> for GeneralizedTime {
+    type Error = ASN1Error;
+    fn try_from(time: SystemTime) -> Result<Self, ASN1Error> { timestamp_from_system_time(time).map(GeneralizedTime) }
+}
+impl TryFrom<SystemTime> for UTCTime {
+    type Error = ASN1Error;
+    fn try_from(time: SystemTime) -> Result<Self, ASN1Error> { timestamp_from_system_time(time).map(UTCTime) }
+}
+impl From<GeneralizedTime> for SystemTime {
+    fn from(time: GeneralizedTime) -> Self { time.0.into() }
+}
+impl From<UTCTime> for SystemTime {
+    fn from(time: UTCTime) -> Self { time.0.into() }
+}
+
+impl fmt::Display for GeneralizedTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Format: YYYYMMDDHHMMSS[.f]Z
+        // DER requires Z (UTC) and no trailing zeros in the fraction.
+        let c = backend::civil(&self.0);
+        write!(f, "{:04}{:02}{:02}{:02}{:02}{:02}{}Z", c.year, c.month, c.day, c.hour, c.minute, c.second, fraction_suffix(c.nanosecond))
+    }
+}
+
+impl FromStr for GeneralizedTime {
+    type Err = ASN1Error;
+
+    /// Parses the DER form `YYYYMMDDHHMMSS[.f]Z` (X.690 11.7): seconds present, a full stop
+    /// before any fraction, no trailing zeros in it, and midnight as `000000`.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let invalid = |reason: &str| ASN1Error::new(ErrorCode::InvalidStringRepresentation, format!("Invalid DER GeneralizedTime {:?}: {}", s, reason), file!().to_string(), line!());
+        let body = s.strip_suffix('Z').ok_or_else(|| invalid("must end with Z"))?;
+        let (whole, fraction) = body.find(['.', ',']).map_or((body, None), |i| (&body[..i], Some(&body[i..])));
+        if !whole.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(invalid("expected YYYYMMDDHHMMSS"));
+        }
+        match whole.len() {
+            14 => {}
+            10 | 12 => return Err(invalid("minutes and seconds must be present")),
+            _ => return Err(invalid("expected YYYYMMDDHHMMSS")),
+        }
+        if &whole[8..10] == "24" {
+            return Err(invalid("midnight must be encoded as 000000"));
+        }
+        let nanos = fraction.map_or(Ok(0), |fraction| fraction_nanos(fraction, true))?;
+        let year = digits(&whole[0..4]) as i32;
+        if year == 0 {
+            return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "GeneralizedTime year must be between 0001 and 9999".to_string(), file!().to_string(), line!()));
+        }
+        with_nanos(civil_datetime(year, &whole[4..])?, nanos).map(GeneralizedTime)
+    }
+}
+
+/// The `.f` suffix for `nanos` without trailing zeros, or nothing for whole seconds.
+fn fraction_suffix(nanos: u32) -> String {
+    if nanos == 0 {
+        return String::new();
+    }
+    format!(".{:09}", nanos).trim_end_matches('0').to_string()
+}
+
+/// Parses a fraction of a second, separator included, into nanoseconds. The `canonical`
+/// (DER) form uses a full stop and has no trailing zeros.
+fn fraction_nanos(fraction: &str, canonical: bool) -> Result<u32, ASN1Error> {
+    let invalid = |reason: &str| ASN1Error::new(ErrorCode::InvalidStringRepresentation, format!("Invalid fraction {:?}: {}", fraction, reason), file!().to_string(), line!());
+    let (separator, fraction_digits) = fraction.split_at(1);
+    if canonical && separator != "." {
+        return Err(invalid("DER requires a full stop as decimal separator"));
+    }
+    if fraction_digits.is_empty() {
+        return Err(invalid("no digits after the decimal separator"));
+    }
+    if !fraction_digits.bytes().all(|b| b.is_ascii_digit()) {
+        return Err(invalid("expected digits"));
+    }
+    if canonical && fraction_digits.ends_with('0') {
+        return Err(invalid("DER forbids trailing zeros"));
+    }
+    if fraction_digits.len() > 9 {
+        return Err(invalid("precision beyond nanoseconds is not supported"));
+    }
+    Ok(digits(fraction_digits) * 10u32.pow(9 - fraction_digits.len() as u32))
+}
+
+/// `time` with its sub-second part set to `nanos`.
+fn with_nanos(time: Timestamp, nanos: u32) -> Result<Timestamp, ASN1Error> {
+    backend::with_nanosecond(time, nanos).ok_or_else(|| {
+        ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Fraction of a second out of range".to_string(), file!().to_string(), line!())
+    })
+}
+
+/// Parses an all-digit string; callers check the digits first.
+fn digits(s: &str) -> u32 {
+    s.bytes().fold(0, |acc, b| acc * 10 + u32::from(b - b'0'))
+}
+
+/// Builds a UTC timestamp from `year` and a validated `MMDDHHMMSS` digit string, rejecting
+/// out-of-range fields instead of letting them wrap, and leap seconds.
+fn civil_datetime(year: i32, rest: &str) -> Result<Timestamp, ASN1Error> {
+    let field = |i: usize| digits(&rest[i..i + 2]);
+    backend::from_civil(year, field(0), field(2), field(4), field(6), field(8))
+        .ok_or_else(|| {
+            ASN1Error::new(
+                ErrorCode::InvalidStringRepresentation,
+                format!("Time component out of range: {}{}", year, rest),
+                file!().to_string(),
+                line!(),
+            )
+        })
+}
+
+/// Splits a BER time string into its local time and its offset from UTC in minutes.
+///
+/// `Z` means UTC and `+HHMM`/`-HHMM` give an explicit offset. A value with neither is local
+/// time; the zone is unknown, so it is taken to be UTC.
+fn split_zone(s: &str) -> Result<(&str, i32), ASN1Error> {
+    if let Some(local) = s.strip_suffix('Z') {
+        return Ok((local, 0));
+    }
+    let Some(split) = s.len().checked_sub(5).filter(|&i| matches!(s.as_bytes()[i], b'+' | b'-')) else {
+        return Ok((s, 0));
+    };
+    let (local, zone) = s.split_at(split);
+    // Check the digits before slicing: a multi-byte character would not split on a boundary.
+    if !zone[1..].bytes().all(|b| b.is_ascii_digit()) || digits(&zone[1..3]) > 23 || digits(&zone[3..]) > 59 {
+        return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, format!("Invalid time zone offset {}", zone), file!().to_string(), line!()));
+    }
+    let (hours, minutes) = (&zone[1..3], &zone[3..]);
+    let offset = (digits(hours) * 60 + digits(minutes)) as i32;
+    Ok((local, if zone.starts_with('-') { -offset } else { offset }))
+}
+
+/// Converts a local time `offset_minutes` ahead of UTC to UTC.
+fn to_utc(local: Timestamp, offset_minutes: i32) -> Result<Timestamp, ASN1Error> {
+    backend::minus_minutes(local, offset_minutes).ok_or_else(|| {
+        ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Time out of range after applying offset".to_string(), file!().to_string(), line!())
+    })
+}
+
+/// The content of a primitive time node, checked against `identifier`.
+fn time_string(node: ASN1Node, identifier: ASN1Identifier, type_name: &str) -> Result<String, ASN1Error> {
+    if node.identifier != identifier {
+        return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
+    }
+    match node.content {
+        crate::asn1::Content::Primitive(bytes) => {
+            String::from_utf8(bytes.to_vec()).map_err(|_| ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid UTF-8".to_string(), file!().to_string(), line!()))
+        }
+        _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("{} must be primitive", type_name), file!().to_string(), line!())),
+    }
+}
+
+impl GeneralizedTime {
+    /// Parses the BER forms of GeneralizedTime, which [`FromStr`] (DER) rejects: a
+    /// `+HHMM`/`-HHMM` offset or a local time without zone, which is taken as UTC, the
+    /// reduced `YYYYMMDDHH` and `YYYYMMDDHHMM` forms, whose missing components are zero, and
+    /// fractions of a second with a comma or trailing zeros.
+    pub fn from_ber_str(s: &str) -> Result<Self, ASN1Error> {
+        let (local, offset) = split_zone(s)?;
+        let (local, fraction) = local.find(['.', ',']).map_or((local, None), |i| (&local[..i], Some(&local[i..])));
+        let allowed_lengths: &[usize] = if fraction.is_some() { &[14] } else { &[10, 12, 14] };
+        if !allowed_lengths.contains(&local.len()) || !local.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid GeneralizedTime format".to_string(), file!().to_string(), line!()));
+        }
+        let year = digits(&local[0..4]) as i32;
+        if year == 0 {
+            return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "GeneralizedTime year must be between 0001 and 9999".to_string(), file!().to_string(), line!()));
+        }
+        let nanos = fraction.map_or(Ok(0), |fraction| fraction_nanos(fraction, false))?;
+        let rest = format!("{:0<10}", &local[4..]);
+        to_utc(with_nanos(civil_datetime(year, &rest)?, nanos)?, offset).map(GeneralizedTime)
+    }
+}
+
+impl UTCTime {
+    /// The first year of the default hundred-year window, which maps `50`..`99` to
+    /// 1950..1999 and `00`..`49` to 2000..2049 as RFC 5280 requires.
+    pub const DEFAULT_WINDOW_START: i32 = 1950;
+
+    /// Parses the DER form, mapping the two-digit year into the hundred years starting at
+    /// `window_start` instead of the default window.
+    pub fn from_str_with_window(s: &str, window_start: i32) -> Result<Self, ASN1Error> {
+        const UTCTIME_LEN: usize = 13; // YYMMDDHHMMSSZ
+        if s.len() != UTCTIME_LEN || !s.ends_with('Z') {
+            return Err(ASN1Error::new(
+                ErrorCode::InvalidStringRepresentation,
+                "UTCTime must match YYMMDDHHMMSSZ".to_string(),
+                file!().to_string(),
+                line!(),
+            ));
+        }
+        let body = &s[..UTCTIME_LEN - 1];
+        if !body.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ASN1Error::new(
+                ErrorCode::InvalidStringRepresentation,
+                "UTCTime must contain only digits before the Z suffix".to_string(),
+                file!().to_string(),
+                line!(),
+            ));
+        }
+        civil_datetime(windowed_year(&body[0..2], window_start)?, &body[2..]).map(UTCTime)
+    }
+
+    /// Parses the BER forms of UTCTime, which [`FromStr`] (DER) rejects: a `+HHMM`/`-HHMM`
+    /// offset or a local time without zone, which is taken as UTC.
+    pub fn from_ber_str(s: &str) -> Result<Self, ASN1Error> {
+        Self::from_ber_str_with_window(s, Self::DEFAULT_WINDOW_START)
+    }
+
+    /// [`from_ber_str`](Self::from_ber_str) with the year window starting at `window_start`.
+    pub fn from_ber_str_with_window(s: &str, window_start: i32) -> Result<Self, ASN1Error> {
+        let (local, offset) = split_zone(s)?;
+        if local.len() != 12 || !local.bytes().all(|b| b.is_ascii_digit()) {
+            return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid UTCTime format".to_string(), file!().to_string(), line!()));
+        }
+        to_utc(civil_datetime(windowed_year(&local[0..2], window_start)?, &local[2..])?, offset).map(UTCTime)
+    }
+
+    /// Decodes a DER UTCTime node with the year window starting at `window_start`.
+    pub fn from_der_node_with_window(node: ASN1Node, window_start: i32) -> Result<Self, ASN1Error> {
+        Self::from_str_with_window(&time_string(node, Self::default_identifier(), "UTCTime")?, window_start)
+    }
+
+    /// Decodes a BER UTCTime node with the year window starting at `window_start`.
+    pub fn from_ber_node_with_window(node: ASN1Node, window_start: i32) -> Result<Self, ASN1Error> {
+        Self::from_ber_str_with_window(&time_string(node, Self::default_identifier(), "UTCTime")?, window_start)
+    }
+
+    /// Serializes the value, which must fall in the hundred years starting at `window_start`
+    /// so that a decoder using the same window recovers it.
+    pub fn serialize_with_window(&self, serializer: &mut Serializer, window_start: i32) -> Result<(), ASN1Error> {
+        let window = window_start..=window_end(window_start)?;
+        let year = backend::civil(&self.0).year;
+        if !window.contains(&year) {
+            return Err(ASN1Error::new(ErrorCode::ValueOutOfRange, format!("UTCTime can only represent years {} to {}, got {}", window.start(), window.end(), year), file!().to_string(), line!()));
+        }
+        let s = self.to_string();
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            buf.extend_from_slice(s.as_bytes());
+            Ok(())
+        })
+    }
+}
+
+/// The last year of the hundred years starting at `window_start`.
+fn window_end(window_start: i32) -> Result<i32, ASN1Error> {
+    window_start.checked_add(99).ok_or_else(|| {
+        ASN1Error::new(ErrorCode::ValueOutOfRange, format!("UTCTime year window starting at {} does not fit", window_start), file!().to_string(), line!())
+    })
+}
 
-impl From<DateTime<Utc>> for GeneralizedTime {
-    fn from(dt: DateTime<Utc>) -> Self { GeneralizedTime(dt) }
+/// Maps the two-digit year `yy` into the hundred years starting at `window_start`.
+fn windowed_year(yy: &str, window_start: i32) -> Result<i32, ASN1Error> {
+    window_end(window_start)?;
+    // Widen so the difference cannot overflow; the result lies inside the window.
+    let offset = (i64::from(digits(yy)) - i64::from(window_start)).rem_euclid(100) as i32;
+    Ok(window_start + offset)
 }
-impl From<DateTime<Utc>> for UTCTime {
-    fn from(dt: DateTime<Utc>) -> Self { UTCTime(dt) }
+
+impl fmt::Display for UTCTime {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // Format: YYMMDDHHMMSSZ
+        let c = backend::civil(&self.0);
+        write!(f, "{:02}{:02}{:02}{:02}{:02}{:02}Z", c.year.rem_euclid(100), c.month, c.day, c.hour, c.minute, c.second)
+    }
+}
+
+impl FromStr for UTCTime {
+    type Err = ASN1Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Self::from_str_with_window(s, Self::DEFAULT_WINDOW_START)
+    }
 }
 
 impl DERParseable for GeneralizedTime {
@@ -26,9 +509,11 @@ impl DERParseable for GeneralizedTime {
 
 impl DERSerializable for GeneralizedTime {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
-         // Format: YYYYMMDDHHMMSSZ
-         // DER requires Z (UTC).
-         let s = self.0.format("%Y%m%d%H%M%SZ").to_string();
+         let year = backend::civil(&self.0).year;
+         if !(1..=9999).contains(&year) {
+             return Err(ASN1Error::new(ErrorCode::ValueOutOfRange, format!("GeneralizedTime can only represent years 0001 to 9999, got {}", year), file!().to_string(), line!()));
+         }
+         let s = self.to_string();
          serializer.append_primitive_node(Self::default_identifier(), |buf| {
              buf.extend_from_slice(s.as_bytes());
              Ok(())
@@ -42,31 +527,7 @@ impl DERImplicitlyTaggable for GeneralizedTime {
     }
 
     fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
-         if node.identifier != identifier {
-             return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
-        }
-        match node.content {
-            crate::asn1::Content::Primitive(bytes) => {
-                let s = String::from_utf8(bytes.to_vec()).map_err(|_| ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid UTF-8".to_string(), file!().to_string(), line!()))?;
-                // Parse GeneralizedTime
-                // Basic format: YYYYMMDDHHMMSSZ
-                // Or with fractional seconds.
-                // Or with offset.
-                // DER requires Z.
-                if !s.ends_with('Z') {
-                     return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "GeneralizedTime must end with Z in DER".to_string(), file!().to_string(), line!()));
-                }
-                
-                // Keep it simple: try %Y%m%d%H%M%SZ.
-                // Fractional not implemented for now to save space/time, strictly adhering to what usually appears.
-                // If parsing fails, error.
-                // Use NaiveDateTime then assume UTC
-                let naive = NaiveDateTime::parse_from_str(&s, "%Y%m%d%H%M%SZ").map_err(|_| ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid GeneralizedTime format".to_string(), file!().to_string(), line!()))?;
-                let dt = Utc.from_utc_datetime(&naive);
-                Ok(GeneralizedTime(dt))
-            },
-             _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "GeneralizedTime must be primitive".to_string(), file!().to_string(), line!()))
-        }
+        time_string(node, identifier, "GeneralizedTime")?.parse()
     }
 }
 
@@ -79,12 +540,7 @@ impl DERParseable for UTCTime {
 
 impl DERSerializable for UTCTime {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
-         // Format: YYMMDDHHMMSSZ
-         let s = self.0.format("%y%m%d%H%M%SZ").to_string();
-         serializer.append_primitive_node(Self::default_identifier(), |buf| {
-             buf.extend_from_slice(s.as_bytes());
-             Ok(())
-         })
+        self.serialize_with_window(serializer, Self::DEFAULT_WINDOW_START)
     }
 }
 
@@ -94,70 +550,324 @@ impl DERImplicitlyTaggable for UTCTime {
     }
 
     fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
-         if node.identifier != identifier {
-             return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
-        }
-        match node.content {
-            crate::asn1::Content::Primitive(bytes) => {
-                let s = String::from_utf8(bytes.to_vec()).map_err(|_| ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid UTF-8".to_string(), file!().to_string(), line!()))?;
-                const UTCTIME_LEN: usize = 13; // YYMMDDHHMMSSZ
-                if s.len() != UTCTIME_LEN || !s.ends_with('Z') {
-                    return Err(ASN1Error::new(
-                        ErrorCode::InvalidStringRepresentation,
-                        "UTCTime must match YYMMDDHHMMSSZ".to_string(),
-                        file!().to_string(),
-                        line!(),
-                    ));
-                }
-                let body = &s[..UTCTIME_LEN - 1];
-                if !body.bytes().all(|b| b.is_ascii_digit()) {
-                    return Err(ASN1Error::new(
-                        ErrorCode::InvalidStringRepresentation,
-                        "UTCTime must contain only digits before the Z suffix".to_string(),
-                        file!().to_string(),
-                        line!(),
-                    ));
-                }
-                
-                let naive = NaiveDateTime::parse_from_str(&s, "%y%m%d%H%M%SZ").map_err(|_| ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid UTCTime format".to_string(), file!().to_string(), line!()))?;
-                
-                // chrono %y parses 1969-2068 logic.
-                // ASN.1 logic: 0..49 -> 2000..2049. 50..99 -> 1950..1999.
-                // Chrono's logic for %y matches this mostly (splits at 69).
-                // "The range of the year logic in chrono needs verification or custom logic."
-                // Chrono docs say: "00-68 maps to 2000-2068, 69-99 maps to 1969-1999".
-                // ASN.1 wants split at 50.
-                
-                let year_str = &s[0..2];
-                let year_val: i32 = year_str.parse().unwrap_or(0);
-                
-                let century = if year_val >= 50 { 1900 } else { 2000 };
-                let full_year = century + year_val;
-                
-                // Construct DateTime with this year.
-                // naive has parsed year already with chrono logic. We correct it.
-                let corrected_naive = naive.with_year(full_year).ok_or(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "Invalid year".to_string(), file!().to_string(), line!()))?;
-                
-                Ok(UTCTime(Utc.from_utc_datetime(&corrected_naive)))
-            },
-             _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "UTCTime must be primitive".to_string(), file!().to_string(), line!()))
+        time_string(node, identifier, "UTCTime")?.parse()
+    }
+}
+
+/// The X.680 TIME type, an ISO 8601 date and time.
+///
+/// The original string is kept alongside the instant it denotes. The DER form, which
+/// [`FromStr`] and [`ASN1Time::new`] use, is `YYYY-MM-DDTHH:MM:SS[.f]Z` with any fraction
+/// free of trailing zeros.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct ASN1Time {
+    string: String,
+    instant: Timestamp,
+}
+
+impl ASN1Time {
+    /// The canonical TIME for `instant`.
+    pub fn new(instant: Timestamp) -> Self {
+        let c = backend::civil(&instant);
+        let string = format!("{:04}-{:02}-{:02}T{:02}:{:02}:{:02}{}Z", c.year, c.month, c.day, c.hour, c.minute, c.second, fraction_suffix(c.nanosecond));
+        ASN1Time { string, instant }
+    }
+
+    /// The string as encoded.
+    pub fn as_str(&self) -> &str {
+        &self.string
+    }
+
+    /// The instant the time denotes.
+    pub fn instant(&self) -> Timestamp {
+        self.instant
+    }
+
+    /// Parses the BER forms of TIME, which [`FromStr`] (DER) rejects: a `+HH:MM`/`-HH:MM`
+    /// offset, a comma as decimal sign and trailing zeros in the fraction.
+    pub fn from_ber_str(s: &str) -> Result<Self, ASN1Error> {
+        parse_iso_time(s, false).map(|instant| ASN1Time { string: s.to_string(), instant })
+    }
+}
+
+/// Parses an ISO 8601 `YYYY-MM-DDTHH:MM:SS` date and time with an optional fraction of up
+/// to nine digits and a zone, restricted to the DER form when `canonical` is set.
+fn parse_iso_time(s: &str, canonical: bool) -> Result<Timestamp, ASN1Error> {
+    let invalid = |reason: &str| ASN1Error::new(ErrorCode::InvalidStringRepresentation, format!("Invalid TIME {:?}: {}", s, reason), file!().to_string(), line!());
+    const DATE_TIME_LEN: usize = 19;
+    let bytes = s.as_bytes();
+    let shape_ok = bytes.len() > DATE_TIME_LEN
+        && bytes[..DATE_TIME_LEN].iter().enumerate().all(|(i, &b)| match i {
+            4 | 7 => b == b'-',
+            10 => b == b'T',
+            13 | 16 => b == b':',
+            _ => b.is_ascii_digit(),
+        });
+    if !shape_ok {
+        return Err(invalid("expected YYYY-MM-DDTHH:MM:SS followed by a zone"));
+    }
+    let rest: String = [&s[5..7], &s[8..10], &s[11..13], &s[14..16], &s[17..19]].concat();
+    let mut tail = &s[DATE_TIME_LEN..];
+
+    let mut nanos = 0;
+    if tail.starts_with(['.', ',']) {
+        let len = 1 + tail[1..].bytes().take_while(u8::is_ascii_digit).count();
+        nanos = fraction_nanos(&tail[..len], canonical)?;
+        tail = &tail[len..];
+    }
+
+    let offset = match tail {
+        "Z" => 0,
+        zone if !canonical && zone.len() == 6 && zone.as_bytes()[3] == b':' => {
+            match split_zone(&[&zone[..3], &zone[4..]].concat())? {
+                ("", offset) => offset,
+                _ => return Err(invalid("malformed zone offset")),
+            }
         }
+        _ if canonical => return Err(invalid("DER requires the Z zone")),
+        _ => return Err(invalid("expected Z or an offset of the form +HH:MM")),
+    };
+
+    to_utc(with_nanos(civil_datetime(digits(&s[0..4]) as i32, &rest)?, nanos)?, offset)
+}
+
+impl fmt::Display for ASN1Time {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.string)
+    }
+}
+
+impl FromStr for ASN1Time {
+    type Err = ASN1Error;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        parse_iso_time(s, true).map(|instant| ASN1Time { string: s.to_string(), instant })
+    }
+}
+
+impl From<Timestamp> for ASN1Time {
+    fn from(dt: Timestamp) -> Self { ASN1Time::new(dt) }
+}
+
+impl DERParseable for ASN1Time {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl DERSerializable for ASN1Time {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            buf.extend_from_slice(self.string.as_bytes());
+            Ok(())
+        })
+    }
+}
+
+impl DERImplicitlyTaggable for ASN1Time {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::TIME
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        time_string(node, identifier, "TIME")?.parse()
+    }
+}
+
+/// A time value that remembers the content octets it was decoded from.
+///
+/// Decoding a time normalizes it, so re-encoding can change bytes that a signature covers,
+/// for example by dropping a fraction or a zone offset. A `RawTime` decoded from a document
+/// serializes its original content octets verbatim; one built with [`RawTime::new`] uses the
+/// value's own encoding.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct RawTime<T> {
+    value: T,
+    original: Option<Bytes>,
+}
+
+impl<T> RawTime<T> {
+    pub fn new(value: T) -> Self {
+        RawTime { value, original: None }
+    }
+
+    pub fn value(&self) -> &T {
+        &self.value
+    }
+
+    pub fn into_inner(self) -> T {
+        self.value
+    }
+
+    /// The content octets the value was decoded from, if it was decoded.
+    pub fn original_bytes(&self) -> Option<&[u8]> {
+        self.original.as_deref()
+    }
+
+    /// Captures the content octets of `node` and decodes the value from it with `decode`.
+    fn capture(node: ASN1Node, decode: impl FnOnce(ASN1Node) -> Result<T, ASN1Error>) -> Result<Self, ASN1Error> {
+        let original = match &node.content {
+            crate::asn1::Content::Primitive(bytes) => Some(bytes.clone()),
+            crate::asn1::Content::Constructed(_) => None,
+        };
+        decode(node).map(|value| RawTime { value, original })
+    }
+}
+
+impl<T> From<T> for RawTime<T> {
+    fn from(value: T) -> Self { RawTime::new(value) }
+}
+
+impl<T: DERImplicitlyTaggable> DERParseable for RawTime<T> {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl<T: DERImplicitlyTaggable> DERSerializable for RawTime<T> {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        match &self.original {
+            Some(original) => serializer.append_primitive_node(T::default_identifier(), |buf| {
+                buf.extend_from_slice(original);
+                Ok(())
+            }),
+            None => self.value.serialize(serializer),
+        }
+    }
+}
+
+impl<T: DERImplicitlyTaggable> DERImplicitlyTaggable for RawTime<T> {
+    fn default_identifier() -> ASN1Identifier {
+        T::default_identifier()
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Self::capture(node, |node| T::from_der_node_with_identifier(node, identifier))
+    }
+}
+
+// Plain timestamps are encoded as GeneralizedTime, and decoded from either
+// GeneralizedTime or UTCTime, as in the X.509 `Time` CHOICE.
+impl DERParseable for Timestamp {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        if node.identifier == UTCTime::default_identifier() {
+            return UTCTime::from_der_node(node).map(|t| t.0);
+        }
+        Self::from_der_node_with_identifier(node, Self::default_identifier())
+    }
+}
+
+impl DERSerializable for Timestamp {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        GeneralizedTime(*self).serialize(serializer)
+    }
+}
+
+impl DERImplicitlyTaggable for Timestamp {
+    fn default_identifier() -> ASN1Identifier {
+        GeneralizedTime::default_identifier()
+    }
+
+    fn accepts_identifier(identifier: ASN1Identifier) -> bool {
+        identifier == GeneralizedTime::default_identifier() || identifier == UTCTime::default_identifier()
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        GeneralizedTime::from_der_node_with_identifier(node, identifier).map(|t| t.0)
+    }
+}
+
+// `SystemTime` follows `Timestamp`, so callers need not name the backend's types.
+impl DERParseable for SystemTime {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Timestamp::from_der_node(node).map(SystemTime::from)
+    }
+}
+
+impl DERSerializable for SystemTime {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        GeneralizedTime::try_from(*self)?.serialize(serializer)
+    }
+}
+
+impl DERImplicitlyTaggable for SystemTime {
+    fn default_identifier() -> ASN1Identifier {
+        GeneralizedTime::default_identifier()
+    }
+
+    fn accepts_identifier(identifier: ASN1Identifier) -> bool {
+        Timestamp::accepts_identifier(identifier)
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        Timestamp::from_der_node_with_identifier(node, identifier).map(SystemTime::from)
     }
 }
 
 // BER implementations
 impl BERParseable for GeneralizedTime {
-    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_der_node(node) }
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_ber_node_with_identifier(node, Self::default_identifier()) }
 }
 impl BERSerializable for GeneralizedTime {}
 impl BERImplicitlyTaggable for GeneralizedTime {
-     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> { Self::from_der_node_with_identifier(node, identifier) }
+     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+         Self::from_ber_str(&time_string(node, identifier, "GeneralizedTime")?)
+     }
 }
 
 impl BERParseable for UTCTime {
-    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_der_node(node) }
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_ber_node_with_identifier(node, Self::default_identifier()) }
 }
 impl BERSerializable for UTCTime {}
 impl BERImplicitlyTaggable for UTCTime {
-     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> { Self::from_der_node_with_identifier(node, identifier) }
+     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+         Self::from_ber_str(&time_string(node, identifier, "UTCTime")?)
+     }
+}
+
+impl BERParseable for ASN1Time {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_ber_node_with_identifier(node, Self::default_identifier()) }
+}
+impl BERSerializable for ASN1Time {}
+impl BERImplicitlyTaggable for ASN1Time {
+     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+         Self::from_ber_str(&time_string(node, identifier, "TIME")?)
+     }
+}
+
+impl<T: BERImplicitlyTaggable> BERParseable for RawTime<T> {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_ber_node_with_identifier(node, Self::default_identifier()) }
+}
+impl<T: BERImplicitlyTaggable> BERSerializable for RawTime<T> {}
+impl<T: BERImplicitlyTaggable> BERImplicitlyTaggable for RawTime<T> {
+     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+         Self::capture(node, |node| T::from_ber_node_with_identifier(node, identifier))
+     }
+}
+
+impl BERParseable for Timestamp {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        if node.identifier == UTCTime::default_identifier() {
+            return UTCTime::from_ber_node(node).map(|t| t.0);
+        }
+        Self::from_ber_node_with_identifier(node, Self::default_identifier())
+    }
+}
+impl BERSerializable for Timestamp {}
+impl BERImplicitlyTaggable for Timestamp {
+     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+         GeneralizedTime::from_ber_node_with_identifier(node, identifier).map(|t| t.0)
+     }
+}
+
+impl BERParseable for SystemTime {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Timestamp::from_ber_node(node).map(SystemTime::from)
+    }
+}
+impl BERSerializable for SystemTime {}
+impl BERImplicitlyTaggable for SystemTime {
+     fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+         Timestamp::from_ber_node_with_identifier(node, identifier).map(SystemTime::from)
+     }
 }
@@ -0,0 +1,188 @@
+use crate::asn1_err;
+use crate::errors::{ASN1Error, ErrorCode};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+const SECONDS_PER_DAY: i64 = 86_400;
+
+/// A validated date and time in UTC with nanosecond precision, the timestamp behind the
+/// time types in builds with neither the `chrono` nor the `time` feature.
+///
+/// It covers what decoding and encoding need: the calendar fields, ordering and conversion
+/// to and from [`SystemTime`]. Fields compare in order, so the derived ordering is
+/// chronological.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct UtcDateTime {
+    year: i32,
+    month: u8,
+    day: u8,
+    hour: u8,
+    minute: u8,
+    second: u8,
+    nanosecond: u32,
+}
+
+impl UtcDateTime {
+    /// Builds a value from its calendar fields, or `None` if any is out of range. Leap
+    /// seconds are not representable.
+    pub fn new(year: i32, month: u32, day: u32, hour: u32, minute: u32, second: u32, nanosecond: u32) -> Option<Self> {
+        if !(1..=12).contains(&month) || day == 0 || day > days_in_month(year, month) {
+            return None;
+        }
+        if hour > 23 || minute > 59 || second > 59 || nanosecond > 999_999_999 {
+            return None;
+        }
+        Some(UtcDateTime {
+            year,
+            month: month as u8,
+            day: day as u8,
+            hour: hour as u8,
+            minute: minute as u8,
+            second: second as u8,
+            nanosecond,
+        })
+    }
+
+    pub fn year(&self) -> i32 {
+        self.year
+    }
+
+    pub fn month(&self) -> u32 {
+        self.month.into()
+    }
+
+    pub fn day(&self) -> u32 {
+        self.day.into()
+    }
+
+    pub fn hour(&self) -> u32 {
+        self.hour.into()
+    }
+
+    pub fn minute(&self) -> u32 {
+        self.minute.into()
+    }
+
+    pub fn second(&self) -> u32 {
+        self.second.into()
+    }
+
+    pub fn nanosecond(&self) -> u32 {
+        self.nanosecond
+    }
+
+    /// The same date and time with the fraction of a second replaced.
+    pub fn with_nanosecond(self, nanosecond: u32) -> Option<Self> {
+        (nanosecond <= 999_999_999).then_some(UtcDateTime { nanosecond, ..self })
+    }
+
+    /// Seconds since 1970-01-01T00:00:00Z, ignoring the fraction.
+    pub fn unix_timestamp(&self) -> i64 {
+        let days = days_from_civil(i64::from(self.year), self.month(), self.day());
+        days * SECONDS_PER_DAY + i64::from(self.hour) * 3600 + i64::from(self.minute) * 60 + i64::from(self.second)
+    }
+
+    /// The value `seconds` after 1970-01-01T00:00:00Z plus `nanosecond`, or `None` if the
+    /// year does not fit.
+    pub fn from_unix_timestamp(seconds: i64, nanosecond: u32) -> Option<Self> {
+        let (days, time) = (seconds.div_euclid(SECONDS_PER_DAY), seconds.rem_euclid(SECONDS_PER_DAY) as u32);
+        let (year, month, day) = civil_from_days(days);
+        UtcDateTime::new(i32::try_from(year).ok()?, month, day, time / 3600, time / 60 % 60, time % 60, nanosecond)
+    }
+
+    /// The value `seconds` later, or `None` on overflow.
+    pub fn checked_add_seconds(self, seconds: i64) -> Option<Self> {
+        Self::from_unix_timestamp(self.unix_timestamp().checked_add(seconds)?, self.nanosecond)
+    }
+}
+
+impl TryFrom<SystemTime> for UtcDateTime {
+    type Error = ASN1Error;
+
+    /// Fails with `ValueOutOfRange` if the year does not fit in an `i32`.
+    fn try_from(time: SystemTime) -> Result<Self, ASN1Error> {
+        crate::asn1_types::time::unix_parts(time)
+            .and_then(|(seconds, nanosecond)| UtcDateTime::from_unix_timestamp(seconds, nanosecond))
+            .ok_or_else(|| asn1_err!(ErrorCode::ValueOutOfRange, "{:?} is out of range for UtcDateTime", time))
+    }
+}
+
+impl From<UtcDateTime> for SystemTime {
+    fn from(time: UtcDateTime) -> Self {
+        let seconds = time.unix_timestamp();
+        let whole = Duration::from_secs(seconds.unsigned_abs());
+        let instant = if seconds < 0 { UNIX_EPOCH - whole } else { UNIX_EPOCH + whole };
+        instant + Duration::from_nanos(time.nanosecond.into())
+    }
+}
+
+fn is_leap_year(year: i32) -> bool {
+    year.rem_euclid(4) == 0 && (year.rem_euclid(100) != 0 || year.rem_euclid(400) == 0)
+}
+
+fn days_in_month(year: i32, month: u32) -> u32 {
+    match month {
+        2 if is_leap_year(year) => 29,
+        2 => 28,
+        4 | 6 | 9 | 11 => 30,
+        _ => 31,
+    }
+}
+
+/// Days from 1970-01-01 to the given proleptic Gregorian date, after Howard Hinnant's
+/// `days_from_civil`.
+fn days_from_civil(year: i64, month: u32, day: u32) -> i64 {
+    let year = if month <= 2 { year - 1 } else { year };
+    let era = year.div_euclid(400);
+    let year_of_era = year.rem_euclid(400);
+    let month_index = i64::from((month + 9) % 12);
+    let day_of_year = (153 * month_index + 2) / 5 + i64::from(day) - 1;
+    let day_of_era = year_of_era * 365 + year_of_era / 4 - year_of_era / 100 + day_of_year;
+    era * 146_097 + day_of_era - 719_468
+}
+
+/// The inverse of [`days_from_civil`].
+fn civil_from_days(days: i64) -> (i64, u32, u32) {
+    let days = days + 719_468;
+    let era = days.div_euclid(146_097);
+    let day_of_era = days.rem_euclid(146_097);
+    let year_of_era = (day_of_era - day_of_era / 1460 + day_of_era / 36_524 - day_of_era / 146_096) / 365;
+    let day_of_year = day_of_era - (365 * year_of_era + year_of_era / 4 - year_of_era / 100);
+    let month_index = (5 * day_of_year + 2) / 153;
+    let day = (day_of_year - (153 * month_index + 2) / 5 + 1) as u32;
+    let month = if month_index < 10 { month_index + 3 } else { month_index - 9 } as u32;
+    let year = year_of_era + era * 400 + i64::from(month <= 2);
+    (year, month, day)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_days_round_trip() {
+        assert_eq!(days_from_civil(1970, 1, 1), 0);
+        assert_eq!(days_from_civil(2000, 3, 1), 11_017);
+        assert_eq!(days_from_civil(1969, 12, 31), -1);
+        for days in (-800_000..800_000).step_by(97) {
+            let (year, month, day) = civil_from_days(days);
+            assert_eq!(days_from_civil(year, month, day), days);
+        }
+    }
+
+    #[test]
+    fn test_validation_and_system_time() {
+        assert!(UtcDateTime::new(2024, 2, 29, 0, 0, 0, 0).is_some());
+        assert!(UtcDateTime::new(2023, 2, 29, 0, 0, 0, 0).is_none());
+        assert!(UtcDateTime::new(1900, 2, 29, 0, 0, 0, 0).is_none());
+        assert!(UtcDateTime::new(2023, 1, 1, 24, 0, 0, 0).is_none());
+        assert!(UtcDateTime::new(2023, 12, 31, 23, 59, 60, 0).is_none());
+
+        let before_epoch = UNIX_EPOCH - Duration::from_millis(1500);
+        let time = UtcDateTime::try_from(before_epoch).unwrap();
+        assert_eq!(time, UtcDateTime::new(1969, 12, 31, 23, 59, 58, 500_000_000).unwrap());
+        assert_eq!(SystemTime::from(time), before_epoch);
+
+        let time = UtcDateTime::new(2023, 12, 31, 23, 30, 0, 0).unwrap();
+        assert_eq!(time.checked_add_seconds(3600), UtcDateTime::new(2024, 1, 1, 0, 30, 0, 0));
+    }
+}
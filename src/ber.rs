@@ -1,8 +1,10 @@
-use crate::asn1::{ASN1Node, ASN1NodeCollection, ASN1NodeCollectionIterator, ParseResult, EncodingRules};
+use crate::asn1::{ASN1Node, ASN1NodeCollectionIterator, EncodingRules};
 use crate::asn1_types::ASN1Identifier;
 use crate::errors::{ASN1Error, ErrorCode};
 use crate::der::{DERParseable, DERSerializable, DERImplicitlyTaggable};
-use bytes::Bytes;
+use crate::buffer::Bytes;
+#[cfg(feature = "bytes")]
+use bytes::Buf;
 
 pub trait BERParseable: DERParseable {
     fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
@@ -29,28 +31,37 @@ pub trait BERImplicitlyTaggable: BERParseable + BERSerializable + DERImplicitlyT
 }
 
 pub fn parse(data: &[u8]) -> Result<ASN1Node, ASN1Error> {
-    let bytes = Bytes::copy_from_slice(data);
-    let result = ParseResult::parse(bytes, EncodingRules::Basic)?;
-    
-    let nodes = result.nodes;
-    let first_node = nodes[0].clone();
-    
-    if first_node.is_constructed {
-          let nodes_arc = std::sync::Arc::new(nodes);
-          let range = 1..nodes_arc.len();
-          let collection = ASN1NodeCollection::new(nodes_arc, range, first_node.depth);
-          Ok(ASN1Node {
-              identifier: first_node.identifier,
-              content: crate::asn1::Content::Constructed(collection),
-              encoded_bytes: first_node.encoded_bytes,
-          })
-     } else {
-          Ok(ASN1Node {
-              identifier: first_node.identifier,
-              content: crate::asn1::Content::Primitive(first_node.data_bytes.unwrap()),
-              encoded_bytes: first_node.encoded_bytes,
-          })
-     }
+    parse_bytes(Bytes::copy_from_slice(data))
+}
+
+/// Parses a BER document from any [`Buf`], including chained or segmented buffers.
+///
+/// As with [`der::parse_buf`](crate::der::parse_buf), a document already held in a single
+/// `Bytes` is parsed in place and anything else is gathered into one allocation.
+#[cfg(feature = "bytes")]
+pub fn parse_buf<B: Buf>(mut buf: B) -> Result<ASN1Node, ASN1Error> {
+    let bytes = buf.copy_to_bytes(buf.remaining());
+    parse_bytes(bytes)
+}
+
+fn parse_bytes(bytes: Bytes) -> Result<ASN1Node, ASN1Error> {
+    crate::asn1::parse_bytes_with_rules(bytes, EncodingRules::Basic)
+}
+
+/// Decodes an OPTIONAL field that is IMPLICITLY tagged with `identifier`, under BER.
+///
+/// The next node is only consumed when its identifier matches; otherwise `None` is
+/// returned and the iterator is left untouched.
+pub fn optional_implicitly_tagged<T: BERImplicitlyTaggable>(
+    iter: &mut ASN1NodeCollectionIterator,
+    identifier: ASN1Identifier,
+) -> Result<Option<T>, ASN1Error> {
+    match iter.peek() {
+        Some(node) if node.identifier == identifier => {}
+        _ => return Ok(None),
+    }
+    let node = iter.next().expect("peeked node must exist");
+    T::from_ber_node_with_identifier(node, identifier).map(Some)
 }
 
 pub fn sequence<T, F>(node: ASN1Node, identifier: ASN1Identifier, builder: F) -> Result<T, ASN1Error>
@@ -0,0 +1,283 @@
+//! Byte buffer types used throughout the public API.
+//!
+//! With the default `bytes` feature these are the `bytes` crate's `Bytes` and `BytesMut`, so
+//! parsed values share memory with their input and interoperate with the wider ecosystem.
+//! Without it, minimal replacements with the same names and the subset of methods this crate
+//! relies on are used instead, for environments that cannot take the dependency.
+
+#[cfg(feature = "bytes")]
+pub use bytes::{Bytes, BytesMut};
+
+#[cfg(not(feature = "bytes"))]
+pub use self::fallback::{Bytes, BytesMut};
+
+#[cfg(not(feature = "bytes"))]
+mod fallback {
+    use std::borrow::Borrow;
+    use std::fmt;
+    use std::hash::{Hash, Hasher};
+    use std::ops::{Bound, Deref, DerefMut, RangeBounds};
+    use std::sync::Arc;
+
+    /// An immutable, cheaply cloneable view into shared bytes.
+    #[derive(Clone)]
+    pub struct Bytes {
+        data: Arc<[u8]>,
+        start: usize,
+        end: usize,
+    }
+
+    impl Bytes {
+        pub fn new() -> Self {
+            Bytes::from(Vec::new())
+        }
+
+        /// Unlike the `bytes` crate, this copies `bytes` once into shared storage.
+        pub fn from_static(bytes: &'static [u8]) -> Self {
+            Bytes::copy_from_slice(bytes)
+        }
+
+        pub fn copy_from_slice(bytes: &[u8]) -> Self {
+            Bytes::from(bytes.to_vec())
+        }
+
+        pub fn len(&self) -> usize {
+            self.end - self.start
+        }
+
+        pub fn is_empty(&self) -> bool {
+            self.start == self.end
+        }
+
+        /// Returns the bytes in `range`, sharing storage with `self`.
+        ///
+        /// Panics if the range is out of bounds.
+        pub fn slice(&self, range: impl RangeBounds<usize>) -> Self {
+            let start = match range.start_bound() {
+                Bound::Included(&n) => n,
+                Bound::Excluded(&n) => n + 1,
+                Bound::Unbounded => 0,
+            };
+            let end = match range.end_bound() {
+                Bound::Included(&n) => n + 1,
+                Bound::Excluded(&n) => n,
+                Bound::Unbounded => self.len(),
+            };
+            assert!(start <= end && end <= self.len(), "range {}..{} out of bounds: {}", start, end, self.len());
+            Bytes { data: self.data.clone(), start: self.start + start, end: self.start + end }
+        }
+
+        /// Splits off and returns the first `at` bytes, leaving the rest in `self`.
+        ///
+        /// Panics if `at > self.len()`.
+        pub fn split_to(&mut self, at: usize) -> Self {
+            let head = self.slice(..at);
+            self.start += at;
+            head
+        }
+    }
+
+    impl Default for Bytes {
+        fn default() -> Self {
+            Bytes::new()
+        }
+    }
+
+    impl Deref for Bytes {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            &self.data[self.start..self.end]
+        }
+    }
+
+    impl AsRef<[u8]> for Bytes {
+        fn as_ref(&self) -> &[u8] {
+            self
+        }
+    }
+
+    impl Borrow<[u8]> for Bytes {
+        fn borrow(&self) -> &[u8] {
+            self
+        }
+    }
+
+    impl From<Vec<u8>> for Bytes {
+        fn from(vec: Vec<u8>) -> Self {
+            let end = vec.len();
+            Bytes { data: Arc::from(vec), start: 0, end }
+        }
+    }
+
+    impl From<&'static [u8]> for Bytes {
+        fn from(bytes: &'static [u8]) -> Self {
+            Bytes::from_static(bytes)
+        }
+    }
+
+    impl From<String> for Bytes {
+        fn from(s: String) -> Self {
+            Bytes::from(s.into_bytes())
+        }
+    }
+
+    impl From<Bytes> for Vec<u8> {
+        fn from(bytes: Bytes) -> Self {
+            bytes.to_vec()
+        }
+    }
+
+    impl PartialEq for Bytes {
+        fn eq(&self, other: &Self) -> bool {
+            **self == **other
+        }
+    }
+
+    impl Eq for Bytes {}
+
+    impl PartialEq<[u8]> for Bytes {
+        fn eq(&self, other: &[u8]) -> bool {
+            **self == *other
+        }
+    }
+
+    impl PartialEq<Vec<u8>> for Bytes {
+        fn eq(&self, other: &Vec<u8>) -> bool {
+            **self == **other
+        }
+    }
+
+    impl<T: ?Sized> PartialEq<&T> for Bytes
+    where
+        Bytes: PartialEq<T>,
+    {
+        fn eq(&self, other: &&T) -> bool {
+            *self == **other
+        }
+    }
+
+    impl PartialOrd for Bytes {
+        fn partial_cmp(&self, other: &Self) -> Option<std::cmp::Ordering> {
+            Some(self.cmp(other))
+        }
+    }
+
+    impl Ord for Bytes {
+        fn cmp(&self, other: &Self) -> std::cmp::Ordering {
+            (**self).cmp(&**other)
+        }
+    }
+
+    impl Hash for Bytes {
+        fn hash<H: Hasher>(&self, state: &mut H) {
+            (**self).hash(state)
+        }
+    }
+
+    impl fmt::Debug for Bytes {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            write!(f, "b\"")?;
+            for &b in self.iter() {
+                if b == b'"' || b == b'\\' {
+                    write!(f, "\\{}", b as char)?;
+                } else if b.is_ascii_graphic() || b == b' ' {
+                    write!(f, "{}", b as char)?;
+                } else {
+                    write!(f, "\\x{:02x}", b)?;
+                }
+            }
+            write!(f, "\"")
+        }
+    }
+
+    /// A growable byte buffer that can be frozen into [`Bytes`].
+    #[derive(Clone, Default, PartialEq, Eq, Hash)]
+    pub struct BytesMut {
+        vec: Vec<u8>,
+    }
+
+    impl BytesMut {
+        pub fn new() -> Self {
+            BytesMut::default()
+        }
+
+        pub fn with_capacity(capacity: usize) -> Self {
+            BytesMut { vec: Vec::with_capacity(capacity) }
+        }
+
+        pub fn extend_from_slice(&mut self, bytes: &[u8]) {
+            self.vec.extend_from_slice(bytes);
+        }
+
+        pub fn put_slice(&mut self, bytes: &[u8]) {
+            self.vec.extend_from_slice(bytes);
+        }
+
+        pub fn freeze(self) -> Bytes {
+            Bytes::from(self.vec)
+        }
+    }
+
+    impl Deref for BytesMut {
+        type Target = [u8];
+
+        fn deref(&self) -> &[u8] {
+            &self.vec
+        }
+    }
+
+    impl DerefMut for BytesMut {
+        fn deref_mut(&mut self) -> &mut [u8] {
+            &mut self.vec
+        }
+    }
+
+    impl AsRef<[u8]> for BytesMut {
+        fn as_ref(&self) -> &[u8] {
+            &self.vec
+        }
+    }
+
+    impl From<&[u8]> for BytesMut {
+        fn from(bytes: &[u8]) -> Self {
+            BytesMut { vec: bytes.to_vec() }
+        }
+    }
+
+    impl fmt::Debug for BytesMut {
+        fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+            fmt::Debug::fmt(&Bytes::copy_from_slice(&self.vec), f)
+        }
+    }
+
+    #[cfg(test)]
+    mod tests {
+        use super::*;
+
+        #[test]
+        fn test_fallback_bytes_slicing_shares_storage() {
+            let mut bytes = Bytes::from(vec![1, 2, 3, 4, 5]);
+            let tail = bytes.slice(2..);
+            assert_eq!(tail.as_ptr(), bytes[2..].as_ptr());
+            assert_eq!(&tail[..], &[3, 4, 5]);
+
+            let head = bytes.split_to(2);
+            assert_eq!((&head[..], &bytes[..]), (&[1, 2][..], &[3, 4, 5][..]));
+            assert_eq!(bytes.slice(1..=1), Bytes::from_static(&[4]));
+            assert!(bytes.slice(3..).is_empty());
+            assert_eq!(format!("{:?}", Bytes::from_static(b"a\"\x00")), "b\"a\\\"\\x00\"");
+
+            let mut buf = BytesMut::with_capacity(4);
+            buf.put_slice(&[1, 2]);
+            buf.extend_from_slice(&[3]);
+            assert_eq!(buf.freeze(), Bytes::from(vec![1, 2, 3]));
+        }
+
+        #[test]
+        #[should_panic]
+        fn test_fallback_bytes_split_past_end_panics() {
+            Bytes::from(vec![1]).split_to(2);
+        }
+    }
+}
@@ -1,10 +1,22 @@
-use crate::asn1::{ASN1Node, ASN1NodeCollection, ASN1NodeCollectionIterator, EncodingRules, ParseResult};
+use crate::asn1::{ASN1Node, ASN1NodeCollectionIterator, EncodingRules};
 use crate::asn1_err;
-use crate::asn1_types::{ASN1Boolean, ASN1Identifier, ASN1Integer, ASN1UTF8String};
+use crate::asn1_types::{ASN1Boolean, ASN1Identifier, ASN1OctetString, TagClass};
+use crate::asn1_types::enumerated::has_redundant_leading_octet;
+#[cfg(feature = "strings")]
+use crate::asn1_types::ASN1UTF8String;
+#[cfg(feature = "real")]
+use crate::asn1_types::ASN1Real;
 use crate::errors::{ASN1Error, ErrorCode};
-use bytes::{BufMut, Bytes, BytesMut};
-use num_bigint::BigInt;
-use num_traits::ToPrimitive;
+use crate::pem::PEMStreamDecoder;
+use crate::buffer::{Bytes, BytesMut};
+#[cfg(feature = "bytes")]
+use bytes::{Buf, BufMut};
+use std::path::Path;
+
+pub use crate::lint::{lint, Violation, ViolationKind};
+
+#[cfg(feature = "derive")]
+pub use rust_asn1_derive::DERSequence;
 
 pub trait DERParseable: Sized {
     fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error>;
@@ -23,74 +35,120 @@ pub trait DERParseable: Sized {
          let node = parse(bytes)?;
          Self::from_der_node(node)
     }
+
+    /// Parses a value from a DER or PEM file; see [`parse_file`].
+    fn from_der_file<P: AsRef<Path>>(path: P) -> Result<Self, ASN1Error> {
+        Self::from_der_node(parse_file(path)?)
+    }
 }
 
 pub trait DERSerializable {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error>;
 }
 
+/// A type that can be decoded from nodes with any of several identifiers, such as a
+/// CHOICE. [`choice`] uses this to pick the alternative for a node.
+///
+/// Every [`DERImplicitlyTaggable`] type is a `Choice` of the identifiers it
+/// [accepts](DERImplicitlyTaggable::accepts_identifier), so CHOICE types can nest. Implement
+/// it by hand for enums that decode a CHOICE.
+pub trait Choice: DERParseable {
+    fn can_decode(identifier: ASN1Identifier) -> bool;
+}
+
+impl<T: DERImplicitlyTaggable> Choice for T {
+    fn can_decode(identifier: ASN1Identifier) -> bool {
+        T::accepts_identifier(identifier)
+    }
+}
+
 pub trait DERImplicitlyTaggable: DERParseable + DERSerializable {
     fn default_identifier() -> ASN1Identifier;
 
+    /// Whether an untagged node with `identifier` holds a value of this type, which is how
+    /// an untagged OPTIONAL field or CHOICE alternative is recognised. Defaults to
+    /// [`default_identifier`](Self::default_identifier) alone; types whose `from_der_node`
+    /// takes several universal types override it to match.
+    fn accepts_identifier(identifier: ASN1Identifier) -> bool {
+        identifier == Self::default_identifier()
+    }
+
     fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error>;
+
+    /// Serializes the value with `identifier` in place of its default identifier, as for
+    /// an IMPLICIT tag.
+    ///
+    /// The default implementation serializes normally and rewrites the identifier of the
+    /// result, keeping its constructed bit and contents.
+    fn serialize_with_identifier(&self, serializer: &mut Serializer, identifier: ASN1Identifier) -> Result<(), ASN1Error> {
+        let mut nested = Serializer::new();
+        self.serialize(&mut nested)?;
+        if nested.node_count() != 1 {
+            return Err(asn1_err!(
+                ErrorCode::InvalidASN1Object,
+                "Cannot implicitly tag {}: it serialized to {} nodes",
+                std::any::type_name::<Self>(),
+                nested.node_count()
+            ));
+        }
+        let encoded = nested.serialized_bytes();
+        let constructed = encoded[0] & 0x20 != 0;
+        serializer.append_node(identifier, constructed, content_octets(&encoded))
+    }
 }
 
 // DER namespace functions
 
 pub fn parse(data: &[u8]) -> Result<ASN1Node, ASN1Error> {
-    let bytes = Bytes::copy_from_slice(data);
-    let result = ParseResult::parse(bytes, EncodingRules::Distinguished)?;
+    parse_bytes(Bytes::copy_from_slice(data))
+}
 
-    let first = result
-        .nodes
-        .first()
-        .ok_or_else(|| {
-            ASN1Error::new(
-                ErrorCode::InvalidASN1Object,
-                "No ASN.1 nodes parsed".to_string(),
-                file!().to_string(),
-                line!(),
-            )
-        })?
-        .clone();
-
-    let nodes_arc = std::sync::Arc::new(result.nodes);
-    let root_depth = first.depth;
-
-    // Verify single root
-    let end_index = nodes_arc
-        .iter()
-        .enumerate()
-        .skip(1)
-        .find(|(_, node)| node.depth <= root_depth)
-        .map(|(idx, _)| idx)
-        .unwrap_or(nodes_arc.len());
-
-    if end_index != nodes_arc.len() {
-        return Err(ASN1Error::new(
-            ErrorCode::InvalidASN1Object,
-            "ASN1ParseResult unexpectedly allowed multiple root nodes".to_string(),
-            file!().to_string(),
-            line!(),
-        ));
-    }
+/// Parses a DER document from any [`Buf`], including chained or segmented buffers.
+///
+/// Parsed nodes share one contiguous `Bytes`, so the document is gathered with
+/// [`Buf::copy_to_bytes`]. That takes a shared view without copying when the remaining data
+/// already sits in a single `Bytes` or `BytesMut`, including the first segment of a chain;
+/// a document spread over several segments, or held in a borrowed slice, is copied once.
+#[cfg(feature = "bytes")]
+pub fn parse_buf<B: Buf>(mut buf: B) -> Result<ASN1Node, ASN1Error> {
+    let bytes = buf.copy_to_bytes(buf.remaining());
+    parse_bytes(bytes)
+}
 
-    if first.is_constructed {
-        let collection = ASN1NodeCollection::new(nodes_arc, 1..end_index, root_depth);
-        Ok(ASN1Node {
-            identifier: first.identifier,
-            content: crate::asn1::Content::Constructed(collection),
-            encoded_bytes: first.encoded_bytes,
-        })
+fn parse_bytes(bytes: Bytes) -> Result<ASN1Node, ASN1Error> {
+    crate::asn1::parse_bytes_with_rules(bytes, EncodingRules::Distinguished)
+}
+
+/// Reads and parses the file at `path`, accepting either raw DER or PEM.
+///
+/// Files whose first non-whitespace bytes are a PEM `-----BEGIN` line are decoded as PEM and
+/// the first document is returned; anything else is parsed as DER.
+pub fn parse_file<P: AsRef<Path>>(path: P) -> Result<ASN1Node, ASN1Error> {
+    let path = path.as_ref();
+    let data = std::fs::read(path)
+        .map_err(|e| asn1_err!(ErrorCode::InvalidASN1Object, "Unable to read {}: {}", path.display(), e))?;
+    if data.trim_ascii_start().starts_with(b"-----BEGIN ") {
+        first_pem_document(&data[..], path)
     } else {
-        Ok(ASN1Node {
-            identifier: first.identifier,
-            content: crate::asn1::Content::Primitive(first.data_bytes.unwrap()),
-            encoded_bytes: first.encoded_bytes,
-        })
+        parse_bytes(Bytes::from(data))
     }
 }
 
+/// Reads the file at `path` as PEM and parses the DER content of its first document.
+pub fn parse_pem_file<P: AsRef<Path>>(path: P) -> Result<ASN1Node, ASN1Error> {
+    let path = path.as_ref();
+    let file = std::fs::File::open(path)
+        .map_err(|e| asn1_err!(ErrorCode::InvalidASN1Object, "Unable to read {}: {}", path.display(), e))?;
+    first_pem_document(file, path)
+}
+
+fn first_pem_document<R: std::io::Read>(reader: R, path: &Path) -> Result<ASN1Node, ASN1Error> {
+    let (_, node) = PEMStreamDecoder::new(reader)
+        .next()
+        .ok_or_else(|| asn1_err!(ErrorCode::InvalidPEMDocument, "No PEM document found in {}", path.display()))??;
+    Ok(node)
+}
+
 pub fn sequence<T, F>(node: ASN1Node, identifier: ASN1Identifier, builder: F) -> Result<T, ASN1Error>
 where
     F: FnOnce(&mut ASN1NodeCollectionIterator) -> Result<T, ASN1Error>,
@@ -111,6 +169,46 @@ where
     }
 }
 
+/// Unwraps an EXPLICITLY tagged value: checks that `node` is the constructed
+/// `[tag_class tag_number]` wrapper and hands its single inner node to `builder`.
+///
+/// See [`explicitly_tagged_from_iterator`] to take the wrapper from a SEQUENCE's iterator.
+pub fn explicitly_tagged<T, F>(node: ASN1Node, tag_number: u64, tag_class: TagClass, builder: F) -> Result<T, ASN1Error>
+where
+    F: FnOnce(ASN1Node) -> Result<T, ASN1Error>,
+{
+    let identifier = ASN1Identifier::new(tag_number, tag_class);
+    if node.identifier != identifier {
+        return Err(asn1_err!(ErrorCode::UnexpectedFieldType, "Expected {}, got {}", identifier, node.identifier));
+    }
+    match node.content {
+        crate::asn1::Content::Constructed(collection) => {
+            let mut iter = collection.into_iter();
+            match (iter.next(), iter.next()) {
+                (Some(inner), None) => builder(inner),
+                _ => Err(asn1_err!(ErrorCode::InvalidASN1Object, "Explicit tag {} must wrap exactly one node", identifier)),
+            }
+        }
+        _ => Err(asn1_err!(ErrorCode::UnexpectedFieldType, "Explicit tag {} must be constructed", identifier)),
+    }
+}
+
+/// [`explicitly_tagged`] applied to the next node of `iter`, failing if there is none.
+pub fn explicitly_tagged_from_iterator<T, F>(
+    iter: &mut ASN1NodeCollectionIterator,
+    tag_number: u64,
+    tag_class: TagClass,
+    builder: F,
+) -> Result<T, ASN1Error>
+where
+    F: FnOnce(ASN1Node) -> Result<T, ASN1Error>,
+{
+    let node = iter.next().ok_or_else(|| {
+        asn1_err!(ErrorCode::InvalidASN1Object, "Expected {}, no ASN.1 nodes to decode", ASN1Identifier::new(tag_number, tag_class))
+    })?;
+    explicitly_tagged(node, tag_number, tag_class, builder)
+}
+
 pub fn sequence_of<T: DERParseable>(identifier: ASN1Identifier, root_node: ASN1Node) -> Result<Vec<T>, ASN1Error> {
      if root_node.identifier != identifier {
          return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("{}", root_node.identifier), file!().to_string(), line!()));
@@ -123,6 +221,200 @@ pub fn sequence_of<T: DERParseable>(identifier: ASN1Identifier, root_node: ASN1N
     }
 }
 
+/// Decodes a SET OF, rejecting it unless the element encodings are in the ascending order
+/// DER requires (X.690 11.6).
+///
+/// Use [`ASN1SetOf`](crate::asn1_types::ASN1SetOf) instead to accept any order and
+/// preserve it on re-encoding.
+pub fn set_of<T: DERParseable>(identifier: ASN1Identifier, root_node: ASN1Node) -> Result<Vec<T>, ASN1Error> {
+    if root_node.identifier != identifier {
+        return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("{}", root_node.identifier), file!().to_string(), line!()));
+    }
+    match root_node.content {
+        crate::asn1::Content::Constructed(collection) => {
+            let mut previous: Option<Bytes> = None;
+            collection
+                .into_iter()
+                .map(|node| {
+                    if let Some(previous) = &previous
+                        && set_of_ordering(previous, &node.encoded_bytes).is_gt()
+                    {
+                        return Err(asn1_err!(ErrorCode::InvalidASN1Object, "SET OF elements are not in ascending DER order"));
+                    }
+                    previous = Some(node.encoded_bytes.clone());
+                    T::from_der_node(node)
+                })
+                .collect()
+        }
+        _ => Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("{}", root_node.identifier), file!().to_string(), line!())),
+    }
+}
+
+/// Orders two SET OF element encodings as X.690 11.6 does: as octet strings, with the
+/// shorter one padded with trailing zero octets.
+pub(crate) fn set_of_ordering(a: &[u8], b: &[u8]) -> std::cmp::Ordering {
+    let len = a.len().max(b.len());
+    let a = a.iter().copied().chain(std::iter::repeat(0)).take(len);
+    let b = b.iter().copied().chain(std::iter::repeat(0)).take(len);
+    a.cmp(b)
+}
+
+/// Splits a buffer of concatenated DER documents into one `Bytes` per document, each
+/// sharing `data`'s storage.
+///
+/// Only the identifier and length octets of each top-level TLV are read and nothing is
+/// copied, so this is cheap even for very large inputs.
+pub fn split_documents(data: &Bytes) -> Result<Vec<Bytes>, ASN1Error> {
+    let mut documents = Vec::new();
+    let mut offset = 0;
+    while offset < data.len() {
+        let rest = data.slice(offset..);
+        let length = crate::asn1::first_node_encoded_length(&rest)?;
+        documents.push(rest.slice(..length));
+        offset += length;
+    }
+    Ok(documents)
+}
+
+/// Parses a buffer of concatenated DER documents (e.g. a trust store or CT log chunk)
+/// in parallel, yielding one result per document in input order.
+///
+/// `data` is copied once into shared storage; the parsed documents all point into it.
+/// Fails up front only if the top-level framing is malformed; errors inside an
+/// individual document are reported through that document's item.
+#[cfg(feature = "rayon")]
+pub fn par_iter_documents(
+    data: &[u8],
+) -> Result<impl rayon::iter::IndexedParallelIterator<Item = Result<ASN1Node, ASN1Error>>, ASN1Error> {
+    use rayon::prelude::*;
+    Ok(split_documents(&Bytes::copy_from_slice(data))?.into_par_iter().map(parse_bytes))
+}
+
+/// Parses concatenated DER documents in parallel and decodes each one as `T`.
+#[cfg(feature = "rayon")]
+pub fn par_parse_documents<T>(data: &[u8]) -> Result<Vec<T>, ASN1Error>
+where
+    T: DERParseable + Send,
+{
+    use rayon::prelude::*;
+    par_iter_documents(data)?
+        .map(|node| node.and_then(T::from_der_node))
+        .collect()
+}
+
+/// Decodes an OPTIONAL field that is IMPLICITLY tagged with `identifier`.
+///
+/// The next node is only consumed when its identifier matches; otherwise `None` is
+/// returned and the iterator is left untouched.
+pub fn optional_implicitly_tagged<T: DERImplicitlyTaggable>(
+    iter: &mut ASN1NodeCollectionIterator,
+    identifier: ASN1Identifier,
+) -> Result<Option<T>, ASN1Error> {
+    match iter.peek() {
+        Some(node) if node.identifier == identifier => {}
+        _ => return Ok(None),
+    }
+    let node = iter.next().expect("peeked node must exist");
+    T::from_der_node_with_identifier(node, identifier).map(Some)
+}
+
+/// Starts decoding `node` as a CHOICE.
+///
+/// Each alternative is tried in turn against the node's identifier; the first that matches
+/// decodes the node and maps the result into `T`, and later alternatives are skipped.
+///
+/// ```
+/// # use rust_asn1::asn1_types::{ASN1Identifier, TagClass};
+/// # use rust_asn1::der;
+/// enum Time { Seconds(i64), Flag(bool) }
+///
+/// let node = der::parse(&[0x80, 0x01, 0x05]).unwrap();
+/// let time = der::choice(node)
+///     .implicit(ASN1Identifier::new(0, TagClass::ContextSpecific), Time::Seconds)
+///     .alternative(Time::Flag)
+///     .finish()
+///     .unwrap();
+/// assert!(matches!(time, Time::Seconds(5)));
+/// ```
+pub fn choice<T>(node: ASN1Node) -> ChoiceDecoder<T> {
+    ChoiceDecoder { node: Some(node), result: None }
+}
+
+/// The alternatives of a CHOICE being decoded; created by [`choice`].
+#[must_use = "call finish() to get the decoded value"]
+pub struct ChoiceDecoder<T> {
+    node: Option<ASN1Node>,
+    result: Option<Result<T, ASN1Error>>,
+}
+
+impl<T> ChoiceDecoder<T> {
+    /// An alternative with its own identifier(s), decoded as `A`.
+    pub fn alternative<A: Choice>(mut self, map: impl FnOnce(A) -> T) -> Self {
+        if let Some(node) = self.node.take_if(|node| A::can_decode(node.identifier)) {
+            self.result = Some(A::from_der_node(node).map(map));
+        }
+        self
+    }
+
+    /// An alternative IMPLICITLY tagged with `identifier`.
+    pub fn implicit<A: DERImplicitlyTaggable>(mut self, identifier: ASN1Identifier, map: impl FnOnce(A) -> T) -> Self {
+        if let Some(node) = self.node.take_if(|node| node.identifier == identifier) {
+            self.result = Some(A::from_der_node_with_identifier(node, identifier).map(map));
+        }
+        self
+    }
+
+    /// An alternative EXPLICITLY tagged `[tag_class tag_number]`.
+    pub fn explicit<A: DERParseable>(mut self, tag_number: u64, tag_class: TagClass, map: impl FnOnce(A) -> T) -> Self {
+        let identifier = ASN1Identifier::new(tag_number, tag_class);
+        if let Some(node) = self.node.take_if(|node| node.identifier == identifier) {
+            self.result = Some(explicitly_tagged(node, tag_number, tag_class, A::from_der_node).map(map));
+        }
+        self
+    }
+
+    /// Returns the decoded alternative, or `UnexpectedFieldType` if none matched.
+    pub fn finish(self) -> Result<T, ASN1Error> {
+        match (self.result, self.node) {
+            (Some(result), _) => result,
+            (None, Some(node)) => Err(asn1_err!(ErrorCode::UnexpectedFieldType, "No CHOICE alternative matches {}", node.identifier)),
+            (None, None) => unreachable!("a node is only taken together with setting a result"),
+        }
+    }
+}
+
+/// Decodes an OPTIONAL field that is EXPLICITLY tagged `[tag_class tag_number]`.
+///
+/// The next node is only consumed when its identifier matches the tag; otherwise `None` is
+/// returned and the iterator is left untouched.
+pub fn optional_explicitly_tagged<T: DERParseable>(
+    iter: &mut ASN1NodeCollectionIterator,
+    tag_number: u64,
+    tag_class: TagClass,
+) -> Result<Option<T>, ASN1Error> {
+    match iter.peek() {
+        Some(node) if node.identifier == ASN1Identifier::new(tag_number, tag_class) => {}
+        _ => return Ok(None),
+    }
+    let node = iter.next().expect("peeked node must exist");
+    explicitly_tagged(node, tag_number, tag_class, T::from_der_node).map(Some)
+}
+
+/// Resolves a field with a DEFAULT value from the result of decoding it as OPTIONAL.
+///
+/// An absent field takes `default`. DER forbids encoding the default value, so a present
+/// field equal to it is rejected.
+pub fn decode_default<T: PartialEq>(value: Option<T>, default: impl FnOnce() -> T) -> Result<T, ASN1Error> {
+    let default = default();
+    match value {
+        None => Ok(default),
+        Some(value) if value == default => {
+            Err(asn1_err!(ErrorCode::InvalidASN1Object, "DEFAULT value was encoded explicitly"))
+        }
+        Some(value) => Ok(value),
+    }
+}
+
 // Primitive implementations
 
 impl DERParseable for bool {
@@ -153,6 +445,7 @@ impl DERImplicitlyTaggable for bool {
     }
 }
 
+#[cfg(feature = "strings")]
 impl DERParseable for String {
     fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
         <Self as DERImplicitlyTaggable>::from_der_node_with_identifier(
@@ -162,12 +455,24 @@ impl DERParseable for String {
     }
 }
 
+#[cfg(feature = "strings")]
+impl DERSerializable for str {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(ASN1UTF8String::default_identifier(), |buf| {
+            buf.extend_from_slice(self.as_bytes());
+            Ok(())
+        })
+    }
+}
+
+#[cfg(feature = "strings")]
 impl DERSerializable for String {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
-        ASN1UTF8String(self.clone()).serialize(serializer)
+        self.as_str().serialize(serializer)
     }
 }
 
+#[cfg(feature = "strings")]
 impl DERImplicitlyTaggable for String {
     fn default_identifier() -> ASN1Identifier {
         ASN1Identifier::UTF8_STRING
@@ -181,15 +486,146 @@ impl DERImplicitlyTaggable for String {
     }
 }
 
+impl DERParseable for Bytes {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        <Self as DERImplicitlyTaggable>::from_der_node_with_identifier(
+            node,
+            <Self as DERImplicitlyTaggable>::default_identifier(),
+        )
+    }
+}
+
+impl DERSerializable for Bytes {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(ASN1Identifier::OCTET_STRING, |buf| {
+            buf.extend_from_slice(self);
+            Ok(())
+        })
+    }
+}
+
+impl DERImplicitlyTaggable for Bytes {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::OCTET_STRING
+    }
+
+    fn from_der_node_with_identifier(
+        node: ASN1Node,
+        identifier: ASN1Identifier,
+    ) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_der_node_with_identifier(node, identifier).map(|s| s.0)
+    }
+}
+
+// Fixed-size byte arrays are OCTET STRINGs of exactly `N` bytes. Slices, like any `[T]`,
+// stay SEQUENCE OF; wrap them in `OctetStringOf` for an OCTET STRING.
+impl<const N: usize> DERParseable for [u8; N] {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        <Self as DERImplicitlyTaggable>::from_der_node_with_identifier(
+            node,
+            <Self as DERImplicitlyTaggable>::default_identifier(),
+        )
+    }
+}
+
+impl<const N: usize> DERSerializable for [u8; N] {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(ASN1Identifier::OCTET_STRING, |buf| {
+            buf.extend_from_slice(self);
+            Ok(())
+        })
+    }
+}
+
+impl<const N: usize> DERImplicitlyTaggable for [u8; N] {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::OCTET_STRING
+    }
+
+    fn from_der_node_with_identifier(
+        node: ASN1Node,
+        identifier: ASN1Identifier,
+    ) -> Result<Self, ASN1Error> {
+        let octets = ASN1OctetString::from_der_node_with_identifier(node, identifier)?;
+        <[u8; N]>::try_from(octets.0.as_ref()).map_err(|_| {
+            ASN1Error::new(
+                ErrorCode::InvalidASN1Object,
+                format!("Expected an OCTET STRING of {} bytes, got {}", N, octets.0.len()),
+                file!().to_string(),
+                line!(),
+            )
+        })
+    }
+}
+
+impl DERParseable for ASN1Node {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Ok(node)
+    }
+}
+
 impl DERSerializable for ASN1Node {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
-        serializer.buffer.put_slice(&self.encoded_bytes);
-        Ok(())
+        serializer.append_encoded_node(&self.encoded_bytes)
+    }
+}
+
+/// The content octets of a DER INTEGER node, checked for the minimal encoding.
+pub(crate) fn integer_content(node: ASN1Node, identifier: ASN1Identifier) -> Result<Bytes, ASN1Error> {
+    if node.identifier != identifier {
+        return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, format!("Expected {}, got {}", identifier, node.identifier), file!().to_string(), line!()));
+    }
+    let bytes = match node.content {
+        crate::asn1::Content::Primitive(bytes) => bytes,
+        _ => return Err(ASN1Error::new(ErrorCode::UnexpectedFieldType, "Integer must be primitive".to_string(), file!().to_string(), line!())),
+    };
+    match bytes[..] {
+        [] => Err(ASN1Error::new(ErrorCode::InvalidASN1Object, "Integer with 0 bytes".to_string(), file!().to_string(), line!())),
+        [0x00, next, ..] if next & 0x80 == 0 => Err(asn1_err!(ErrorCode::InvalidASN1IntegerEncoding, "Integer encoded with redundant leading zero")),
+        [0xFF, next, ..] if next & 0x80 != 0 => Err(asn1_err!(ErrorCode::InvalidASN1IntegerEncoding, "Integer encoded with redundant leading FF")),
+        _ => Ok(bytes),
+    }
+}
+
+/// Decodes minimal two's complement `bytes` as an `i128`, or `None` if they are too wide.
+pub(crate) fn decode_i128(bytes: &[u8]) -> Option<i128> {
+    if bytes.len() > 16 {
+        return None;
     }
+    let fill = if bytes[0] & 0x80 != 0 { 0xFF } else { 0x00 };
+    let mut octets = [fill; 16];
+    octets[16 - bytes.len()..].copy_from_slice(bytes);
+    Some(i128::from_be_bytes(octets))
 }
 
-macro_rules! impl_der_for_signed_int {
-    ($($ty:ty => $to_method:ident),+ $(,)?) => {
+/// Decodes minimal two's complement `bytes` as a `u128`, or `None` if they are negative or
+/// too wide.
+pub(crate) fn decode_u128(bytes: &[u8]) -> Option<u128> {
+    if bytes[0] & 0x80 != 0 {
+        return None;
+    }
+    let bytes = bytes.strip_prefix(&[0x00]).filter(|rest| !rest.is_empty()).unwrap_or(bytes);
+    if bytes.len() > 16 {
+        return None;
+    }
+    let mut octets = [0x00; 16];
+    octets[16 - bytes.len()..].copy_from_slice(bytes);
+    Some(u128::from_be_bytes(octets))
+}
+
+/// Appends the minimal two's complement encoding of `octets`, a big-endian value that
+/// already carries its sign in the first octet.
+fn write_minimal_integer(octets: &[u8], buf: &mut Vec<u8>) {
+    let mut start = 0;
+    while has_redundant_leading_octet(&octets[start..]) {
+        start += 1;
+    }
+    buf.extend_from_slice(&octets[start..]);
+}
+
+// Machine integers are encoded and decoded directly, without going through `ASN1Integer`.
+macro_rules! impl_der_for_int {
+    ($($ty:ty => $decode:ident),+ $(,)?) => {
         $(
             impl DERParseable for $ty {
                 fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
@@ -202,7 +638,14 @@ macro_rules! impl_der_for_signed_int {
 
             impl DERSerializable for $ty {
                 fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
-                    ASN1Integer { value: BigInt::from(*self) }.serialize(serializer)
+                    serializer.append_primitive_node(ASN1Identifier::INTEGER, |buf| {
+                        // A leading zero octet gives unsigned values a sign bit to spare.
+                        let mut octets = [if *self < (0 as $ty) { 0xFF } else { 0x00 }; 17];
+                        let value = self.to_be_bytes();
+                        octets[17 - value.len()..].copy_from_slice(&value);
+                        write_minimal_integer(&octets[16 - value.len()..], buf);
+                        Ok(())
+                    })
                 }
             }
 
@@ -215,10 +658,9 @@ macro_rules! impl_der_for_signed_int {
                     node: ASN1Node,
                     identifier: ASN1Identifier,
                 ) -> Result<Self, ASN1Error> {
-                    let value = ASN1Integer::from_der_node_with_identifier(node, identifier)?;
-                    value
-                        .value
-                        .$to_method()
+                    let bytes = integer_content(node, identifier)?;
+                    $decode(&bytes)
+                        .and_then(|value| <$ty>::try_from(value).ok())
                         .ok_or_else(|| asn1_err!(ErrorCode::ValueOutOfRange, concat!("ASN1Integer does not fit into ", stringify!($ty))))
                 }
             }
@@ -226,8 +668,24 @@ macro_rules! impl_der_for_signed_int {
     };
 }
 
-macro_rules! impl_der_for_unsigned_int {
-    ($($ty:ty => $to_method:ident),+ $(,)?) => {
+impl_der_for_int!(
+    i8 => decode_i128,
+    i16 => decode_i128,
+    i32 => decode_i128,
+    i64 => decode_i128,
+    i128 => decode_i128,
+    isize => decode_i128,
+    u8 => decode_u128,
+    u16 => decode_u128,
+    u32 => decode_u128,
+    u64 => decode_u128,
+    u128 => decode_u128,
+    usize => decode_u128,
+);
+
+#[cfg(feature = "real")]
+macro_rules! impl_der_for_float {
+    ($($ty:ty => $convert:expr),+ $(,)?) => {
         $(
             impl DERParseable for $ty {
                 fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
@@ -240,46 +698,31 @@ macro_rules! impl_der_for_unsigned_int {
 
             impl DERSerializable for $ty {
                 fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
-                    ASN1Integer { value: BigInt::from(*self) }.serialize(serializer)
+                    ASN1Real::from(*self).serialize(serializer)
                 }
             }
 
             impl DERImplicitlyTaggable for $ty {
                 fn default_identifier() -> ASN1Identifier {
-                    ASN1Identifier::INTEGER
+                    ASN1Identifier::REAL
                 }
 
                 fn from_der_node_with_identifier(
                     node: ASN1Node,
                     identifier: ASN1Identifier,
                 ) -> Result<Self, ASN1Error> {
-                    let value = ASN1Integer::from_der_node_with_identifier(node, identifier)?;
-                    value
-                        .value
-                        .$to_method()
-                        .ok_or_else(|| asn1_err!(ErrorCode::ValueOutOfRange, concat!("ASN1Integer does not fit into ", stringify!($ty))))
+                    let value = ASN1Real::from_der_node_with_identifier(node, identifier)?;
+                    $convert(value)
                 }
             }
         )+
     };
 }
 
-impl_der_for_signed_int!(
-    i8 => to_i8,
-    i16 => to_i16,
-    i32 => to_i32,
-    i64 => to_i64,
-    i128 => to_i128,
-    isize => to_isize,
-);
-
-impl_der_for_unsigned_int!(
-    u8 => to_u8,
-    u16 => to_u16,
-    u32 => to_u32,
-    u64 => to_u64,
-    u128 => to_u128,
-    usize => to_usize,
+#[cfg(feature = "real")]
+impl_der_for_float!(
+    f64 => |value: ASN1Real| Ok(value.0),
+    f32 => |value: ASN1Real| value.to_f32(),
 );
 
 impl<T> DERParseable for Vec<T>
@@ -294,7 +737,7 @@ where
     }
 }
 
-impl<T> DERSerializable for Vec<T>
+impl<T> DERSerializable for [T]
 where
     T: DERSerializable,
 {
@@ -308,6 +751,15 @@ where
     }
 }
 
+impl<T> DERSerializable for Vec<T>
+where
+    T: DERSerializable,
+{
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        self.as_slice().serialize(serializer)
+    }
+}
+
 impl<T> DERImplicitlyTaggable for Vec<T>
 where
     T: DERParseable + DERSerializable,
@@ -332,19 +784,15 @@ where
         T::from_der_node(node).map(Some)
     }
 
+    /// Takes the next node only when `T` [accepts](DERImplicitlyTaggable::accepts_identifier)
+    /// its identifier; otherwise returns `None` and leaves the iterator untouched.
     fn from_der_iterator(
         iter: &mut ASN1NodeCollectionIterator,
     ) -> Result<Self, ASN1Error> {
-        let should_decode = match iter.peek() {
-            None => return Ok(None),
-            Some(node) => node.identifier == T::default_identifier(),
-        };
-
-        if !should_decode {
-            return Ok(None);
+        match iter.peek() {
+            Some(node) if T::accepts_identifier(node.identifier) => T::from_der_iterator(iter).map(Some),
+            _ => Ok(None),
         }
-        let node = iter.next().expect("peeked node must exist");
-        T::from_der_node(node).map(Some)
     }
 }
 
@@ -360,7 +808,61 @@ where
     }
 }
 
-impl<T: DERSerializable> DERSerializable for Box<T> {
+// Tuples are anonymous SEQUENCEs of their elements.
+macro_rules! impl_der_for_tuple {
+    ($($name:ident $index:tt),+) => {
+        impl<$($name: DERParseable),+> DERParseable for ($($name,)+) {
+            fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+                sequence(node, ASN1Identifier::SEQUENCE, |iter| Ok(($($name::from_der_iterator(iter)?,)+)))
+            }
+        }
+
+        impl<$($name: DERSerializable),+> DERSerializable for ($($name,)+) {
+            fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+                serializer.write_sequence(|seq| {
+                    $(seq.serialize(&self.$index)?;)+
+                    Ok(())
+                })
+            }
+        }
+
+        impl<$($name: DERParseable + DERSerializable),+> DERImplicitlyTaggable for ($($name,)+) {
+            fn default_identifier() -> ASN1Identifier {
+                ASN1Identifier::SEQUENCE
+            }
+
+            fn from_der_node_with_identifier(
+                node: ASN1Node,
+                identifier: ASN1Identifier,
+            ) -> Result<Self, ASN1Error> {
+                sequence(node, identifier, |iter| Ok(($($name::from_der_iterator(iter)?,)+)))
+            }
+        }
+    };
+}
+
+impl_der_for_tuple!(A 0);
+impl_der_for_tuple!(A 0, B 1);
+impl_der_for_tuple!(A 0, B 1, C 2);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10);
+impl_der_for_tuple!(A 0, B 1, C 2, D 3, E 4, F 5, G 6, H 7, I 8, J 9, K 10, L 11);
+
+// References and boxes encode as what they point to, so recursive structures and borrowed
+// fields, slices and strings serialize without cloning.
+impl<T: DERSerializable + ?Sized> DERSerializable for &T {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        (**self).serialize(serializer)
+    }
+}
+
+impl<T: DERSerializable + ?Sized> DERSerializable for Box<T> {
     fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
         (**self).serialize(serializer)
     }
@@ -370,39 +872,141 @@ impl<T: DERParseable> DERParseable for Box<T> {
     fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
         Ok(Box::new(T::from_der_node(node)?))
     }
-    
+
+    // Defer to `T` so that, for one, a boxed OPTIONAL can be absent.
     fn from_der_iterator(iter: &mut ASN1NodeCollectionIterator) -> Result<Self, ASN1Error> {
-        let node = iter.next().ok_or_else(|| ASN1Error::new(
-            ErrorCode::InvalidASN1Object,
-            std::format!("Unable to decode {}, no ASN.1 nodes to decode", std::any::type_name::<Self>()),
-            file!().to_string(),
-            line!(),
-        ))?;
-        Self::from_der_node(node)
+        T::from_der_iterator(iter).map(Box::new)
     }
-    
-    fn from_der_bytes(bytes: &[u8]) -> Result<Self, ASN1Error> {
-         let node = parse(bytes)?;
-         Self::from_der_node(node)
+}
+
+impl<T: DERImplicitlyTaggable> DERImplicitlyTaggable for Box<T> {
+    fn default_identifier() -> ASN1Identifier {
+        T::default_identifier()
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        T::from_der_node_with_identifier(node, identifier).map(Box::new)
+    }
+
+    fn serialize_with_identifier(&self, serializer: &mut Serializer, identifier: ASN1Identifier) -> Result<(), ASN1Error> {
+        (**self).serialize_with_identifier(serializer, identifier)
     }
 }
 
+use crate::ber::{BERImplicitlyTaggable, BERParseable, BERSerializable};
 
-pub struct Serializer {
-    pub buffer: BytesMut,
+impl<T: BERSerializable + ?Sized> BERSerializable for &T {}
+
+impl<T: BERParseable> BERParseable for Box<T> {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        T::from_ber_node(node).map(Box::new)
+    }
+
+    fn from_ber_iterator(iter: &mut ASN1NodeCollectionIterator) -> Result<Self, ASN1Error> {
+        T::from_ber_iterator(iter).map(Box::new)
+    }
 }
 
-impl Serializer {
+impl<T: BERSerializable + ?Sized> BERSerializable for Box<T> {}
+
+impl<T: BERImplicitlyTaggable> BERImplicitlyTaggable for Box<T> {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        T::from_ber_node_with_identifier(node, identifier).map(Box::new)
+    }
+}
+
+
+/// Builds DER output one top-level node at a time.
+///
+/// Nodes go to the serializer's own buffer or, with `with_buf_mut` (`bytes` feature),
+/// straight into a caller's buffer. Either way they are only written through the
+/// `append_*` methods, which keep [`node_count`](Self::node_count) in step with the output;
+/// the implicit tagging in [`DERImplicitlyTaggable::serialize_with_identifier`] relies on
+/// that count.
+pub struct Serializer<'a> {
+    buffer: BytesMut,
+    /// The caller's buffer that top-level nodes go to instead of `buffer`.
+    sink: Option<&'a mut dyn Sink>,
+    len: usize,
+    node_count: usize,
+}
+
+/// A caller's buffer that a [`Serializer`] writes into.
+trait Sink {
+    fn remaining(&self) -> usize;
+    fn put(&mut self, bytes: &[u8]);
+}
+
+#[cfg(feature = "bytes")]
+impl<B: BufMut> Sink for B {
+    fn remaining(&self) -> usize {
+        self.remaining_mut()
+    }
+
+    fn put(&mut self, bytes: &[u8]) {
+        self.put_slice(bytes);
+    }
+}
+
+impl Default for Serializer<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> Serializer<'a> {
     pub fn new() -> Self {
         Serializer {
             buffer: BytesMut::with_capacity(1024),
+            sink: None,
+            len: 0,
+            node_count: 0,
         }
     }
-    
+
+    /// A serializer that writes each top-level node straight into `dest` (a ring buffer,
+    /// pooled buffer, fixed-size array, ...) instead of keeping its own buffer, so
+    /// [`as_bytes`](Self::as_bytes) and [`serialized_bytes`](Self::serialized_bytes) stay
+    /// empty.
+    ///
+    /// The contents of a constructed node are still assembled first, as DER puts their
+    /// length in front of them. A node that does not fit in the space left in `dest` fails
+    /// with [`ErrorCode::BufferTooSmall`] without writing any of it; nodes already written
+    /// are kept.
+    #[cfg(feature = "bytes")]
+    pub fn with_buf_mut<B: BufMut>(dest: &'a mut B) -> Self {
+        Serializer {
+            buffer: BytesMut::new(),
+            sink: Some(dest),
+            len: 0,
+            node_count: 0,
+        }
+    }
+
+    /// The number of bytes serialized so far.
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// The number of top-level nodes appended to this serializer. Nodes nested inside
+    /// constructed nodes are not counted.
+    pub fn node_count(&self) -> usize {
+        self.node_count
+    }
+
+    /// The bytes serialized so far.
+    pub fn as_bytes(&self) -> &[u8] {
+        &self.buffer
+    }
+
     pub fn serialized_bytes(&self) -> Bytes {
         self.buffer.clone().freeze()
     }
-    
+
     pub fn append_primitive_node(
         &mut self,
         identifier: ASN1Identifier,
@@ -434,23 +1038,71 @@ impl Serializer {
         self.append_constructed_node(ASN1Identifier::SEQUENCE, writer)
     }
 
-    pub fn serialize<T: DERSerializable>(&mut self, node: &T) -> Result<(), ASN1Error> {
+    /// Writes `elements` as a SET OF, emitting them in the ascending order of their
+    /// encodings that DER requires (X.690 11.6), whatever order they are given in.
+    pub fn write_set_of<T: DERSerializable>(&mut self, elements: &[T]) -> Result<(), ASN1Error> {
+        let mut encodings = elements
+            .iter()
+            .map(|element| {
+                let mut serializer = Serializer::new();
+                serializer.serialize(element)?;
+                Ok(serializer.serialized_bytes())
+            })
+            .collect::<Result<Vec<_>, ASN1Error>>()?;
+        encodings.sort_by(|a, b| set_of_ordering(a, b));
+        self.append_constructed_node(ASN1Identifier::SET, |nested| {
+            for encoding in &encodings {
+                nested.append_encoded_node(encoding)?;
+            }
+            Ok(())
+        })
+    }
+
+    pub fn serialize<T: DERSerializable + ?Sized>(&mut self, node: &T) -> Result<(), ASN1Error> {
         node.serialize(self)
     }
 
+    /// Serializes `node` under `identifier` instead of its default identifier.
+    pub fn serialize_with_identifier<T: DERImplicitlyTaggable>(&mut self, node: &T, identifier: ASN1Identifier) -> Result<(), ASN1Error> {
+        node.serialize_with_identifier(self, identifier)
+    }
+
+    /// Appends the complete, already-encoded TLV `encoded` as one node.
+    pub(crate) fn append_encoded_node(&mut self, encoded: &[u8]) -> Result<(), ASN1Error> {
+        self.write_node(&[encoded])
+    }
+
     fn append_node(
         &mut self,
         identifier: ASN1Identifier,
         constructed: bool,
         content: &[u8],
     ) -> Result<(), ASN1Error> {
-        let mut temp_vec = Vec::new();
-        temp_vec.write_identifier(identifier, constructed);
-        self.buffer.put_slice(&temp_vec);
+        let mut header = Vec::new();
+        header.write_identifier(identifier, constructed);
+        header.extend_from_slice(&encode_length(content.len()));
+        self.write_node(&[&header, content])
+    }
 
-        let len_bytes = encode_length(content.len());
-        self.buffer.put_slice(&len_bytes);
-        self.buffer.put_slice(content);
+    /// Writes the pieces of one top-level node to the output, all of them or none.
+    fn write_node(&mut self, pieces: &[&[u8]]) -> Result<(), ASN1Error> {
+        let len = pieces.iter().map(|piece| piece.len()).sum();
+        match &mut self.sink {
+            Some(sink) => {
+                if sink.remaining() < len {
+                    return Err(asn1_err!(
+                        ErrorCode::BufferTooSmall,
+                        "Node of {} bytes does not fit in the {} bytes left in the buffer",
+                        len,
+                        sink.remaining()
+                    ));
+                }
+                pieces.iter().for_each(|piece| sink.put(piece));
+            }
+            None => pieces.iter().for_each(|piece| self.buffer.extend_from_slice(piece)),
+        }
+        self.len += len;
+        self.node_count += 1;
         Ok(())
     }
 }
@@ -503,7 +1155,25 @@ fn write_asn1_discipline_uint(v: &mut Vec<u8>, mut n: u64) {
     }
 }
 
-fn encode_length(len: usize) -> Vec<u8> {
+/// Returns the contents of the single DER-encoded TLV `encoded`, skipping its identifier
+/// and length octets.
+fn content_octets(encoded: &[u8]) -> &[u8] {
+    let mut offset = 1;
+    if encoded[0] & 0x1F == 0x1F {
+        while encoded[offset] & 0x80 != 0 {
+            offset += 1;
+        }
+        offset += 1;
+    }
+    let length_byte = encoded[offset];
+    offset += 1;
+    if length_byte & 0x80 != 0 {
+        offset += usize::from(length_byte & 0x7F);
+    }
+    &encoded[offset..]
+}
+
+pub(crate) fn encode_length(len: usize) -> Vec<u8> {
     if len <= 0x7F {
         vec![len as u8]
     } else {
@@ -528,6 +1198,7 @@ fn encode_length(len: usize) -> Vec<u8> {
 mod tests {
     use super::*;
     use crate::asn1_types::{ASN1Identifier, ASN1Integer, TagClass};
+    #[cfg(feature = "num-bigint")]
     use num_traits::ToPrimitive;
 
     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -592,6 +1263,182 @@ mod tests {
         assert_eq!(numbers, vec![1, 2]);
     }
 
+    #[test]
+    fn test_explicitly_tagged() {
+        // [0] EXPLICIT INTEGER 2, as in the X.509 version field.
+        let node = parse(&[0xA0, 0x03, 0x02, 0x01, 0x02]).unwrap();
+        let version = explicitly_tagged(node, 0, TagClass::ContextSpecific, i64::from_der_node).unwrap();
+        assert_eq!(version, 2);
+
+        let wrong_tag = parse(&[0xA1, 0x03, 0x02, 0x01, 0x02]).unwrap();
+        let err = explicitly_tagged(wrong_tag, 0, TagClass::ContextSpecific, i64::from_der_node).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+
+        let primitive = parse(&[0x80, 0x01, 0x02]).unwrap();
+        let err = explicitly_tagged(primitive, 0, TagClass::ContextSpecific, i64::from_der_node).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+
+        for data in [&[0xA0, 0x00][..], &[0xA0, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02]] {
+            let err = explicitly_tagged(parse(data).unwrap(), 0, TagClass::ContextSpecific, i64::from_der_node).unwrap_err();
+            assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+        }
+
+        // SEQUENCE { [0] EXPLICIT INTEGER 2, INTEGER 7 }, taking the wrapper from the iterator.
+        let data = [0x30, 0x08, 0xA0, 0x03, 0x02, 0x01, 0x02, 0x02, 0x01, 0x07];
+        let parsed = sequence(parse(&data).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            let version = explicitly_tagged_from_iterator(iter, 0, TagClass::ContextSpecific, i64::from_der_node)?;
+            Ok((version, i64::from_der_iterator(iter)?))
+        });
+        assert_eq!(parsed.unwrap(), (2, 7));
+        let err = sequence(parse(&[0x30, 0x00]).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            explicitly_tagged_from_iterator(iter, 0, TagClass::ContextSpecific, i64::from_der_node)
+        })
+        .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+    }
+
+    #[test]
+    fn test_optional_explicitly_tagged() {
+        // SEQUENCE { [0] EXPLICIT INTEGER 2 OPTIONAL, [1] EXPLICIT BOOLEAN OPTIONAL, INTEGER 7 }
+        let data = [0x30, 0x08, 0xA0, 0x03, 0x02, 0x01, 0x02, 0x02, 0x01, 0x07];
+        let (version, flag, serial) = sequence(parse(&data).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            let version = optional_explicitly_tagged::<i64>(iter, 0, TagClass::ContextSpecific)?;
+            let flag = optional_explicitly_tagged::<bool>(iter, 1, TagClass::ContextSpecific)?;
+            Ok((version, flag, i64::from_der_iterator(iter)?))
+        })
+        .unwrap();
+        assert_eq!((version, flag, serial), (Some(2), None, 7));
+
+        // A matching tag with the wrong inner type is an error, not an absent field.
+        let data = [0x30, 0x05, 0xA0, 0x03, 0x01, 0x01, 0xFF];
+        let err = sequence(parse(&data).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            optional_explicitly_tagged::<i64>(iter, 0, TagClass::ContextSpecific)
+        })
+        .unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+    }
+
+    #[test]
+    fn test_decode_default() {
+        assert_eq!(decode_default(None, || 1i64).unwrap(), 1);
+        assert_eq!(decode_default(Some(2i64), || 1).unwrap(), 2);
+        let err = decode_default(Some(1i64), || 1).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+    }
+
+    #[derive(Debug, PartialEq)]
+    enum Name {
+        Email(ASN1OctetString),
+        Number(i64),
+        Nested(Vec<bool>),
+        Flag(bool),
+    }
+
+    impl DERParseable for Name {
+        fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+            choice(node)
+                .implicit(ASN1Identifier::new(1, TagClass::ContextSpecific), Name::Email)
+                .alternative(Name::Number)
+                .explicit(4, TagClass::ContextSpecific, Name::Nested)
+                .alternative(Name::Flag)
+                .finish()
+        }
+    }
+
+    impl Choice for Name {
+        fn can_decode(identifier: ASN1Identifier) -> bool {
+            identifier == ASN1Identifier::new(1, TagClass::ContextSpecific)
+                || identifier == ASN1Identifier::new(4, TagClass::ContextSpecific)
+                || <i64 as Choice>::can_decode(identifier)
+                || <bool as Choice>::can_decode(identifier)
+        }
+    }
+
+    #[test]
+    fn test_choice_picks_alternative_by_identifier() {
+        let cases: [(&[u8], Name); 4] = [
+            (&[0x81, 0x01, 0x61], Name::Email(ASN1OctetString::from(vec![0x61]))),
+            (&[0x02, 0x01, 0x07], Name::Number(7)),
+            (&[0xA4, 0x05, 0x30, 0x03, 0x01, 0x01, 0x00], Name::Nested(vec![false])),
+            (&[0x01, 0x01, 0xFF], Name::Flag(true)),
+        ];
+        for (data, expected) in cases {
+            assert_eq!(Name::from_der_bytes(data).unwrap(), expected);
+        }
+
+        // No alternative matches.
+        let err = Name::from_der_bytes(&[0x05, 0x00]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+        // The matching alternative's error is reported, not a fall-through.
+        let err = Name::from_der_bytes(&[0x02, 0x02, 0x00, 0x01]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1IntegerEncoding);
+
+        // Nested CHOICE types are alternatives too.
+        let nested = choice::<Option<Name>>(parse(&[0x02, 0x01, 0x01]).unwrap())
+            .alternative(|name: Name| Some(name))
+            .finish()
+            .unwrap();
+        assert_eq!(nested, Some(Name::Number(1)));
+    }
+
+    #[test]
+    fn test_serialize_with_identifier() {
+        let tag = ASN1Identifier::new(0, TagClass::ContextSpecific);
+        let mut serializer = Serializer::new();
+        serializer.serialize_with_identifier(&ASN1Integer::from(5), tag).unwrap();
+        serializer.serialize_with_identifier(&vec![true], ASN1Identifier::new(1, TagClass::ContextSpecific)).unwrap();
+        serializer.serialize_with_identifier(&ASN1OctetString::from(vec![0xAB; 200]), ASN1Identifier::new(40, TagClass::Application)).unwrap();
+        let encoded = serializer.serialized_bytes();
+        assert_eq!(&encoded[..8], &[0x80, 0x01, 0x05, 0xA1, 0x03, 0x01, 0x01, 0xFF]);
+        assert_eq!(&encoded[8..12], &[0x5F, 0x28, 0x81, 0xC8]);
+        assert_eq!(encoded.len(), 12 + 200);
+        assert_eq!(serializer.node_count(), 3);
+
+        let node = parse(&encoded[..3]).unwrap();
+        assert_eq!(ASN1Integer::from_der_node_with_identifier(node, tag).unwrap(), ASN1Integer::from(5));
+    }
+
+    #[test]
+    fn test_der_set_of_enforces_ordering() {
+        // SET OF { INTEGER 1, INTEGER 2, INTEGER 2 }: ascending, duplicates allowed.
+        let sorted = [0x31, 0x09, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02, 0x02, 0x01, 0x02];
+        let values = set_of::<i64>(ASN1Identifier::SET, parse(&sorted).unwrap()).unwrap();
+        assert_eq!(values, vec![1, 2, 2]);
+
+        let unsorted = [0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01];
+        let err = set_of::<i64>(ASN1Identifier::SET, parse(&unsorted).unwrap()).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+
+        let err = set_of::<i64>(ASN1Identifier::SET, parse(&[0x30, 0x00]).unwrap()).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+        assert!(set_of::<i64>(ASN1Identifier::SET, parse(&[0x31, 0x00]).unwrap()).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_write_set_of_sorts_encodings() {
+        let mut serializer = Serializer::new();
+        serializer.write_set_of(&[300i64, -1, 5, 5]).unwrap();
+        let encoded = serializer.serialized_bytes();
+        // 02 01 05 < 02 01 05 < 02 01 FF < 02 02 01 2C
+        assert_eq!(
+            encoded.as_ref(),
+            &[0x31, 0x0D, 0x02, 0x01, 0x05, 0x02, 0x01, 0x05, 0x02, 0x01, 0xFF, 0x02, 0x02, 0x01, 0x2C]
+        );
+        assert_eq!(set_of::<i64>(ASN1Identifier::SET, parse(&encoded).unwrap()).unwrap(), vec![5, 5, -1, 300]);
+
+        let mut serializer = Serializer::new();
+        serializer.write_set_of::<bool>(&[]).unwrap();
+        assert_eq!(serializer.serialized_bytes().as_ref(), &[0x31, 0x00]);
+    }
+
+    #[test]
+    fn test_set_of_ordering_pads_with_zeros() {
+        use std::cmp::Ordering;
+        assert_eq!(set_of_ordering(&[0x04, 0x01], &[0x04, 0x01, 0x00]), Ordering::Equal);
+        assert_eq!(set_of_ordering(&[0x04, 0x01], &[0x04, 0x01, 0x01]), Ordering::Less);
+        assert_eq!(set_of_ordering(&[0x04, 0x02], &[0x04, 0x01, 0xFF]), Ordering::Greater);
+    }
+
     #[test]
     fn test_identifier_writing_edge_cases() {
         let mut buf = Vec::new();
@@ -662,7 +1509,7 @@ mod tests {
         let mut serializer = Serializer::new();
         serializer
             .append_primitive_node(ASN1Identifier::OCTET_STRING, |buf| {
-                buf.extend_from_slice(&vec![0u8; 128]);
+                buf.extend_from_slice(&[0u8; 128]);
                 Ok(())
             })
             .unwrap();
@@ -706,6 +1553,32 @@ mod tests {
         assert_eq!(buf, vec![0x1F, 0x81, 0x00]);
     }
 
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_serializer_with_buf_mut() {
+        let mut array = [0u8; 4];
+        let mut dest = &mut array[..];
+        let mut serializer = Serializer::with_buf_mut(&mut dest);
+        serializer.serialize(&ASN1Integer::from(5)).unwrap();
+        assert_eq!((serializer.len(), serializer.node_count()), (3, 1));
+        assert!(serializer.as_bytes().is_empty());
+
+        // A node too big for the space left is not written at all.
+        let err = serializer.serialize(&ASN1Integer::from(5)).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::BufferTooSmall);
+        assert_eq!(serializer.len(), 3);
+        assert_eq!(dest.len(), 1);
+        assert_eq!(array, [0x02, 0x01, 0x05, 0x00]);
+
+        // Constructed nodes and implicit tags go through as whole nodes.
+        let mut vec = vec![0xFF];
+        let mut serializer = Serializer::with_buf_mut(&mut vec);
+        serializer.serialize(&vec![true]).unwrap();
+        serializer.serialize_with_identifier(&ASN1Integer::from(5), ASN1Identifier::new(0, TagClass::ContextSpecific)).unwrap();
+        assert_eq!(serializer.node_count(), 2);
+        assert_eq!(vec, vec![0xFF, 0x30, 0x03, 0x01, 0x01, 0xFF, 0x80, 0x01, 0x05]);
+    }
+
     #[test]
     fn test_der_serializer_append() {
         let mut serializer = Serializer::new();
@@ -730,6 +1603,7 @@ mod tests {
     }
 
     #[test]
+    #[cfg(feature = "strings")]
     fn test_string_roundtrip() {
         let bytes = vec![0x0C, 0x02, b'H', b'I'];
         let node = parse(&bytes).unwrap();
@@ -741,6 +1615,188 @@ mod tests {
         assert_eq!(serializer.serialized_bytes(), bytes);
     }
 
+    #[test]
+    fn test_bytes_roundtrip_as_octet_string() {
+        let bytes = vec![0x04, 0x03, 0x01, 0x02, 0x03];
+        let node = parse(&bytes).unwrap();
+        let value = Bytes::from_der_node(node).unwrap();
+        assert_eq!(value.as_ref(), &[0x01, 0x02, 0x03]);
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(&value).unwrap();
+        assert_eq!(serializer.serialized_bytes(), bytes);
+
+        assert!(Bytes::from_der_bytes(&[0x30, 0x00]).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_borrowed_strings_and_byte_buffers() {
+        let mut serializer = Serializer::new();
+        let name: &str = "HI";
+        serializer.serialize(&name).unwrap();
+        serializer.serialize("HI").unwrap();
+        assert_eq!(serializer.serialized_bytes(), vec![0x0C, 0x02, b'H', b'I', 0x0C, 0x02, b'H', b'I']);
+
+        // Bytes are an OCTET STRING through `ASN1OctetString`, `Bytes`, `OctetStringOf` or a
+        // fixed-size array; a bare `[u8]` is a SEQUENCE OF INTEGER, the same as `Vec<u8>`.
+        let bytes: &[u8] = &[0x01, 0x02];
+        let mut serializer = Serializer::new();
+        serializer.serialize(&ASN1OctetString::from(bytes)).unwrap();
+        serializer.serialize(&Bytes::copy_from_slice(bytes)).unwrap();
+        serializer.serialize(&crate::asn1_types::OctetStringOf(bytes)).unwrap();
+        serializer.serialize(&[0x01u8, 0x02]).unwrap();
+        assert_eq!(serializer.serialized_bytes(), [0x04, 0x02, 0x01, 0x02].repeat(4));
+        let mut serializer = Serializer::new();
+        serializer.serialize(bytes).unwrap();
+        serializer.serialize(&bytes.to_vec()).unwrap();
+        let sequence_of = [0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
+        assert_eq!(serializer.serialized_bytes(), [sequence_of, sequence_of].concat());
+    }
+
+    #[test]
+    fn test_byte_arrays_as_octet_strings() {
+        let mut serializer = Serializer::new();
+        serializer.serialize(&[0xABu8, 0xCD]).unwrap();
+        serializer.serialize(&[0u8; 0]).unwrap();
+        assert_eq!(serializer.serialized_bytes(), vec![0x04, 0x02, 0xAB, 0xCD, 0x04, 0x00]);
+        assert_eq!(<[u8; 2]>::from_der_bytes(&[0x04, 0x02, 0xAB, 0xCD]).unwrap(), [0xAB, 0xCD]);
+        assert_eq!(<[u8; 0]>::from_der_bytes(&[0x04, 0x00]).unwrap(), [0u8; 0]);
+        let err = <[u8; 3]>::from_der_bytes(&[0x04, 0x02, 0xAB, 0xCD]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+        assert!(<[u8; 0]>::from_der_bytes(&[0x02, 0x01, 0x00]).is_err());
+
+        // As a field, an array is tagged and recognised like any OCTET STRING.
+        let mut serializer = Serializer::new();
+        serializer.serialize_with_identifier(&[0x01u8], ASN1Identifier::new(0, TagClass::ContextSpecific)).unwrap();
+        assert_eq!(serializer.serialized_bytes(), vec![0x80, 0x01, 0x01]);
+        let data = [0x30, 0x06, 0x04, 0x01, 0x01, 0x02, 0x01, 0x05];
+        let fields = sequence(parse(&data).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            Ok((Option::<[u8; 1]>::from_der_iterator(iter)?, Option::<[u8; 1]>::from_der_iterator(iter)?, i64::from_der_iterator(iter)?))
+        });
+        assert_eq!(fields.unwrap(), (Some([0x01]), None, 5));
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_tuples_as_anonymous_sequences() {
+        let value = (5i64, true, Some(String::from("x")));
+        let mut serializer = Serializer::new();
+        serializer.serialize(&value).unwrap();
+        let encoded = serializer.serialized_bytes();
+        assert_eq!(encoded, vec![0x30, 0x09, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF, 0x0C, 0x01, b'x']);
+        assert_eq!(<(i64, bool, Option<String>)>::from_der_bytes(&encoded).unwrap(), value);
+
+        // A trailing OPTIONAL may be absent; extra elements are not allowed.
+        let short = [0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF];
+        assert_eq!(<(i64, bool, Option<String>)>::from_der_bytes(&short).unwrap(), (5, true, None));
+        assert!(<(i64,)>::from_der_bytes(&short).is_err());
+        assert!(<(i64, bool)>::from_der_bytes(&[0x31, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF]).is_err());
+
+        let wide = (1u8, 2u8, 3u8, 4u8, 5u8, 6u8, 7u8, 8u8, 9u8, 10u8, 11u8, 12u8);
+        let mut serializer = Serializer::new();
+        serializer.serialize(&wide).unwrap();
+        type Wide = (u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8, u8);
+        assert_eq!(Wide::from_der_bytes(&serializer.serialized_bytes()).unwrap(), wide);
+
+        let tag = ASN1Identifier::new(2, TagClass::ContextSpecific);
+        let mut serializer = Serializer::new();
+        serializer.serialize_with_identifier(&(7i64, false), tag).unwrap();
+        let node = parse(&serializer.serialized_bytes()).unwrap();
+        assert_eq!(<(i64, bool)>::from_der_node_with_identifier(node, tag).unwrap(), (7, false));
+    }
+
+    #[test]
+    fn test_references_and_boxes() {
+        // A recursive list: SEQUENCE { INTEGER, next [0] IMPLICIT List OPTIONAL }.
+        #[derive(Debug, PartialEq)]
+        struct List(i64, Option<Box<List>>);
+        let tag = ASN1Identifier::new(0, TagClass::ContextSpecific);
+        impl DERSerializable for List {
+            fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+                serializer.write_sequence(|seq| {
+                    seq.serialize(&self.0)?;
+                    if let Some(next) = &self.1 {
+                        next.serialize_with_identifier(seq, ASN1Identifier::new(0, TagClass::ContextSpecific))?;
+                    }
+                    Ok(())
+                })
+            }
+        }
+        impl DERParseable for List {
+            fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+                Self::from_der_node_with_identifier(node, ASN1Identifier::SEQUENCE)
+            }
+        }
+        impl DERImplicitlyTaggable for List {
+            fn default_identifier() -> ASN1Identifier {
+                ASN1Identifier::SEQUENCE
+            }
+            fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+                sequence(node, identifier, |iter| {
+                    let value = i64::from_der_iterator(iter)?;
+                    let next = optional_implicitly_tagged::<Box<List>>(iter, ASN1Identifier::new(0, TagClass::ContextSpecific))?;
+                    Ok(List(value, next))
+                })
+            }
+        }
+
+        let list = List(1, Some(Box::new(List(2, None))));
+        let mut serializer = Serializer::new();
+        serializer.serialize(&&list).unwrap();
+        let encoded = serializer.serialized_bytes();
+        assert_eq!(encoded, vec![0x30, 0x08, 0x02, 0x01, 0x01, 0xA0, 0x03, 0x02, 0x01, 0x02]);
+        assert_eq!(List::from_der_bytes(&encoded).unwrap(), list);
+        assert_eq!(<Box<List>>::default_identifier(), ASN1Identifier::SEQUENCE);
+        let node = parse(&[0xA0, 0x03, 0x02, 0x01, 0x02]).unwrap();
+        assert_eq!(*<Box<List>>::from_der_node_with_identifier(node, tag).unwrap(), List(2, None));
+
+        // Borrowed elements serialize like owned ones.
+        let values = [ASN1Boolean::from(true)];
+        let borrowed: Vec<&ASN1Boolean> = values.iter().collect();
+        let mut serializer = Serializer::new();
+        serializer.serialize(&borrowed).unwrap();
+        assert_eq!(serializer.serialized_bytes(), vec![0x30, 0x03, 0x01, 0x01, 0xFF]);
+
+        // Boxed slices and strings serialize like their owned forms.
+        let boxed: Box<[i64]> = vec![1, 2].into_boxed_slice();
+        let mut serializer = Serializer::new();
+        serializer.serialize(&boxed).unwrap();
+        assert_eq!(serializer.serialized_bytes(), vec![0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02]);
+        #[cfg(feature = "strings")]
+        {
+            let boxed: Box<str> = "x".into();
+            let mut serializer = Serializer::new();
+            serializer.serialize(&boxed).unwrap();
+            assert_eq!(serializer.serialized_bytes(), vec![0x0C, 0x01, b'x']);
+        }
+
+        // A boxed OPTIONAL may be absent from the end of a SEQUENCE.
+        let parsed = <(i64, Box<Option<i64>>)>::from_der_bytes(&[0x30, 0x03, 0x02, 0x01, 0x05]).unwrap();
+        assert_eq!(parsed, (5, Box::new(None)));
+
+        let node = crate::ber::parse(&[0x02, 0x02, 0x00, 0x05]).unwrap();
+        assert_eq!(*<Box<ASN1Integer> as crate::ber::BERParseable>::from_ber_node(node).unwrap(), ASN1Integer::from(5));
+    }
+
+    #[test]
+    #[cfg(feature = "strings")]
+    fn test_str_serializes_as_utf8_string() {
+        let mut serializer = Serializer::new();
+        serializer.serialize("HI").unwrap();
+        assert_eq!(serializer.serialized_bytes(), vec![0x0C, 0x02, b'H', b'I']);
+
+        let owned = String::from("HI");
+        let borrowed: &str = &owned;
+        let mut from_borrowed = Serializer::new();
+        from_borrowed.serialize(borrowed).unwrap();
+        assert_eq!(from_borrowed.serialized_bytes(), serializer.serialized_bytes());
+
+        let mut empty = Serializer::new();
+        empty.serialize("").unwrap();
+        assert_eq!(empty.serialized_bytes(), vec![0x0C, 0x00]);
+    }
+
     #[test]
     fn test_signed_integer_roundtrip() {
         let bytes = vec![0x02, 0x01, 0x7F];
@@ -765,6 +1821,39 @@ mod tests {
         assert_eq!(serializer.serialized_bytes(), bytes);
     }
 
+    #[test]
+    fn test_machine_integer_boundaries() {
+        fn content<T: DERSerializable>(value: T) -> Vec<u8> {
+            let mut serializer = Serializer::new();
+            serializer.serialize(&value).unwrap();
+            serializer.serialized_bytes()[2..].to_vec()
+        }
+        assert_eq!(content(0u8), vec![0x00]);
+        assert_eq!(content(255u8), vec![0x00, 0xFF]);
+        assert_eq!(content(-128i8), vec![0x80]);
+        assert_eq!(content(-129i16), vec![0xFF, 0x7F]);
+        assert_eq!(content(i64::MIN), vec![0x80, 0, 0, 0, 0, 0, 0, 0]);
+        assert_eq!(content(u128::MAX), [&[0x00][..], &[0xFF; 16]].concat());
+        assert_eq!(content(i128::MIN), [&[0x80][..], &[0x00; 15]].concat());
+
+        for value in [i128::MIN, -1, 0, 1, i128::MAX] {
+            assert_eq!(i128::from_der_bytes(&[&[0x02, content(value).len() as u8][..], &content(value)].concat()).unwrap(), value);
+        }
+        let max = [&[0x02, 0x11][..], &content(u128::MAX)].concat();
+        assert_eq!(u128::from_der_bytes(&max).unwrap(), u128::MAX);
+        assert_eq!(u64::from_der_bytes(&[0x02, 0x09, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap(), u64::MAX);
+
+        for bytes in [&[0x02, 0x02, 0x00, 0x80][..], &[0x02, 0x02, 0xFF, 0x7F], &max] {
+            assert_eq!(i8::from_der_bytes(bytes).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        }
+        assert_eq!(u32::from_der_bytes(&[0x02, 0x01, 0xFF]).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(i128::from_der_bytes(&max).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+        assert_eq!(u8::from_der_bytes(&[0x02, 0x02, 0x00, 0x05]).unwrap_err().code(), ErrorCode::InvalidASN1IntegerEncoding);
+        assert_eq!(i16::from_der_bytes(&[0x02, 0x02, 0xFF, 0x80]).unwrap_err().code(), ErrorCode::InvalidASN1IntegerEncoding);
+        assert_eq!(i32::from_der_bytes(&[0x02, 0x00]).unwrap_err().code(), ErrorCode::InvalidASN1Object);
+        assert_eq!(i32::from_der_bytes(&[0x0A, 0x01, 0x00]).unwrap_err().code(), ErrorCode::UnexpectedFieldType);
+    }
+
     #[test]
     fn test_vec_der_roundtrip() {
         let bytes = vec![0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02];
@@ -777,6 +1866,37 @@ mod tests {
         assert_eq!(serializer.serialized_bytes(), bytes);
     }
 
+    #[test]
+    fn test_slice_serializes_as_sequence_of() {
+        let values = [1i64, 2];
+        let borrowed: &[i64] = &values;
+
+        let mut serializer = Serializer::new();
+        serializer.serialize(borrowed).unwrap();
+        assert_eq!(
+            serializer.serialized_bytes(),
+            vec![0x30, 0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02]
+        );
+
+        let mut from_vec = Serializer::new();
+        from_vec.serialize(&values.to_vec()).unwrap();
+        assert_eq!(from_vec.serialized_bytes(), serializer.serialized_bytes());
+
+        let mut empty = Serializer::new();
+        empty.serialize::<[bool]>(&[]).unwrap();
+        assert_eq!(empty.serialized_bytes(), vec![0x30, 0x00]);
+
+        // `&[T]` itself is serializable, so it can fill a generic parameter or a field.
+        fn encode<T: DERSerializable>(value: T) -> Bytes {
+            let mut serializer = Serializer::new();
+            serializer.serialize(&value).unwrap();
+            serializer.serialized_bytes()
+        }
+        assert_eq!(encode(borrowed), serializer.serialized_bytes());
+        assert_eq!(encode((borrowed, true)), encode((values.to_vec(), true)));
+        assert_eq!(encode::<&[bool]>(&[]), vec![0x30, 0x00]);
+    }
+
     #[test]
     fn test_option_absent_and_present() {
         fn parse_optional(bytes: &[u8]) -> Result<Option<bool>, ASN1Error> {
@@ -794,6 +1914,80 @@ mod tests {
         assert_eq!(parse_optional(&present).unwrap(), Some(true));
     }
 
+    #[test]
+    fn test_optional_implicitly_tagged() {
+        fn parse_optional(bytes: &[u8]) -> Result<(Option<i64>, bool), ASN1Error> {
+            let node = parse(bytes)?;
+            sequence(node, ASN1Identifier::SEQUENCE, |iter| {
+                let tag = ASN1Identifier::new(1, TagClass::ContextSpecific);
+                let value = optional_implicitly_tagged::<i64>(iter, tag)?;
+                let flag = bool::from_der_iterator(iter)?;
+                Ok((value, flag))
+            })
+        }
+
+        // SEQUENCE { [1] IMPLICIT INTEGER 5, BOOLEAN TRUE }
+        let present = vec![0x30, 0x06, 0x81, 0x01, 0x05, 0x01, 0x01, 0xFF];
+        assert_eq!(parse_optional(&present).unwrap(), (Some(5), true));
+
+        // SEQUENCE { BOOLEAN TRUE }
+        let absent = vec![0x30, 0x03, 0x01, 0x01, 0xFF];
+        assert_eq!(parse_optional(&absent).unwrap(), (None, true));
+
+        // A universal INTEGER does not match the context-specific tag.
+        let untagged = vec![0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF];
+        assert!(parse_optional(&untagged).is_err());
+    }
+
+    #[test]
+    #[cfg(feature = "bytes")]
+    fn test_parse_buf_from_chained_segments() {
+        let head: &[u8] = &[0x30, 0x06, 0x02, 0x01];
+        let tail: &[u8] = &[0x01, 0x02, 0x01, 0x02];
+        let node = parse_buf(head.chain(tail)).unwrap();
+        assert_eq!(Vec::<i64>::from_der_node(node).unwrap(), vec![1, 2]);
+
+        let contiguous = Bytes::from_static(&[0x02, 0x01, 0x2A]);
+        let node = parse_buf(contiguous.clone()).unwrap();
+        assert_eq!(node.encoded_bytes.as_ptr(), contiguous.as_ptr());
+
+        // A chain whose first segment holds the whole document is not copied either.
+        let node = parse_buf(contiguous.clone().chain(Bytes::new())).unwrap();
+        assert_eq!(node.encoded_bytes.as_ptr(), contiguous.as_ptr());
+
+        assert!(parse_buf(&[0x02, 0x01][..]).is_err());
+    }
+
+    #[test]
+    fn test_split_documents() {
+        let data = Bytes::from(vec![0x02, 0x01, 0x01, 0x30, 0x03, 0x01, 0x01, 0xFF, 0x05, 0x00]);
+        let docs = split_documents(&data).unwrap();
+        assert_eq!(docs, vec![&data[0..3], &data[3..8], &data[8..10]]);
+        assert_eq!(docs[1].as_ptr(), data[3..].as_ptr());
+        assert!(split_documents(&Bytes::new()).unwrap().is_empty());
+        assert!(split_documents(&Bytes::from(vec![0x02, 0x01, 0x01, 0x02, 0x02, 0x01])).is_err());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn test_par_parse_documents() {
+        use rayon::prelude::*;
+
+        let mut data = Vec::new();
+        for i in 0..100u8 {
+            data.extend_from_slice(&[0x02, 0x01, i]);
+        }
+        let values: Vec<u8> = par_parse_documents(&data).unwrap();
+        assert_eq!(values, (0..100).collect::<Vec<u8>>());
+
+        // A malformed document only fails its own item.
+        let data = vec![0x02, 0x01, 0x01, 0x30, 0x02, 0x02, 0x05];
+        let results: Vec<_> = par_iter_documents(&data).unwrap().collect();
+        assert!(results[0].is_ok());
+        assert!(results[1].is_err());
+        assert!(par_parse_documents::<i64>(&data).is_err());
+    }
+
     #[test]
     fn test_serializer_write_sequence_helper() {
         let mut serializer = Serializer::new();
@@ -810,4 +2004,54 @@ mod tests {
             vec![0x30, 0x06, 0x02, 0x01, 0x05, 0x01, 0x01, 0xFF]
         );
     }
+
+    #[test]
+    fn test_parse_file_detects_pem_and_der() {
+        let dir = std::env::temp_dir().join(format!("rust-asn1-parse-file-{}", std::process::id()));
+        std::fs::create_dir_all(&dir).unwrap();
+        let der_path = dir.join("value.der");
+        let pem_path = dir.join("value.pem");
+        let empty_path = dir.join("empty.pem");
+        std::fs::write(&der_path, [0x02, 0x01, 0x2A]).unwrap();
+        std::fs::write(&pem_path, "\n-----BEGIN NUMBER-----\nAgEq\n-----END NUMBER-----\n").unwrap();
+        std::fs::write(&empty_path, "no documents here\n").unwrap();
+
+        assert_eq!(parse_file(&der_path).unwrap().encoded_bytes.as_ref(), &[0x02, 0x01, 0x2A]);
+        assert_eq!(parse_file(&pem_path).unwrap().encoded_bytes.as_ref(), &[0x02, 0x01, 0x2A]);
+        assert_eq!(parse_pem_file(&pem_path).unwrap().encoded_bytes.as_ref(), &[0x02, 0x01, 0x2A]);
+        assert_eq!(i64::from_der_file(&pem_path).unwrap(), 42);
+
+        assert_eq!(parse_pem_file(&der_path).unwrap_err().code(), ErrorCode::InvalidPEMDocument);
+        assert_eq!(parse_pem_file(&empty_path).unwrap_err().code(), ErrorCode::InvalidPEMDocument);
+        assert_eq!(
+            parse_file(dir.join("missing.der")).unwrap_err().code(),
+            ErrorCode::InvalidASN1Object
+        );
+
+        std::fs::remove_dir_all(&dir).unwrap();
+    }
+
+    #[test]
+    fn test_serializer_introspection() {
+        let mut serializer = Serializer::new();
+        assert!(serializer.is_empty());
+        assert_eq!((serializer.len(), serializer.node_count()), (0, 0));
+
+        serializer.serialize(&5i64).unwrap();
+        serializer
+            .write_sequence(|seq| {
+                seq.serialize(&true)?;
+                seq.serialize(&false)?;
+                assert_eq!(seq.node_count(), 2);
+                Ok(())
+            })
+            .unwrap();
+        serializer.serialize(&parse(&[0x05, 0x00]).unwrap()).unwrap();
+
+        assert!(!serializer.is_empty());
+        assert_eq!(serializer.as_bytes(), &serializer.serialized_bytes()[..]);
+        assert_eq!(serializer.len(), serializer.serialized_bytes().len());
+        assert_eq!(serializer.len(), 3 + 8 + 2);
+        assert_eq!(serializer.node_count(), 3);
+    }
 }
@@ -11,6 +11,7 @@ struct Backing {
     reason: String,
     file: String,
     line: u32,
+    context: Option<String>,
 }
 
 impl ASN1Error {
@@ -21,6 +22,7 @@ impl ASN1Error {
                 reason,
                 file,
                 line,
+                context: None,
             },
         }
     }
@@ -28,6 +30,38 @@ impl ASN1Error {
     pub fn code(&self) -> ErrorCode {
         self.backing.code
     }
+
+    /// A hex excerpt of the input around the failure point, when the `debug-errors`
+    /// feature is enabled and the error came from the parser.
+    ///
+    /// The context is diagnostic only and does not take part in equality or hashing.
+    pub fn context(&self) -> Option<&str> {
+        self.backing.context.as_deref()
+    }
+
+    /// Attaches a bounded hex excerpt of `data` around `offset`, marking the byte at `offset`.
+    #[cfg(feature = "debug-errors")]
+    pub(crate) fn with_byte_context(mut self, data: &[u8], offset: usize) -> Self {
+        const BEFORE: usize = 8;
+        const AFTER: usize = 16;
+
+        let offset = offset.min(data.len());
+        let start = offset.saturating_sub(BEFORE);
+        let end = offset.saturating_add(AFTER).min(data.len());
+        let mut excerpt = format!("offset {}:", offset);
+        if start > 0 {
+            excerpt.push_str(" ..");
+        }
+        for (i, byte) in data[start..end].iter().enumerate() {
+            let marker = if start + i == offset { ">" } else { "" };
+            excerpt.push_str(&format!(" {}{:02X}", marker, byte));
+        }
+        if end < data.len() {
+            excerpt.push_str(" ..");
+        }
+        self.backing.context = Some(excerpt);
+        self
+    }
 }
 
 impl PartialEq for ASN1Error {
@@ -56,7 +90,11 @@ impl fmt::Display for ASN1Error {
             f,
             "ASN1Error.{:?}: {} {}:{}",
             self.backing.code, self.backing.reason, self.backing.file, self.backing.line
-        )
+        )?;
+        if let Some(context) = &self.backing.context {
+            write!(f, " ({})", context)?;
+        }
+        Ok(())
     }
 }
 
@@ -73,6 +111,7 @@ pub enum ErrorCode {
     InvalidStringRepresentation,
     TooFewOIDComponents,
     ValueOutOfRange,
+    BufferTooSmall,
 }
 
 #[macro_export]
@@ -0,0 +1,191 @@
+//! Kerberos (RFC 4120) profiles of the core ASN.1 types.
+
+use crate::asn1::ASN1Node;
+use crate::asn1_types::strings::impl_string_type;
+use crate::asn1_types::{ASN1BitString, ASN1Identifier, GeneralizedTime};
+use crate::ber::{BERImplicitlyTaggable, BERParseable, BERSerializable};
+use crate::der::{DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
+use crate::errors::{ASN1Error, ErrorCode};
+use crate::buffer::Bytes;
+use chrono::{DateTime, Timelike, Utc};
+
+// KerberosString ::= GeneralString (IA5String)
+impl_string_type!(KerberosString, ASN1Identifier::GENERAL_STRING, |s: &str| s.is_ascii());
+
+/// `KerberosTime ::= GeneralizedTime -- with no fractional seconds`
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct KerberosTime(DateTime<Utc>);
+
+/// Length of `YYYYMMDDHHMMSSZ`, the only form KerberosTime permits.
+const KERBEROS_TIME_LEN: usize = 15;
+
+impl KerberosTime {
+    /// Creates a KerberosTime, discarding any sub-second precision.
+    pub fn new(dt: DateTime<Utc>) -> Self {
+        KerberosTime(dt.with_nanosecond(0).expect("zero nanoseconds is always valid"))
+    }
+
+    pub fn datetime(&self) -> DateTime<Utc> {
+        self.0
+    }
+}
+
+impl From<DateTime<Utc>> for KerberosTime {
+    fn from(dt: DateTime<Utc>) -> Self {
+        KerberosTime::new(dt)
+    }
+}
+
+impl DERParseable for KerberosTime {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, KerberosTime::default_identifier())
+    }
+}
+
+impl DERSerializable for KerberosTime {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        GeneralizedTime(self.0).serialize(serializer)
+    }
+}
+
+impl DERImplicitlyTaggable for KerberosTime {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::GENERALIZED_TIME
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        if let crate::asn1::Content::Primitive(bytes) = &node.content
+            && bytes.len() != KERBEROS_TIME_LEN
+        {
+            return Err(ASN1Error::new(ErrorCode::InvalidStringRepresentation, "KerberosTime must match YYYYMMDDHHMMSSZ".to_string(), file!().to_string(), line!()));
+        }
+        GeneralizedTime::from_der_node_with_identifier(node, identifier).map(|t| KerberosTime(t.0))
+    }
+}
+
+impl BERParseable for KerberosTime {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> { Self::from_der_node(node) }
+}
+impl BERSerializable for KerberosTime {}
+impl BERImplicitlyTaggable for KerberosTime {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> { Self::from_der_node_with_identifier(node, identifier) }
+}
+
+/// `KerberosFlags ::= BIT STRING (SIZE (32..MAX))`
+///
+/// Bit 0 is the most significant bit of the first octet, matching the numbering used by
+/// `TicketFlags`, `KDCOptions` and `APOptions`. Encodings always carry exactly 32 bits; on
+/// decode, missing bits read as zero and bits past 31 are ignored, as RFC 4120 requires.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Hash)]
+pub struct KerberosFlags(pub u32);
+
+impl KerberosFlags {
+    pub fn is_set(&self, bit: u8) -> bool {
+        bit < 32 && self.0 & Self::mask(bit) != 0
+    }
+
+    /// Sets `bit`. Panics if `bit` is not below 32.
+    pub fn set(&mut self, bit: u8) {
+        assert!(bit < 32, "KerberosFlags bit {} out of range", bit);
+        self.0 |= Self::mask(bit);
+    }
+
+    /// Clears `bit`. Panics if `bit` is not below 32.
+    pub fn clear(&mut self, bit: u8) {
+        assert!(bit < 32, "KerberosFlags bit {} out of range", bit);
+        self.0 &= !Self::mask(bit);
+    }
+
+    fn mask(bit: u8) -> u32 {
+        0x8000_0000 >> bit
+    }
+
+    fn from_bit_string(bits: ASN1BitString) -> Self {
+        let mut octets = [0u8; 4];
+        let len = bits.bytes.len().min(4);
+        octets[..len].copy_from_slice(&bits.bytes[..len]);
+        KerberosFlags(u32::from_be_bytes(octets))
+    }
+}
+
+impl DERParseable for KerberosFlags {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, KerberosFlags::default_identifier())
+    }
+}
+
+impl DERSerializable for KerberosFlags {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        ASN1BitString::new(Bytes::copy_from_slice(&self.0.to_be_bytes()), 0)?.serialize(serializer)
+    }
+}
+
+impl DERImplicitlyTaggable for KerberosFlags {
+    fn default_identifier() -> ASN1Identifier {
+        ASN1Identifier::BIT_STRING
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1BitString::from_der_node_with_identifier(node, identifier).map(Self::from_bit_string)
+    }
+}
+
+impl BERParseable for KerberosFlags {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_ber_node_with_identifier(node, KerberosFlags::default_identifier())
+    }
+}
+impl BERSerializable for KerberosFlags {}
+impl BERImplicitlyTaggable for KerberosFlags {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1BitString::from_ber_node_with_identifier(node, identifier).map(Self::from_bit_string)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::testing::{assert_der_roundtrip, assert_parses_to};
+    use chrono::TimeZone;
+
+    #[test]
+    fn test_kerberos_string() {
+        let name = KerberosString::new("krbtgt".to_string()).unwrap();
+        let encoded = assert_der_roundtrip(&name);
+        assert_eq!(encoded[0], 0x1B);
+        assert!(KerberosString::new("caf\u{e9}".to_string()).is_err());
+        assert!(KerberosString::from_der_bytes(&[0x16, 0x01, 0x61]).is_err());
+    }
+
+    #[test]
+    fn test_kerberos_time_drops_fractional_seconds() {
+        let dt = Utc.with_ymd_and_hms(2024, 2, 29, 23, 59, 58).unwrap();
+        let time = KerberosTime::new(dt + chrono::Duration::milliseconds(250));
+        assert_eq!(time.datetime(), dt);
+
+        let encoded = assert_der_roundtrip(&time);
+        assert_eq!(&encoded[2..], b"20240229235958Z");
+
+        let mut fractional = vec![0x18, 0x12];
+        fractional.extend_from_slice(b"20240229235958.25Z");
+        let err = KerberosTime::from_der_bytes(&fractional).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidStringRepresentation);
+    }
+
+    #[test]
+    fn test_kerberos_flags() {
+        let mut flags = KerberosFlags::default();
+        flags.set(1); // forwardable
+        flags.set(8); // renewable
+        assert!(flags.is_set(1) && flags.is_set(8) && !flags.is_set(2) && !flags.is_set(40));
+        flags.clear(8);
+        assert_eq!(flags, KerberosFlags(0x4000_0000));
+
+        let encoded = assert_der_roundtrip(&flags);
+        assert_eq!(encoded, vec![0x03, 0x05, 0x00, 0x40, 0x00, 0x00, 0x00]);
+
+        // Short and long encodings are tolerated.
+        assert_parses_to(&[0x03, 0x02, 0x00, 0x40], &KerberosFlags(0x4000_0000));
+        assert_parses_to(&[0x03, 0x06, 0x00, 0x40, 0x00, 0x00, 0x01, 0x80], &KerberosFlags(0x4000_0001));
+    }
+}
@@ -1,5 +1,13 @@
 pub mod asn1;
 pub mod asn1_types;
 pub mod ber;
+pub mod buffer;
 pub mod der;
 pub mod errors;
+mod lint;
+#[cfg(feature = "kerberos")]
+pub mod kerberos;
+pub mod pem;
+#[cfg(feature = "snmp")]
+pub mod snmp;
+pub mod testing;
@@ -0,0 +1,329 @@
+//! DER compliance checking that reports every violation instead of stopping at the first.
+
+use std::fmt;
+
+/// Nesting depth beyond which the walker gives up, matching the parser's limit.
+const MAXIMUM_DEPTH: usize = 50;
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ViolationKind {
+    /// A tag number below 31 in the long form, or with a leading 0x80 octet.
+    NonMinimalTag,
+    /// A length in the long form when the short form would do, or with leading zero octets.
+    NonMinimalLength,
+    /// The indefinite length form, which DER forbids.
+    IndefiniteLength,
+    /// SET elements that are not in DER canonical order.
+    UnsortedSet,
+    /// A BOOLEAN whose content is not a single 0x00 or 0xFF octet.
+    NonCanonicalBoolean,
+    /// An INTEGER or ENUMERATED that is empty or has redundant leading octets.
+    NonCanonicalInteger,
+    /// A BIT STRING with an invalid unused-bits count or non-zero unused bits.
+    NonCanonicalBitString,
+    /// A string type in the constructed form.
+    ConstructedString,
+    /// Bytes after the end of the top-level element.
+    TrailingData,
+    /// An element that could not be decoded at all; its contents are not checked.
+    Malformed,
+}
+
+/// A single DER violation and the byte offset of the element it was found in.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Violation {
+    pub offset: usize,
+    pub kind: ViolationKind,
+}
+
+impl fmt::Display for Violation {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        let description = match self.kind {
+            ViolationKind::NonMinimalTag => "tag number not minimally encoded",
+            ViolationKind::NonMinimalLength => "length not minimally encoded",
+            ViolationKind::IndefiniteLength => "indefinite length form",
+            ViolationKind::UnsortedSet => "SET elements not in canonical order",
+            ViolationKind::NonCanonicalBoolean => "BOOLEAN is not 0x00 or 0xFF",
+            ViolationKind::NonCanonicalInteger => "INTEGER not minimally encoded",
+            ViolationKind::NonCanonicalBitString => "BIT STRING padding is not canonical",
+            ViolationKind::ConstructedString => "string type uses the constructed form",
+            ViolationKind::TrailingData => "trailing data after the document",
+            ViolationKind::Malformed => "malformed element",
+        };
+        write!(f, "offset {}: {}", self.offset, description)
+    }
+}
+
+/// Checks `data` against the DER rules and returns every violation found, in document order.
+///
+/// Unlike [`crate::der::parse`], which rejects a document at the first problem, this walks
+/// the whole structure leniently (accepting BER forms) so that tools can report all issues
+/// at once. An empty result means no violations were detected.
+pub fn lint(data: &[u8]) -> Vec<Violation> {
+    let mut violations = Vec::new();
+    if let Some(element) = walk_element(data, 0, data.len(), 1, &mut violations)
+        && element.end < data.len()
+    {
+        violations.push(Violation { offset: element.end, kind: ViolationKind::TrailingData });
+    }
+    violations
+}
+
+struct Element {
+    offset: usize,
+    end: usize,
+    tag: (u8, u64),
+}
+
+/// Walks the element starting at `pos`, which must end by `limit`. Returns `None` when the
+/// element cannot be delimited, in which case a [`ViolationKind::Malformed`] was recorded.
+fn walk_element(
+    data: &[u8],
+    offset: usize,
+    limit: usize,
+    depth: usize,
+    out: &mut Vec<Violation>,
+) -> Option<Element> {
+    let malformed = |out: &mut Vec<Violation>| {
+        out.push(Violation { offset, kind: ViolationKind::Malformed });
+        None
+    };
+    let violation = |out: &mut Vec<Violation>, kind| out.push(Violation { offset, kind });
+
+    if depth > MAXIMUM_DEPTH || offset >= limit {
+        return malformed(out);
+    }
+    let mut pos = offset;
+    let first = data[pos];
+    pos += 1;
+    let class = first >> 6;
+    let constructed = first & 0x20 != 0;
+
+    let mut tag_number = u64::from(first & 0x1F);
+    if tag_number == 0x1F {
+        if pos < limit && data[pos] == 0x80 {
+            violation(out, ViolationKind::NonMinimalTag);
+        }
+        tag_number = 0;
+        loop {
+            let Some(&byte) = data[..limit].get(pos) else {
+                return malformed(out);
+            };
+            pos += 1;
+            let Some(next) = tag_number.checked_mul(128) else {
+                return malformed(out);
+            };
+            tag_number = next | u64::from(byte & 0x7F);
+            if byte & 0x80 == 0 {
+                break;
+            }
+        }
+        if tag_number < 0x1F {
+            violation(out, ViolationKind::NonMinimalTag);
+        }
+    }
+
+    let Some(&length_byte) = data[..limit].get(pos) else {
+        return malformed(out);
+    };
+    pos += 1;
+
+    let mut children = Vec::new();
+    let (content, end) = if length_byte == 0x80 {
+        violation(out, ViolationKind::IndefiniteLength);
+        if !constructed {
+            return malformed(out);
+        }
+        let content_start = pos;
+        loop {
+            if pos + 2 <= limit && data[pos] == 0 && data[pos + 1] == 0 {
+                break;
+            }
+            let child = walk_element(data, pos, limit, depth + 1, out)?;
+            pos = child.end;
+            children.push(child);
+        }
+        (content_start..pos, pos + 2)
+    } else {
+        let length = if length_byte & 0x80 == 0 {
+            usize::from(length_byte)
+        } else {
+            let count = usize::from(length_byte & 0x7F);
+            if count == 0x7F || pos + count > limit {
+                return malformed(out);
+            }
+            let octets = &data[pos..pos + count];
+            pos += count;
+            let mut length: usize = 0;
+            for &octet in octets {
+                let Some(next) = length.checked_mul(256) else {
+                    return malformed(out);
+                };
+                length = next | usize::from(octet);
+            }
+            if length < 0x80 || octets[0] == 0 {
+                violation(out, ViolationKind::NonMinimalLength);
+            }
+            length
+        };
+        let Some(end) = pos.checked_add(length).filter(|end| *end <= limit) else {
+            return malformed(out);
+        };
+        if constructed {
+            let mut child_pos = pos;
+            while child_pos < end {
+                // A broken child only hides the rest of this element's contents.
+                let Some(child) = walk_element(data, child_pos, end, depth + 1, out) else {
+                    break;
+                };
+                child_pos = child.end;
+                children.push(child);
+            }
+        }
+        (pos..end, end)
+    };
+
+    if class == 0 {
+        check_universal(data, tag_number, constructed, &data[content], &children, offset, out);
+    }
+    Some(Element { offset, end, tag: (class, tag_number) })
+}
+
+fn check_universal(
+    data: &[u8],
+    tag_number: u64,
+    constructed: bool,
+    content: &[u8],
+    children: &[Element],
+    offset: usize,
+    out: &mut Vec<Violation>,
+) {
+    let mut violation = |kind| out.push(Violation { offset, kind });
+    let redundant_integer_octet = matches!(content, [0x00, next, ..] if next & 0x80 == 0)
+        || matches!(content, [0xFF, next, ..] if next & 0x80 != 0);
+    match tag_number {
+        // BOOLEAN
+        1 if constructed || !matches!(content, [0x00] | [0xFF]) => {
+            violation(ViolationKind::NonCanonicalBoolean)
+        }
+        // INTEGER, ENUMERATED
+        2 | 10 if constructed || content.is_empty() || redundant_integer_octet => {
+            violation(ViolationKind::NonCanonicalInteger)
+        }
+        // BIT STRING
+        3 if constructed => violation(ViolationKind::ConstructedString),
+        3 if !canonical_bit_string(content) => violation(ViolationKind::NonCanonicalBitString),
+        // OCTET STRING, ObjectDescriptor, the character string types and the time types
+        4 | 7 | 12 | 18..=30 if constructed => violation(ViolationKind::ConstructedString),
+        // SET
+        17 if constructed => {
+            if let Some(position) = unsorted_position(data, children) {
+                out.push(Violation { offset: children[position].offset, kind: ViolationKind::UnsortedSet });
+            }
+        }
+        _ => {}
+    }
+}
+
+fn canonical_bit_string(content: &[u8]) -> bool {
+    match content {
+        [] => false,
+        [padding] => *padding == 0,
+        [padding, .., last] => *padding <= 7 && last & ((1u8 << *padding) - 1) == 0,
+    }
+}
+
+/// Returns the index of the first SET element that is out of order.
+///
+/// Elements with distinct tags (a SET) must be in tag order; elements sharing tags (a SET OF)
+/// must be in ascending order of their encodings.
+fn unsorted_position(data: &[u8], children: &[Element]) -> Option<usize> {
+    let mut tags: Vec<_> = children.iter().map(|c| c.tag).collect();
+    tags.sort_unstable();
+    let distinct_tags = tags.windows(2).all(|pair| pair[0] != pair[1]);
+
+    children.windows(2).position(|pair| {
+        if distinct_tags {
+            pair[0].tag > pair[1].tag
+        } else {
+            data[pair[0].offset..pair[0].end] > data[pair[1].offset..pair[1].end]
+        }
+    }).map(|index| index + 1)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kinds(data: &[u8]) -> Vec<(usize, ViolationKind)> {
+        lint(data).into_iter().map(|v| (v.offset, v.kind)).collect()
+    }
+
+    #[test]
+    fn test_lint_accepts_canonical_der() {
+        let data = [
+            0x30, 0x13, 0x01, 0x01, 0xFF, 0x02, 0x02, 0x00, 0x80, 0x03, 0x02, 0x04, 0xF0, 0x31,
+            0x06, 0x02, 0x01, 0x01, 0x02, 0x01, 0x02,
+        ];
+        assert!(lint(&data).is_empty());
+    }
+
+    #[test]
+    fn test_lint_reports_every_violation() {
+        let data = [
+            0x30, 0x81, 0x13, // non-minimal length
+            0x01, 0x01, 0x01, // BOOLEAN 0x01
+            0x02, 0x02, 0x00, 0x05, // INTEGER with redundant 0x00
+            0x03, 0x02, 0x04, 0xF1, // BIT STRING with set unused bits
+            0x31, 0x06, 0x02, 0x01, 0x02, 0x02, 0x01, 0x01, // unsorted SET OF
+            0x00, // trailing
+        ];
+        assert_eq!(
+            kinds(&data),
+            vec![
+                (0, ViolationKind::NonMinimalLength),
+                (3, ViolationKind::NonCanonicalBoolean),
+                (6, ViolationKind::NonCanonicalInteger),
+                (10, ViolationKind::NonCanonicalBitString),
+                (19, ViolationKind::UnsortedSet),
+                (22, ViolationKind::TrailingData),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_lint_ber_forms() {
+        // Indefinite-length constructed OCTET STRING.
+        let data = [0x24, 0x80, 0x04, 0x01, 0xAA, 0x00, 0x00];
+        assert_eq!(
+            kinds(&data),
+            vec![(0, ViolationKind::IndefiniteLength), (0, ViolationKind::ConstructedString)]
+        );
+
+        // Long-form tag for a low tag number.
+        assert_eq!(kinds(&[0x9F, 0x05, 0x00]), vec![(0, ViolationKind::NonMinimalTag)]);
+
+        // SET with distinct tags out of tag order; encodings would sort the other way.
+        let set = [0x31, 0x05, 0xA0, 0x00, 0x81, 0x01, 0x00];
+        assert_eq!(kinds(&set), vec![]);
+        let set = [0x31, 0x05, 0x81, 0x01, 0x00, 0xA0, 0x00];
+        assert_eq!(kinds(&set), vec![(5, ViolationKind::UnsortedSet)]);
+    }
+
+    #[test]
+    fn test_lint_malformed_input_is_contained() {
+        assert_eq!(kinds(&[]), vec![(0, ViolationKind::Malformed)]);
+        assert_eq!(kinds(&[0x02, 0x05, 0x01]), vec![(0, ViolationKind::Malformed)]);
+
+        // A broken element does not hide violations in its siblings.
+        let data = [0x30, 0x08, 0x30, 0x02, 0x02, 0x05, 0x01, 0x01, 0x01, 0x01];
+        assert_eq!(
+            kinds(&data),
+            vec![
+                (4, ViolationKind::Malformed),
+                (6, ViolationKind::NonCanonicalBoolean),
+                (9, ViolationKind::Malformed),
+            ]
+        );
+    }
+}
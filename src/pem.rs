@@ -0,0 +1,668 @@
+use crate::asn1::ASN1Node;
+use crate::der::{self, DERParseable, DERSerializable, Serializer};
+use crate::errors::{ASN1Error, ErrorCode};
+use std::io::{BufRead, BufReader, Read};
+
+const BEGIN_PREFIX: &str = "-----BEGIN ";
+const END_PREFIX: &str = "-----END ";
+const BOUNDARY_SUFFIX: &str = "-----";
+
+/// Lines longer than this are rejected so a missing newline cannot exhaust memory.
+const MAX_LINE_LENGTH: u64 = 64 * 1024;
+
+/// Base64 characters per line in generated PEM, as RFC 7468 requires.
+const LINE_WIDTH: usize = 64;
+
+const BASE64_ALPHABET: &[u8; 64] = b"ABCDEFGHIJKLMNOPQRSTUVWXYZabcdefghijklmnopqrstuvwxyz0123456789+/";
+
+fn pem_error(reason: String, line: u32) -> ASN1Error {
+    ASN1Error::new(ErrorCode::InvalidPEMDocument, reason, file!().to_string(), line)
+}
+
+fn boundary_label<'a>(line: &'a str, prefix: &str) -> Option<&'a str> {
+    line.strip_prefix(prefix)?.strip_suffix(BOUNDARY_SUFFIX)
+}
+
+fn base64_encode(data: &[u8]) -> String {
+    let mut output = String::with_capacity(data.len().div_ceil(3) * 4);
+    for chunk in data.chunks(3) {
+        let mut octets = [0u8; 3];
+        octets[..chunk.len()].copy_from_slice(chunk);
+        let [a, b, c] = octets;
+        let indices = [a >> 2, ((a & 0x03) << 4) | (b >> 4), ((b & 0x0F) << 2) | (c >> 6), c & 0x3F];
+        for (position, index) in indices.into_iter().enumerate() {
+            if position <= chunk.len() {
+                output.push(BASE64_ALPHABET[index as usize] as char);
+            } else {
+                output.push('=');
+            }
+        }
+    }
+    output
+}
+
+/// Incremental base64 decoder that accepts input split at arbitrary points.
+#[derive(Default)]
+struct Base64Decoder {
+    quad: [u8; 4],
+    filled: usize,
+    padding: usize,
+    finished: bool,
+    output: Vec<u8>,
+}
+
+impl Base64Decoder {
+    fn push_str(&mut self, input: &str) -> Result<(), ASN1Error> {
+        for c in input.bytes().filter(|c| !c.is_ascii_whitespace()) {
+            self.push(c)?;
+        }
+        Ok(())
+    }
+
+    fn push(&mut self, c: u8) -> Result<(), ASN1Error> {
+        if self.finished {
+            return Err(pem_error("Base64 data continues after padding".to_string(), line!()));
+        }
+        let value = match c {
+            b'A'..=b'Z' => c - b'A',
+            b'a'..=b'z' => c - b'a' + 26,
+            b'0'..=b'9' => c - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' if self.filled >= 2 => {
+                self.padding += 1;
+                0
+            }
+            _ => return Err(pem_error(format!("Invalid base64 character 0x{:02X}", c), line!())),
+        };
+        if self.padding > 0 && c != b'=' {
+            return Err(pem_error("Base64 data continues after padding".to_string(), line!()));
+        }
+        self.quad[self.filled] = value;
+        self.filled += 1;
+
+        if self.filled == 4 {
+            let [a, b, c, d] = self.quad;
+            let decoded = [(a << 2) | (b >> 4), (b << 4) | (c >> 2), (c << 6) | d];
+            self.output.extend_from_slice(&decoded[..3 - self.padding]);
+            self.finished = self.padding > 0;
+            self.filled = 0;
+        }
+        Ok(())
+    }
+
+    fn finish(self) -> Result<Vec<u8>, ASN1Error> {
+        if self.filled != 0 {
+            return Err(pem_error("Truncated base64 data".to_string(), line!()));
+        }
+        Ok(self.output)
+    }
+}
+
+/// How closely PEM input must follow RFC 7468.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub enum PEMMode {
+    /// The strict grammar of RFC 7468 section 3: base64 lines of exactly 64 characters
+    /// except the last, no headers or stray whitespace, and well-formed labels in their
+    /// canonical spelling (`CERTIFICATE`, not `X509 CERTIFICATE`).
+    Strict,
+    /// Accepts legacy output such as older OpenSSL's: any line width, whitespace, RFC 1421
+    /// headers (which are skipped) and any label.
+    #[default]
+    Lax,
+}
+
+/// Layout of the PEM text written by [`PEMDocument::to_pem_string_with_options`].
+///
+/// The defaults produce RFC 7468 output, which [`PEMMode::Strict`] accepts.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct PEMWriteOptions {
+    /// Base64 characters per line; `0` writes the whole body on one line.
+    pub line_width: usize,
+    /// RFC 1421 headers such as `("Proc-Type", "4,ENCRYPTED")` and `("DEK-Info", ...)`,
+    /// written in order after the BEGIN line and followed by a blank line.
+    pub headers: Vec<(String, String)>,
+    /// Whether the END line is followed by a newline.
+    pub trailing_newline: bool,
+}
+
+impl Default for PEMWriteOptions {
+    fn default() -> Self {
+        PEMWriteOptions { line_width: LINE_WIDTH, headers: Vec::new(), trailing_newline: true }
+    }
+}
+
+/// A single PEM block: its label and the DER bytes it carries.
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct PEMDocument {
+    /// The label of the BEGIN/END lines, e.g. `"CERTIFICATE"`.
+    pub discriminator: String,
+    pub der_bytes: Vec<u8>,
+}
+
+impl PEMDocument {
+    pub fn new(discriminator: impl Into<String>, der_bytes: Vec<u8>) -> Self {
+        PEMDocument { discriminator: discriminator.into(), der_bytes }
+    }
+
+    /// Parses a string holding exactly one PEM document in [`PEMMode::Lax`].
+    ///
+    /// Explanatory text around the block is ignored. The DER bytes are not validated.
+    pub fn parse(pem: &str) -> Result<Self, ASN1Error> {
+        Self::parse_with_mode(pem, PEMMode::Lax)
+    }
+
+    /// Parses a string holding exactly one PEM document under `mode`.
+    pub fn parse_with_mode(pem: &str, mode: PEMMode) -> Result<Self, ASN1Error> {
+        let mut decoder = PEMReader::with_mode(pem.as_bytes(), mode);
+        let (discriminator, der_bytes) = decoder
+            .next_document()?
+            .ok_or_else(|| pem_error("No PEM document found".to_string(), line!()))?;
+        if decoder.next_document()?.is_some() {
+            return Err(pem_error("Expected a single PEM document, found several".to_string(), line!()));
+        }
+        Ok(PEMDocument { discriminator, der_bytes })
+    }
+
+    /// Encodes the document as PEM with 64-character lines and a trailing newline.
+    pub fn to_pem_string(&self) -> String {
+        self.to_pem_string_with_options(&PEMWriteOptions::default())
+    }
+
+    /// Encodes the document as PEM laid out according to `options`.
+    pub fn to_pem_string_with_options(&self, options: &PEMWriteOptions) -> String {
+        let encoded = base64_encode(&self.der_bytes);
+        let mut pem = format!("{}{}{}\n", BEGIN_PREFIX, self.discriminator, BOUNDARY_SUFFIX);
+        if !options.headers.is_empty() {
+            for (name, value) in &options.headers {
+                pem.push_str(&format!("{}: {}\n", name, value));
+            }
+            pem.push('\n');
+        }
+        let line_width = if options.line_width == 0 { encoded.len().max(1) } else { options.line_width };
+        for line in encoded.as_bytes().chunks(line_width) {
+            // Base64 output is ASCII, so any chunk is valid UTF-8.
+            pem.push_str(std::str::from_utf8(line).expect("base64 output is ASCII"));
+            pem.push('\n');
+        }
+        pem.push_str(&format!("{}{}{}", END_PREFIX, self.discriminator, BOUNDARY_SUFFIX));
+        if options.trailing_newline {
+            pem.push('\n');
+        }
+        pem
+    }
+}
+
+/// A type with a fixed PEM label, such as a certificate (`"CERTIFICATE"`) or a PKCS #8 key
+/// (`"PRIVATE KEY"`).
+///
+/// Reading checks the label, so a document of another kind is rejected before its DER is
+/// decoded, and writing always uses the right one.
+pub trait PEMRepresentable: DERParseable + DERSerializable {
+    /// The label of the BEGIN/END lines.
+    const DISCRIMINATOR: &'static str;
+
+    /// Decodes the DER content of `document`, which must carry [`Self::DISCRIMINATOR`].
+    fn from_pem_document(document: &PEMDocument) -> Result<Self, ASN1Error> {
+        if document.discriminator != Self::DISCRIMINATOR {
+            return Err(pem_error(
+                format!("Expected PEM discriminator {}, got {}", Self::DISCRIMINATOR, document.discriminator),
+                line!(),
+            ));
+        }
+        Self::from_der_bytes(&document.der_bytes)
+    }
+
+    /// Parses a value from a string holding a single PEM document.
+    fn from_pem(pem: &str) -> Result<Self, ASN1Error> {
+        Self::from_pem_document(&PEMDocument::parse(pem)?)
+    }
+
+    fn to_pem_document(&self) -> Result<PEMDocument, ASN1Error> {
+        let mut serializer = Serializer::new();
+        self.serialize(&mut serializer)?;
+        Ok(PEMDocument::new(Self::DISCRIMINATOR, serializer.serialized_bytes().to_vec()))
+    }
+
+    /// Serializes the value as a PEM document labelled [`Self::DISCRIMINATOR`].
+    fn to_pem(&self) -> Result<String, ASN1Error> {
+        Ok(self.to_pem_document()?.to_pem_string())
+    }
+}
+
+/// Reads [`PEMDocument`]s lazily from any [`BufRead`], one document at a time.
+///
+/// Only the current line and the current document's DER bytes are held in memory, so
+/// bundles of any size, such as CA files with thousands of certificates, can be processed.
+/// Text outside BEGIN/END blocks is skipped and the DER bytes are not validated.
+/// Iteration stops after the first error.
+pub struct PEMReader<R: BufRead> {
+    reader: R,
+    line: String,
+    mode: PEMMode,
+    failed: bool,
+}
+
+impl<R: BufRead> PEMReader<R> {
+    /// Creates a reader that parses in [`PEMMode::Lax`].
+    pub fn new(reader: R) -> Self {
+        Self::with_mode(reader, PEMMode::Lax)
+    }
+
+    pub fn with_mode(reader: R, mode: PEMMode) -> Self {
+        PEMReader { reader, line: String::new(), mode, failed: false }
+    }
+
+    /// Returns the underlying reader.
+    pub fn into_inner(self) -> R {
+        self.reader
+    }
+
+    fn read_line(&mut self) -> Result<bool, ASN1Error> {
+        self.line.clear();
+        let read = (&mut self.reader)
+            .take(MAX_LINE_LENGTH + 1)
+            .read_line(&mut self.line)
+            .map_err(|e| pem_error(format!("Failed to read PEM input: {}", e), line!()))?;
+        if read as u64 > MAX_LINE_LENGTH {
+            return Err(pem_error("PEM line exceeds maximum length".to_string(), line!()));
+        }
+        Ok(read > 0)
+    }
+
+    fn next_document(&mut self) -> Result<Option<(String, Vec<u8>)>, ASN1Error> {
+        let label = loop {
+            if !self.read_line()? {
+                return Ok(None);
+            }
+            if let Some(label) = boundary_label(self.line.trim(), BEGIN_PREFIX) {
+                break label.to_string();
+            }
+        };
+        if self.mode == PEMMode::Strict {
+            check_strict_boundary(&self.line)?;
+            check_strict_label(&label)?;
+        }
+
+        let mut decoder = Base64Decoder::default();
+        let mut first_line = true;
+        let mut in_headers = false;
+        let mut short_line_seen = false;
+        loop {
+            if !self.read_line()? {
+                return Err(pem_error(format!("Missing END line for {}", label), line!()));
+            }
+            let line = self.line.trim();
+            if let Some(end_label) = boundary_label(line, END_PREFIX) {
+                if end_label != label {
+                    return Err(pem_error(
+                        format!("END label {} does not match BEGIN label {}", end_label, label),
+                        line!(),
+                    ));
+                }
+                if self.mode == PEMMode::Strict {
+                    check_strict_boundary(&self.line)?;
+                }
+                return Ok(Some((label, decoder.finish()?)));
+            }
+
+            match self.mode {
+                PEMMode::Lax => {
+                    // RFC 1421 headers run up to the first blank line and are skipped.
+                    in_headers = if first_line { line.contains(':') } else { in_headers && !line.is_empty() };
+                    if !in_headers {
+                        decoder.push_str(line)?;
+                    }
+                }
+                PEMMode::Strict => {
+                    let line = strip_line_ending(&self.line);
+                    if line.contains(':') {
+                        return Err(pem_error("PEM headers are not allowed in strict mode".to_string(), line!()));
+                    }
+                    if short_line_seen || line.is_empty() || line.len() > LINE_WIDTH || !line.len().is_multiple_of(4) {
+                        return Err(pem_error(
+                            format!("Base64 lines must be {} characters except the last", LINE_WIDTH),
+                            line!(),
+                        ));
+                    }
+                    if line.bytes().any(|c| c.is_ascii_whitespace()) {
+                        return Err(pem_error("Whitespace inside base64 line".to_string(), line!()));
+                    }
+                    short_line_seen = line.len() < LINE_WIDTH;
+                    decoder.push_str(line)?;
+                }
+            }
+            first_line = false;
+        }
+    }
+}
+
+fn strip_line_ending(line: &str) -> &str {
+    let line = line.strip_suffix('\n').unwrap_or(line);
+    line.strip_suffix('\r').unwrap_or(line)
+}
+
+/// Strict boundaries are exactly `-----BEGIN label-----` or `-----END label-----`.
+fn check_strict_boundary(line: &str) -> Result<(), ASN1Error> {
+    let line = strip_line_ending(line);
+    if line != line.trim() {
+        return Err(pem_error("Whitespace around PEM boundary line".to_string(), line!()));
+    }
+    Ok(())
+}
+
+/// Labels spelled differently from their RFC 7468 form by older tools.
+const LEGACY_LABELS: [(&str, &str); 4] = [
+    ("X509 CERTIFICATE", "CERTIFICATE"),
+    ("X.509 CERTIFICATE", "CERTIFICATE"),
+    ("NEW CERTIFICATE REQUEST", "CERTIFICATE REQUEST"),
+    ("X509 CRL", "CRL"),
+];
+
+/// Checks a label against the RFC 7468 grammar: printable characters other than `-`,
+/// separated by single hyphens or spaces, in their canonical spelling.
+fn check_strict_label(label: &str) -> Result<(), ASN1Error> {
+    let is_label_char = |c: u8| c.is_ascii_graphic() && c != b'-';
+    let bytes = label.as_bytes();
+    let well_formed = bytes.iter().all(|&c| is_label_char(c) || c == b'-' || c == b' ')
+        && bytes.first().is_none_or(|&c| is_label_char(c))
+        && bytes.last().is_none_or(|&c| is_label_char(c))
+        && bytes.windows(2).all(|pair| is_label_char(pair[0]) || is_label_char(pair[1]));
+    if !well_formed {
+        return Err(pem_error(format!("Invalid PEM label {:?}", label), line!()));
+    }
+    if let Some((_, canonical)) = LEGACY_LABELS.iter().find(|(legacy, _)| *legacy == label) {
+        return Err(pem_error(format!("Non-canonical PEM label {}, expected {}", label, canonical), line!()));
+    }
+    Ok(())
+}
+
+impl<R: BufRead> Iterator for PEMReader<R> {
+    type Item = Result<PEMDocument, ASN1Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self
+            .next_document()
+            .transpose()?
+            .map(|(discriminator, der_bytes)| PEMDocument { discriminator, der_bytes });
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+/// Decodes a stream of PEM documents from any [`Read`], one document at a time.
+///
+/// This is a [`PEMReader`] that also parses each document's DER bytes. Each item is the
+/// block's label (e.g. `"CERTIFICATE"`) and its parsed DER node. Iteration stops after the
+/// first error.
+pub struct PEMStreamDecoder<R: Read> {
+    documents: PEMReader<BufReader<R>>,
+    failed: bool,
+}
+
+impl<R: Read> PEMStreamDecoder<R> {
+    /// Creates a decoder that parses in [`PEMMode::Lax`].
+    pub fn new(reader: R) -> Self {
+        Self::with_mode(reader, PEMMode::Lax)
+    }
+
+    pub fn with_mode(reader: R, mode: PEMMode) -> Self {
+        PEMStreamDecoder { documents: PEMReader::with_mode(BufReader::new(reader), mode), failed: false }
+    }
+}
+
+impl<R: Read> Iterator for PEMStreamDecoder<R> {
+    type Item = Result<(String, ASN1Node), ASN1Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.failed {
+            return None;
+        }
+        let result = self
+            .documents
+            .next()?
+            .and_then(|document| Ok((document.discriminator, der::parse(&document.der_bytes)?)));
+        self.failed = result.is_err();
+        Some(result)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::der::DERParseable;
+
+    fn decode(input: &str) -> Result<Vec<u8>, ASN1Error> {
+        let mut decoder = Base64Decoder::default();
+        decoder.push_str(input)?;
+        decoder.finish()
+    }
+
+    #[test]
+    fn test_base64_decoding() {
+        assert_eq!(decode("").unwrap(), b"");
+        assert_eq!(decode("Zg==").unwrap(), b"f");
+        assert_eq!(decode("Zm8=").unwrap(), b"fo");
+        assert_eq!(decode("Zm9v").unwrap(), b"foo");
+        assert_eq!(decode("Zm9v\nYmFy").unwrap(), b"foobar");
+        assert_eq!(decode("+/8=").unwrap(), vec![0xFB, 0xFF]);
+
+        assert!(decode("Zm9").is_err());
+        assert!(decode("Zm9*").is_err());
+        assert!(decode("Z===").is_err());
+        assert!(decode("Zg==Zg==").is_err());
+        assert!(decode("Zg=a").is_err());
+    }
+
+    #[test]
+    fn test_base64_encoding_roundtrip() {
+        for (data, encoded) in [(&b""[..], ""), (b"f", "Zg=="), (b"fo", "Zm8="), (b"foo", "Zm9v"), (&[0xFB, 0xFF], "+/8=")] {
+            assert_eq!(base64_encode(data), encoded);
+            assert_eq!(decode(encoded).unwrap(), data);
+        }
+    }
+
+    #[test]
+    fn test_pem_document_roundtrip() {
+        let document = PEMDocument::new("DATA", (0..=255).collect());
+        let pem = document.to_pem_string();
+        let lines: Vec<_> = pem.lines().collect();
+        assert_eq!(lines.first(), Some(&"-----BEGIN DATA-----"));
+        assert_eq!(lines.last(), Some(&"-----END DATA-----"));
+        assert!(lines[1..lines.len() - 1].iter().all(|line| line.len() <= LINE_WIDTH));
+        assert_eq!(lines[1].len(), LINE_WIDTH);
+        assert!(pem.ends_with("-----\n"));
+        assert_eq!(PEMDocument::parse(&pem).unwrap(), document);
+
+        let empty = PEMDocument::new("EMPTY", vec![]);
+        assert_eq!(empty.to_pem_string(), "-----BEGIN EMPTY-----\n-----END EMPTY-----\n");
+        assert_eq!(PEMDocument::parse(&empty.to_pem_string()).unwrap(), empty);
+    }
+
+    #[test]
+    fn test_pem_document_parse_errors() {
+        for input in ["", "no pem here", "-----BEGIN A-----\nAgEq\n", "-----BEGIN A-----\nAgEq\n-----END B-----\n"] {
+            assert_eq!(PEMDocument::parse(input).unwrap_err().code(), ErrorCode::InvalidPEMDocument, "{:?}", input);
+        }
+        let two = "-----BEGIN A-----\nAgEq\n-----END A-----\n-----BEGIN A-----\nAgEq\n-----END A-----\n";
+        assert_eq!(PEMDocument::parse(two).unwrap_err().code(), ErrorCode::InvalidPEMDocument);
+    }
+
+    #[test]
+    fn test_write_options() {
+        let document = PEMDocument::new("A", vec![0x02, 0x01, 0x2A, 0x02, 0x01, 0x2B]);
+        let options = PEMWriteOptions { line_width: 4, ..Default::default() };
+        assert_eq!(document.to_pem_string_with_options(&options), "-----BEGIN A-----\nAgEq\nAgEr\n-----END A-----\n");
+
+        let options = PEMWriteOptions { line_width: 0, trailing_newline: false, ..Default::default() };
+        let pem = document.to_pem_string_with_options(&options);
+        assert_eq!(pem, "-----BEGIN A-----\nAgEqAgEr\n-----END A-----");
+        assert_eq!(PEMDocument::parse(&pem).unwrap(), document);
+
+        let options = PEMWriteOptions {
+            headers: vec![
+                ("Proc-Type".to_string(), "4,ENCRYPTED".to_string()),
+                ("DEK-Info".to_string(), "AES-128-CBC,00FF".to_string()),
+            ],
+            ..Default::default()
+        };
+        let pem = document.to_pem_string_with_options(&options);
+        assert_eq!(
+            pem,
+            "-----BEGIN A-----\nProc-Type: 4,ENCRYPTED\nDEK-Info: AES-128-CBC,00FF\n\nAgEqAgEr\n-----END A-----\n"
+        );
+        assert_eq!(PEMDocument::parse(&pem).unwrap(), document);
+        assert!(PEMDocument::parse_with_mode(&pem, PEMMode::Strict).is_err());
+        assert_eq!(document.to_pem_string_with_options(&PEMWriteOptions::default()), document.to_pem_string());
+    }
+
+    #[test]
+    fn test_strict_mode() {
+        let document = PEMDocument::new("CERTIFICATE", (0..100).collect());
+        let pem = document.to_pem_string();
+        assert_eq!(PEMDocument::parse_with_mode(&pem, PEMMode::Strict).unwrap(), document);
+        assert_eq!(PEMDocument::parse_with_mode(&pem.replace('\n', "\r\n"), PEMMode::Strict).unwrap(), document);
+        let with_text = format!("Subject: example\n{}trailing\n", pem);
+        assert_eq!(PEMDocument::parse_with_mode(&with_text, PEMMode::Strict).unwrap(), document);
+
+        let lines: Vec<&str> = pem.lines().collect();
+        let base64 = lines[1..lines.len() - 1].concat();
+        let rewrapped = format!("{}\n{}\n{}\n{}\n", lines[0], &base64[..76], &base64[76..], lines[lines.len() - 1]);
+        let legacy = [
+            // Lines not 64 characters wide.
+            rewrapped,
+            // RFC 1421 headers.
+            pem.replacen("-----\n", "-----\nProc-Type: 4,ENCRYPTED\nDEK-Info: AES-128-CBC,00\n\n", 1),
+            // Whitespace inside the body and around the boundaries.
+            pem.replacen("-----\n", "-----\n\n", 1),
+            pem.replacen("\n", " \n", 1),
+            // Non-canonical labels.
+            pem.replace("CERTIFICATE", "X509 CERTIFICATE"),
+            pem.replace("CERTIFICATE", "CERTIFICATE-"),
+            pem.replace("CERTIFICATE", "CERT  IFICATE"),
+        ];
+        for input in &legacy {
+            let err = PEMDocument::parse_with_mode(input, PEMMode::Strict).unwrap_err();
+            assert_eq!(err.code(), ErrorCode::InvalidPEMDocument, "{}", input);
+            assert_eq!(PEMDocument::parse(input).unwrap().der_bytes, document.der_bytes, "{}", input);
+        }
+        assert!(check_strict_label("").is_ok());
+        assert!(check_strict_label("RSA PRIVATE KEY").is_ok());
+        assert!(check_strict_label("-KEY").is_err());
+    }
+
+    #[derive(Debug, PartialEq)]
+    struct Number(i64);
+
+    impl DERParseable for Number {
+        fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+            i64::from_der_node(node).map(Number)
+        }
+    }
+
+    impl DERSerializable for Number {
+        fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+            self.0.serialize(serializer)
+        }
+    }
+
+    impl PEMRepresentable for Number {
+        const DISCRIMINATOR: &'static str = "NUMBER";
+    }
+
+    #[test]
+    fn test_pem_representable() {
+        let pem = Number(42).to_pem().unwrap();
+        assert_eq!(pem, "-----BEGIN NUMBER-----\nAgEq\n-----END NUMBER-----\n");
+        assert_eq!(Number::from_pem(&pem).unwrap(), Number(42));
+        assert_eq!(Number(42).to_pem_document().unwrap(), PEMDocument::new("NUMBER", vec![0x02, 0x01, 0x2A]));
+
+        let err = Number::from_pem(&pem.replace("NUMBER", "CERTIFICATE")).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidPEMDocument);
+        let err = Number::from_pem_document(&PEMDocument::new("NUMBER", vec![0x01, 0x01, 0xFF])).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+    }
+
+    #[test]
+    fn test_reader_yields_documents_lazily() {
+        /// Fails the test if the reader pulls input past the first document.
+        struct Guarded<'a>(&'a [u8], usize);
+        impl Read for Guarded<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                assert!(self.0.len() > self.1, "read past the first document");
+                self.0.read(buf)
+            }
+        }
+
+        let first = PEMDocument::new("CERTIFICATE", vec![0x02, 0x01, 0x2A]);
+        let input = format!("{}{}", first.to_pem_string(), "x".repeat(1000));
+        let guarded = Guarded(input.as_bytes(), 1000);
+        let mut reader = PEMReader::new(BufReader::with_capacity(8, guarded));
+        assert_eq!(reader.next().unwrap().unwrap(), first);
+
+        let bundle: String = (0..1000u32)
+            .map(|n| PEMDocument::new("CERTIFICATE", n.to_be_bytes().to_vec()).to_pem_string())
+            .collect();
+        let documents = PEMReader::new(bundle.as_bytes()).collect::<Result<Vec<_>, _>>().unwrap();
+        assert_eq!(documents.len(), 1000);
+        assert_eq!(documents[999].der_bytes, 999u32.to_be_bytes());
+
+        // Undecodable DER is not the reader's concern; broken PEM stops iteration.
+        let input = "-----BEGIN A-----\nAgE=\n-----END A-----\n-----BEGIN B-----\n!\n-----END B-----\n-----BEGIN C-----\n-----END C-----\n";
+        let mut reader = PEMReader::new(input.as_bytes());
+        assert_eq!(reader.next().unwrap().unwrap().der_bytes, vec![0x02, 0x01]);
+        assert_eq!(reader.next().unwrap().unwrap_err().code(), ErrorCode::InvalidPEMDocument);
+        assert!(reader.next().is_none());
+    }
+
+    #[test]
+    fn test_stream_decoder_yields_each_document() {
+        let input = "\
+Some explanatory text
+-----BEGIN NUMBER-----
+AgEq
+-----END NUMBER-----
+-----BEGIN LIST-----
+MAYCAQEC
+AQI=
+-----END LIST-----
+trailing text
+";
+        let docs: Vec<_> = PEMStreamDecoder::new(input.as_bytes())
+            .collect::<Result<_, _>>()
+            .unwrap();
+        assert_eq!(docs.len(), 2);
+        assert_eq!(docs[0].0, "NUMBER");
+        assert_eq!(i64::from_der_node(docs[0].1.clone()).unwrap(), 42);
+        assert_eq!(docs[1].0, "LIST");
+        assert_eq!(Vec::<i64>::from_der_node(docs[1].1.clone()).unwrap(), vec![1, 2]);
+    }
+
+    #[test]
+    fn test_stream_decoder_errors_stop_iteration() {
+        let mismatched = "-----BEGIN A-----\nAgEq\n-----END B-----\n-----BEGIN A-----\nAgEq\n-----END A-----\n";
+        let mut decoder = PEMStreamDecoder::new(mismatched.as_bytes());
+        let err = decoder.next().unwrap().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidPEMDocument);
+        assert!(decoder.next().is_none());
+
+        let unterminated = "-----BEGIN A-----\nAgEq\n";
+        let err = PEMStreamDecoder::new(unterminated.as_bytes()).next().unwrap().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidPEMDocument);
+
+        let not_der = "-----BEGIN A-----\nAgE=\n-----END A-----\n";
+        let err = PEMStreamDecoder::new(not_der.as_bytes()).next().unwrap().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::TruncatedASN1Field);
+    }
+
+    #[test]
+    fn test_stream_decoder_rejects_overlong_lines() {
+        let input = "x".repeat(MAX_LINE_LENGTH as usize + 10);
+        let err = PEMStreamDecoder::new(input.as_bytes()).next().unwrap().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidPEMDocument);
+        assert!(PEMStreamDecoder::new(&b""[..]).next().is_none());
+    }
+}
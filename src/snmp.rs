@@ -0,0 +1,101 @@
+//! SNMP SMI (RFC 2578) application-wide types.
+
+use crate::asn1::ASN1Node;
+use crate::asn1_types::{ASN1Identifier, ASN1OctetString, TagClass};
+use crate::ber::{self, BERImplicitlyTaggable, BERParseable, BERSerializable};
+use crate::der::{DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
+use crate::errors::ASN1Error;
+use crate::buffer::Bytes;
+
+/// `Opaque ::= [APPLICATION 4] IMPLICIT OCTET STRING`
+///
+/// The content is itself a BER encoding of an arbitrary ASN.1 value, conventionally one of
+/// the net-snmp Opaque extensions (floats, 64-bit counters).
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+pub struct Opaque(pub Bytes);
+
+impl Opaque {
+    pub const IDENTIFIER: ASN1Identifier = ASN1Identifier::new(4, TagClass::Application);
+
+    /// Wraps the encoding of `value`.
+    pub fn wrapping<T: DERSerializable + ?Sized>(value: &T) -> Result<Self, ASN1Error> {
+        let mut serializer = Serializer::new();
+        serializer.serialize(value)?;
+        Ok(Opaque(serializer.serialized_bytes()))
+    }
+
+    /// Decodes the wrapped value with the BER parser.
+    pub fn decode_inner<T: BERParseable>(&self) -> Result<T, ASN1Error> {
+        T::from_ber_node(ber::parse(&self.0)?)
+    }
+}
+
+impl DERParseable for Opaque {
+    fn from_der_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_der_node_with_identifier(node, Opaque::default_identifier())
+    }
+}
+
+impl DERSerializable for Opaque {
+    fn serialize(&self, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+        serializer.append_primitive_node(Self::default_identifier(), |buf| {
+            buf.extend_from_slice(&self.0);
+            Ok(())
+        })
+    }
+}
+
+impl DERImplicitlyTaggable for Opaque {
+    fn default_identifier() -> ASN1Identifier {
+        Self::IDENTIFIER
+    }
+
+    fn from_der_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_der_node_with_identifier(node, identifier).map(|s| Opaque(s.0))
+    }
+}
+
+impl BERParseable for Opaque {
+    fn from_ber_node(node: ASN1Node) -> Result<Self, ASN1Error> {
+        Self::from_ber_node_with_identifier(node, Opaque::default_identifier())
+    }
+}
+impl BERSerializable for Opaque {}
+impl BERImplicitlyTaggable for Opaque {
+    fn from_ber_node_with_identifier(node: ASN1Node, identifier: ASN1Identifier) -> Result<Self, ASN1Error> {
+        ASN1OctetString::from_ber_node_with_identifier(node, identifier).map(|s| Opaque(s.0))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_types::ASN1Integer;
+    use crate::errors::ErrorCode;
+    use crate::testing::assert_der_roundtrip;
+
+    #[test]
+    fn test_opaque_roundtrip_and_decode_inner() {
+        let opaque = Opaque::wrapping(&1234i64).unwrap();
+        assert_eq!(opaque.0.as_ref(), &[0x02, 0x02, 0x04, 0xD2]);
+
+        let encoded = assert_der_roundtrip(&opaque);
+        assert_eq!(encoded, vec![0x44, 0x04, 0x02, 0x02, 0x04, 0xD2]);
+        assert_eq!(opaque.decode_inner::<ASN1Integer>().unwrap(), ASN1Integer::from(1234));
+    }
+
+    #[test]
+    fn test_opaque_inner_uses_ber() {
+        // Indefinite-length constructed OCTET STRING is only valid BER.
+        let opaque = Opaque(Bytes::from_static(&[0x24, 0x80, 0x04, 0x01, 0xAA, 0x04, 0x01, 0xBB, 0x00, 0x00]));
+        let inner = opaque.decode_inner::<ASN1OctetString>().unwrap();
+        assert_eq!(inner.0.as_ref(), &[0xAA, 0xBB]);
+
+        // Constructed outer encoding is accepted in BER.
+        let node = ber::parse(&[0x64, 0x80, 0x04, 0x01, 0x05, 0x04, 0x01, 0x00, 0x00, 0x00]).unwrap();
+        assert_eq!(Opaque::from_ber_node(node).unwrap().0.as_ref(), &[0x05, 0x00]);
+
+        let err = Opaque::from_der_bytes(&[0x04, 0x00]).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+    }
+}
@@ -0,0 +1,337 @@
+//! Assertion helpers for conformance tests of types built on this crate.
+//!
+//! These panic with a hex dump of the encodings involved, so they are meant to be
+//! called from `#[test]` functions.
+
+use crate::asn1::{ASN1Node, Content};
+use crate::asn1_types::TagClass;
+use crate::ber::{self, BERParseable};
+use crate::der::{self, DERParseable, DERSerializable, IdentfierWriter, Serializer};
+use crate::errors::ASN1Error;
+use std::fmt::Debug;
+
+fn hex(bytes: &[u8]) -> String {
+    bytes.iter().map(|b| format!("{:02X}", b)).collect::<Vec<_>>().join(" ")
+}
+
+/// Serializes `value` to DER, parses it back and checks that the decoded value
+/// equals `value` and that re-serializing it reproduces the same bytes.
+///
+/// Returns the DER encoding so callers can make further assertions on it.
+#[track_caller]
+pub fn assert_der_roundtrip<T>(value: &T) -> Vec<u8>
+where
+    T: DERParseable + DERSerializable + PartialEq + Debug,
+{
+    let mut serializer = Serializer::new();
+    if let Err(err) = serializer.serialize(value) {
+        panic!("failed to serialize {:?}: {}", value, err);
+    }
+    let encoded = serializer.serialized_bytes().to_vec();
+
+    let decoded = match T::from_der_bytes(&encoded) {
+        Ok(decoded) => decoded,
+        Err(err) => panic!(
+            "failed to parse serialized {:?}\n  encoding: {}\n  error: {}",
+            value,
+            hex(&encoded),
+            err
+        ),
+    };
+    if &decoded != value {
+        panic!(
+            "DER round-trip changed the value\n  original: {:?}\n  decoded:  {:?}\n  encoding: {}",
+            value,
+            decoded,
+            hex(&encoded)
+        );
+    }
+
+    let mut reserializer = Serializer::new();
+    if let Err(err) = reserializer.serialize(&decoded) {
+        panic!("failed to re-serialize {:?}: {}", decoded, err);
+    }
+    let reencoded = reserializer.serialized_bytes();
+    if reencoded.as_ref() != encoded.as_slice() {
+        panic!(
+            "DER re-serialization is not byte-exact for {:?}\n  first:  {}\n  second: {}",
+            value,
+            hex(&encoded),
+            hex(&reencoded)
+        );
+    }
+    encoded
+}
+
+/// Parses `bytes` as DER and checks that the result equals `expected`.
+#[track_caller]
+pub fn assert_parses_to<T>(bytes: &[u8], expected: &T)
+where
+    T: DERParseable + PartialEq + Debug,
+{
+    match T::from_der_bytes(bytes) {
+        Ok(decoded) if &decoded == expected => {}
+        Ok(decoded) => panic!(
+            "decoded value does not match\n  expected: {:?}\n  decoded:  {:?}\n  input:    {}",
+            expected,
+            decoded,
+            hex(bytes)
+        ),
+        Err(err) => panic!(
+            "failed to parse {}\n  expected: {:?}\n  input:    {}\n  error:    {}",
+            std::any::type_name::<T>(),
+            expected,
+            hex(bytes),
+            err
+        ),
+    }
+}
+
+/// A BER-only way of re-encoding a single element of a DER document.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum BERVariantKind {
+    /// The length uses one more octet than the minimal encoding.
+    NonMinimalLength,
+    /// A constructed element uses the indefinite length form.
+    IndefiniteLength,
+    /// A primitive string is re-encoded as a constructed string of primitive segments.
+    ConstructedString,
+    /// A TRUE BOOLEAN is encoded as 0x01 rather than 0xFF.
+    NonCanonicalBoolean,
+}
+
+/// A BER encoding that is semantically equivalent to some DER input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BERVariant {
+    /// Pre-order index of the re-encoded element; the root is 0.
+    pub element: usize,
+    pub kind: BERVariantKind,
+    pub encoding: Vec<u8>,
+}
+
+/// Generates BER encodings equivalent to the DER document `der`.
+///
+/// Each variant applies exactly one [`BERVariantKind`] to one element, and every applicable
+/// combination of element and kind is produced, so a parser that accepts BER should decode
+/// all of them to the same value as `der` itself.
+pub fn ber_variants(der: &[u8]) -> Result<Vec<BERVariant>, ASN1Error> {
+    let root = der::parse(der)?;
+    let mut applicable = Vec::new();
+    collect_variant_kinds(&root, &mut applicable);
+
+    let mut variants = Vec::new();
+    for (element, kinds) in applicable.into_iter().enumerate() {
+        for kind in kinds {
+            let mut encoding = Vec::new();
+            encode_variant(&root, (element, kind), &mut 0, &mut encoding);
+            variants.push(BERVariant { element, kind, encoding });
+        }
+    }
+    Ok(variants)
+}
+
+/// Checks that every [`ber_variants`] encoding of `value`'s DER form parses, as BER,
+/// back to `value`.
+#[track_caller]
+pub fn assert_accepts_ber_variants<T>(value: &T)
+where
+    T: BERParseable + DERSerializable + PartialEq + Debug,
+{
+    let mut serializer = Serializer::new();
+    if let Err(err) = serializer.serialize(value) {
+        panic!("failed to serialize {:?}: {}", value, err);
+    }
+    let encoded = serializer.serialized_bytes();
+    let variants = ber_variants(&encoded).expect("serializer produced invalid DER");
+    for variant in variants {
+        match ber::parse(&variant.encoding).and_then(T::from_ber_node) {
+            Ok(decoded) if &decoded == value => {}
+            Ok(decoded) => panic!(
+                "BER variant {:?} of element {} decoded differently\n  original: {:?}\n  decoded:  {:?}\n  input:    {}",
+                variant.kind,
+                variant.element,
+                value,
+                decoded,
+                hex(&variant.encoding)
+            ),
+            Err(err) => panic!(
+                "failed to parse BER variant {:?} of element {}\n  value: {:?}\n  input: {}\n  error: {}",
+                variant.kind,
+                variant.element,
+                value,
+                hex(&variant.encoding),
+                err
+            ),
+        }
+    }
+}
+
+fn collect_variant_kinds(node: &ASN1Node, out: &mut Vec<Vec<BERVariantKind>>) {
+    let mut kinds = vec![BERVariantKind::NonMinimalLength];
+    let universal = node.identifier.tag_class == TagClass::Universal;
+    match &node.content {
+        Content::Constructed(children) => {
+            kinds.push(BERVariantKind::IndefiniteLength);
+            out.push(kinds);
+            for child in children {
+                collect_variant_kinds(&child, out);
+            }
+        }
+        Content::Primitive(content) => {
+            match node.identifier.tag_number {
+                // BIT STRING, OCTET STRING, ObjectDescriptor, the character string types and the time types
+                3 | 4 | 7 | 12 | 18..=30 if universal => kinds.push(BERVariantKind::ConstructedString),
+                1 if universal && content.as_ref() == [0xFF] => kinds.push(BERVariantKind::NonCanonicalBoolean),
+                _ => {}
+            }
+            out.push(kinds);
+        }
+    }
+}
+
+fn encode_variant(node: &ASN1Node, target: (usize, BERVariantKind), index: &mut usize, out: &mut Vec<u8>) {
+    let kind = (target.0 == *index).then_some(target.1);
+    *index += 1;
+
+    let mut content = Vec::new();
+    let constructed = match &node.content {
+        Content::Constructed(children) => {
+            for child in children {
+                encode_variant(&child, target, index, &mut content);
+            }
+            true
+        }
+        Content::Primitive(bytes) if kind == Some(BERVariantKind::ConstructedString) => {
+            content = constructed_string_segments(node, bytes);
+            true
+        }
+        Content::Primitive(_) if kind == Some(BERVariantKind::NonCanonicalBoolean) => {
+            content.push(0x01);
+            false
+        }
+        Content::Primitive(bytes) => {
+            content.extend_from_slice(bytes);
+            false
+        }
+    };
+
+    out.write_identifier(node.identifier, constructed);
+    match kind {
+        Some(BERVariantKind::IndefiniteLength) => {
+            out.push(0x80);
+            out.extend_from_slice(&content);
+            out.extend_from_slice(&[0x00, 0x00]);
+            return;
+        }
+        Some(BERVariantKind::NonMinimalLength) => {
+            // The long form where the short form would do, or a leading zero octet.
+            let length = der::encode_length(content.len());
+            match length.split_first() {
+                Some((&short, [])) => out.extend_from_slice(&[0x81, short]),
+                Some((&indicator, octets)) => {
+                    out.extend_from_slice(&[indicator + 1, 0x00]);
+                    out.extend_from_slice(octets);
+                }
+                None => unreachable!("encoded lengths are never empty"),
+            }
+        }
+        _ => out.extend_from_slice(&der::encode_length(content.len())),
+    }
+    out.extend_from_slice(&content);
+}
+
+/// Splits a primitive string's content into primitive segments of the same universal type.
+fn constructed_string_segments(node: &ASN1Node, content: &[u8]) -> Vec<u8> {
+    let segments = if node.identifier.tag_number == 3 {
+        // Only the final BIT STRING segment may have unused bits, so keep the content whole.
+        vec![content]
+    } else {
+        let (head, tail) = content.split_at(content.len() / 2);
+        vec![head, tail]
+    };
+    let mut out = Vec::new();
+    for segment in segments {
+        out.write_identifier(node.identifier, false);
+        out.extend_from_slice(&der::encode_length(segment.len()));
+        out.extend_from_slice(segment);
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::asn1_types::{ASN1Integer, ASN1OctetString};
+
+    #[test]
+    fn test_assert_der_roundtrip_returns_encoding() {
+        let encoded = assert_der_roundtrip(&ASN1Integer::from(300));
+        assert_eq!(encoded, vec![0x02, 0x02, 0x01, 0x2C]);
+        assert_der_roundtrip(&vec![true, false]);
+    }
+
+    #[test]
+    fn test_assert_parses_to() {
+        assert_parses_to(&[0x04, 0x02, 0xAB, 0xCD], &ASN1OctetString::from(vec![0xAB, 0xCD]));
+    }
+
+    #[test]
+    #[should_panic(expected = "decoded value does not match")]
+    fn test_assert_parses_to_mismatch_panics() {
+        assert_parses_to(&[0x02, 0x01, 0x01], &2i64);
+    }
+
+    #[test]
+    #[should_panic(expected = "input:    02 01")]
+    fn test_assert_parses_to_reports_hex_input() {
+        assert_parses_to(&[0x02, 0x01], &2i64);
+    }
+
+    #[test]
+    fn test_ber_variants_of_sequence() {
+        // SEQUENCE { BOOLEAN TRUE, OCTET STRING 'ABCD'H }
+        let der = [0x30, 0x07, 0x01, 0x01, 0xFF, 0x04, 0x02, 0xAB, 0xCD];
+        let variants = ber_variants(&der).unwrap();
+        let summary: Vec<_> = variants.iter().map(|v| (v.element, v.kind)).collect();
+        assert_eq!(
+            summary,
+            vec![
+                (0, BERVariantKind::NonMinimalLength),
+                (0, BERVariantKind::IndefiniteLength),
+                (1, BERVariantKind::NonMinimalLength),
+                (1, BERVariantKind::NonCanonicalBoolean),
+                (2, BERVariantKind::NonMinimalLength),
+                (2, BERVariantKind::ConstructedString),
+            ]
+        );
+        assert_eq!(variants[0].encoding, vec![0x30, 0x81, 0x07, 0x01, 0x01, 0xFF, 0x04, 0x02, 0xAB, 0xCD]);
+        assert_eq!(variants[1].encoding, vec![0x30, 0x80, 0x01, 0x01, 0xFF, 0x04, 0x02, 0xAB, 0xCD, 0x00, 0x00]);
+        assert_eq!(variants[3].encoding, vec![0x30, 0x07, 0x01, 0x01, 0x01, 0x04, 0x02, 0xAB, 0xCD]);
+        assert_eq!(
+            variants[5].encoding,
+            vec![0x30, 0x0B, 0x01, 0x01, 0xFF, 0x24, 0x06, 0x04, 0x01, 0xAB, 0x04, 0x01, 0xCD]
+        );
+        for variant in &variants {
+            assert!(!der::lint(&variant.encoding).is_empty(), "{:?} is valid DER", variant.kind);
+        }
+    }
+
+    #[test]
+    fn test_ber_variants_long_lengths_and_bit_strings() {
+        let mut der = vec![0x03, 0x81, 0x81, 0x04];
+        der.extend_from_slice(&[0xF0; 0x80]);
+        let variants = ber_variants(&der).unwrap();
+        assert_eq!(&variants[0].encoding[..5], &[0x03, 0x82, 0x00, 0x81, 0x04]);
+        assert_eq!(&variants[1].encoding[..7], &[0x23, 0x81, 0x84, 0x03, 0x81, 0x81, 0x04]);
+
+        assert_accepts_ber_variants(&crate::asn1_types::ASN1BitString::from_der_bytes(&der).unwrap());
+        assert_accepts_ber_variants(&ASN1OctetString::from(vec![1, 2, 3]));
+        assert!(ber_variants(&[0x30, 0x80, 0x00, 0x00]).is_err());
+    }
+
+    #[test]
+    fn test_hex_formatting() {
+        assert_eq!(hex(&[0x00, 0x7F, 0xFF]), "00 7F FF");
+        assert_eq!(hex(&[]), "");
+    }
+}
@@ -1,5 +1,5 @@
 use rust_asn1::asn1_types::ASN1Boolean;
-use bytes::Bytes;
+use rust_asn1::buffer::Bytes;
 use rust_asn1::ber::{self, BERImplicitlyTaggable, BERParseable};
 use rust_asn1::asn1_types::ASN1Identifier;
 use rust_asn1::der::{DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
@@ -20,7 +20,7 @@ fn test_ber_boolean_lax() {
     
     let node = rust_asn1::ber::parse(&data).unwrap();
     let b = ASN1Boolean::from_ber_node(node).unwrap();
-    assert_eq!(b.0, true);
+    assert!(b.0);
     
     // 0x00 is false
     let data2 = Bytes::from(vec![
@@ -28,7 +28,7 @@ fn test_ber_boolean_lax() {
     ]);
     let node2 = rust_asn1::ber::parse(&data2).unwrap();
     let b2 = ASN1Boolean::from_ber_node(node2).unwrap();
-    assert_eq!(b2.0, false);
+    assert!(!b2.0);
     
     // 0xFF is true
     let data3 = Bytes::from(vec![
@@ -36,7 +36,7 @@ fn test_ber_boolean_lax() {
     ]);
     let node3 = rust_asn1::ber::parse(&data3).unwrap();
     let b3 = ASN1Boolean::from_ber_node(node3).unwrap();
-    assert_eq!(b3.0, true);
+    assert!(b3.0);
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
@@ -112,6 +112,6 @@ fn test_ber_default_from_ber_node_and_with_identifier() {
 fn test_ber_default_from_ber_iterator() {
     let data = [0x30, 0x03, 0x02, 0x01, 0x2A];
     let node = ber::parse(&data).unwrap();
-    let v: Dummy = ber::sequence(node, ASN1Identifier::SEQUENCE, |iter| Dummy::from_ber_iterator(iter)).unwrap();
+    let v: Dummy = ber::sequence(node, ASN1Identifier::SEQUENCE, Dummy::from_ber_iterator).unwrap();
     assert_eq!(v, Dummy(0x2A));
 }
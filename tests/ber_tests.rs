@@ -55,7 +55,7 @@ fn test_ber_parse_constructed_bit_string() {
     let val = ASN1BitString::from_ber_node(node).expect("Failed to parse Bit String");
     
     assert_eq!(val.padding_bits, 4);
-    assert_eq!(val.bytes, bytes::Bytes::from(vec![0x41, 0x42]));
+    assert_eq!(val.bytes, rust_asn1::buffer::Bytes::from(vec![0x41, 0x42]));
 }
 
 
@@ -93,3 +93,33 @@ fn test_from_ber_iterator_error() {
    
    assert!(res.is_err());
 }
+
+#[test]
+fn test_ber_optional_implicitly_tagged() {
+    // SEQUENCE { [0] IMPLICIT INTEGER with a redundant leading zero }
+    let data = vec![0x30, 0x04, 0x80, 0x02, 0x00, 0x01];
+    let node = ber::parse(&data).expect("Failed to parse BER");
+    let tag = rust_asn1::asn1_types::ASN1Identifier::new(0, rust_asn1::asn1_types::TagClass::ContextSpecific);
+
+    let val: Option<ASN1Integer> = ber::sequence(node, rust_asn1::asn1_types::ASN1Identifier::SEQUENCE, |iter| {
+        let first = ber::optional_implicitly_tagged::<ASN1Integer>(iter, tag)?;
+        let second = ber::optional_implicitly_tagged::<ASN1Integer>(iter, tag)?;
+        assert!(second.is_none());
+        Ok(first)
+    }).expect("Failed to parse sequence");
+
+    assert_eq!(val, Some(ASN1Integer::from(1)));
+}
+
+#[test]
+#[cfg(feature = "bytes")]
+fn test_ber_parse_buf_chained() {
+    use bytes::Buf;
+
+    // Indefinite-length SEQUENCE split across two segments.
+    let head: &[u8] = &[0x30, 0x80, 0x02, 0x01];
+    let tail: &[u8] = &[0x0A, 0x00, 0x00];
+    let node = ber::parse_buf(head.chain(tail)).expect("Failed to parse BER");
+    assert!(node.is_constructed());
+    assert_eq!(node.encoded_bytes.len(), 7);
+}
@@ -0,0 +1,44 @@
+//! The time types without `chrono` or `time`; run with
+//! `cargo test --no-default-features --test builtin_time_tests`.
+#![cfg(not(any(feature = "chrono", feature = "time")))]
+
+use rust_asn1::asn1_types::{GeneralizedTime, UTCTime, UtcDateTime};
+use rust_asn1::ber::{self, BERParseable};
+use rust_asn1::der::{DERParseable, DERSerializable, Serializer};
+use rust_asn1::errors::ErrorCode;
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+#[test]
+fn test_time_components_without_a_date_library() {
+    let encoded = [&[0x18, 0x12][..], b"20240229235958.25Z"].concat();
+    let parsed = GeneralizedTime::from_der_bytes(&encoded).unwrap();
+    let time = parsed.0;
+    assert_eq!((time.year(), time.month(), time.day()), (2024, 2, 29));
+    assert_eq!((time.hour(), time.minute(), time.second(), time.nanosecond()), (23, 59, 58, 250_000_000));
+
+    let mut serializer = Serializer::new();
+    parsed.serialize(&mut serializer).unwrap();
+    assert_eq!(serializer.serialized_bytes().to_vec(), encoded);
+    assert!("20230229000000Z".parse::<GeneralizedTime>().is_err());
+
+    // Offsets are applied across day and year boundaries.
+    let offset = [&[0x17, 0x11][..], b"991231230000-0200"].concat();
+    let parsed = UTCTime::from_ber_node(ber::parse(&offset).unwrap()).unwrap();
+    assert_eq!(parsed.0, UtcDateTime::new(2000, 1, 1, 1, 0, 0, 0).unwrap());
+    assert_eq!(parsed.to_string(), "000101010000Z");
+}
+
+#[test]
+fn test_system_time_without_a_date_library() {
+    let time = UNIX_EPOCH + Duration::from_secs(1_672_574_400) + Duration::from_millis(5);
+    let mut serializer = Serializer::new();
+    time.serialize(&mut serializer).unwrap();
+    let encoded = serializer.serialized_bytes();
+    assert_eq!(&encoded[2..], b"20230101120000.005Z");
+    assert_eq!(SystemTime::from_der_bytes(&encoded).unwrap(), time);
+    assert!(GeneralizedTime::try_from(time).unwrap() > UTCTime::try_from(UNIX_EPOCH).unwrap());
+    assert_eq!(UtcDateTime::try_from(time).unwrap(), UtcDateTime::new(2023, 1, 1, 12, 0, 0, 5_000_000).unwrap());
+
+    let far_future = UNIX_EPOCH + Duration::from_secs(1 << 62);
+    assert_eq!(UtcDateTime::try_from(far_future).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+}
@@ -0,0 +1,391 @@
+#![cfg(feature = "derive")]
+
+use std::net::Ipv4Addr;
+
+use rust_asn1::asn1::ASN1Node;
+use rust_asn1::asn1_err;
+use rust_asn1::asn1_types::{ASN1Identifier, ASN1Integer, ASN1ObjectIdentifier, ASN1OctetString, TagClass};
+use rust_asn1::der::{DERImplicitlyTaggable, DERParseable, DERSequence, Serializer};
+use rust_asn1::errors::{ASN1Error, ErrorCode};
+use rust_asn1::testing::{assert_der_roundtrip, assert_parses_to};
+
+#[derive(Debug, Clone, PartialEq, DERSequence)]
+struct AlgorithmIdentifier {
+    algorithm: ASN1ObjectIdentifier,
+    parameters: Option<ASN1OctetString>,
+}
+
+#[derive(Debug, Clone, PartialEq, DERSequence)]
+struct Signed {
+    version: i64,
+    algorithm: AlgorithmIdentifier,
+    flags: Vec<bool>,
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Pair(ASN1Integer, bool);
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Empty;
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Wrapper<T> {
+    inner: T,
+}
+
+#[test]
+fn test_derive_sequence_roundtrip() {
+    let value = Signed {
+        version: 2,
+        algorithm: AlgorithmIdentifier {
+            algorithm: ASN1ObjectIdentifier::new(&[1, 2, 840, 113549]).unwrap(),
+            parameters: None,
+        },
+        flags: vec![true],
+    };
+    let encoded = assert_der_roundtrip(&value);
+    assert_eq!(
+        encoded,
+        vec![
+            0x30, 0x12, 0x02, 0x01, 0x02, 0x30, 0x08, 0x06, 0x06, 0x2A, 0x86, 0x48, 0x86, 0xF7, 0x0D,
+            0x30, 0x03, 0x01, 0x01, 0xFF,
+        ]
+    );
+
+    assert_der_roundtrip(&Pair(ASN1Integer::from(-3), false));
+    assert_parses_to(&[0x30, 0x00], &Empty);
+    assert_parses_to(&[0x30, 0x03, 0x02, 0x01, 0x09], &Wrapper { inner: 9u8 });
+}
+
+#[test]
+fn test_derive_sequence_errors() {
+    // Missing trailing field.
+    let err = Pair::from_der_bytes(&[0x30, 0x03, 0x02, 0x01, 0x01]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+    // Extra trailing field.
+    let err = Empty::from_der_bytes(&[0x30, 0x03, 0x05, 0x01, 0x00]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+    // Not a SEQUENCE.
+    let err = Empty::from_der_bytes(&[0x31, 0x00]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Envelope<T, U> {
+    version: i64,
+    #[asn1(context = 0, explicit)]
+    body: T,
+    #[asn1(context = 1, implicit, optional)]
+    extra: Option<U>,
+}
+
+/// Borrows its fields, so only `DERSerializable` is derived.
+#[derive(DERSequence)]
+struct Borrowed<'a> {
+    value: &'a ASN1Integer,
+    #[asn1(context = 0, implicit, optional)]
+    label: Option<&'a ASN1OctetString>,
+    #[asn1(context = 1, explicit)]
+    flags: &'a [bool],
+}
+
+#[derive(DERSequence)]
+struct BorrowedEnvelope<'a, T> {
+    version: i64,
+    body: &'a T,
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct OwnedEnvelope<T> {
+    version: i64,
+    body: T,
+}
+
+#[test]
+fn test_derive_generics_and_lifetimes() {
+    let envelope = Envelope { version: 1, body: Pair(ASN1Integer::from(2), true), extra: Some(ASN1OctetString::from(vec![0xAB])) };
+    let encoded = assert_der_roundtrip(&envelope);
+    assert_eq!(
+        encoded,
+        vec![
+            0x30, 0x10, 0x02, 0x01, 0x01, 0xA0, 0x08, 0x30, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF, 0x81,
+            0x01, 0xAB,
+        ]
+    );
+    assert_der_roundtrip(&Envelope::<bool, i64> { version: 1, body: false, extra: None });
+
+    let encode = |value: &dyn Fn(&mut Serializer)| {
+        let mut serializer = Serializer::new();
+        value(&mut serializer);
+        serializer.serialized_bytes().to_vec()
+    };
+    let value = ASN1Integer::from(5);
+    let label = ASN1OctetString::from(vec![0xAB]);
+    let flags = vec![true, false];
+    let borrowed = Borrowed { value: &value, label: Some(&label), flags: &flags };
+    assert_eq!(
+        encode(&|s| s.serialize(&borrowed).unwrap()),
+        vec![0x30, 0x10, 0x02, 0x01, 0x05, 0x80, 0x01, 0xAB, 0xA1, 0x08, 0x30, 0x06, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00]
+    );
+    let borrowed = Borrowed { label: None, ..borrowed };
+    assert_eq!(
+        encode(&|s| s.serialize(&borrowed).unwrap()),
+        vec![0x30, 0x0D, 0x02, 0x01, 0x05, 0xA1, 0x08, 0x30, 0x06, 0x01, 0x01, 0xFF, 0x01, 0x01, 0x00]
+    );
+
+    // A borrowed envelope encodes like the owned one.
+    let body = Pair(ASN1Integer::from(2), true);
+    let encoded = encode(&|s| s.serialize(&BorrowedEnvelope { version: 1, body: &body }).unwrap());
+    assert_parses_to(&encoded, &OwnedEnvelope { version: 1, body });
+}
+
+#[test]
+fn test_derive_sequence_implicit_tagging() {
+    let tag = ASN1Identifier::new(3, TagClass::ContextSpecific);
+    assert_eq!(Pair::default_identifier(), ASN1Identifier::SEQUENCE);
+
+    let mut serializer = Serializer::new();
+    serializer.serialize_with_identifier(&Pair(ASN1Integer::from(1), true), tag).unwrap();
+    let encoded = serializer.serialized_bytes();
+    assert_eq!(encoded.as_ref(), &[0xA3, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF]);
+
+    let node = rust_asn1::der::parse(&encoded).unwrap();
+    assert_eq!(Pair::from_der_node_with_identifier(node, tag).unwrap(), Pair(ASN1Integer::from(1), true));
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Tagged {
+    #[asn1(context = 0, explicit)]
+    version: i64,
+    #[asn1(context = 1, implicit)]
+    key_id: ASN1OctetString,
+    #[asn1(context = 2, implicit)]
+    pair: Pair,
+    serial: ASN1Integer,
+}
+
+#[test]
+fn test_derive_field_tagging() {
+    let value = Tagged {
+        version: 2,
+        key_id: ASN1OctetString::from(vec![0xAB]),
+        pair: Pair(ASN1Integer::from(1), true),
+        serial: ASN1Integer::from(7),
+    };
+    let encoded = assert_der_roundtrip(&value);
+    assert_eq!(
+        encoded,
+        vec![
+            0x30, 0x13, 0xA0, 0x03, 0x02, 0x01, 0x02, 0x81, 0x01, 0xAB, 0xA2, 0x06, 0x02, 0x01, 0x01,
+            0x01, 0x01, 0xFF, 0x02, 0x01, 0x07,
+        ]
+    );
+
+    // The untagged encoding of a tagged field is rejected.
+    let untagged = [0x30, 0x11, 0x02, 0x01, 0x02, 0x04, 0x01, 0xAB, 0x30, 0x06, 0x02, 0x01, 0x01, 0x01, 0x01, 0xFF, 0x02, 0x01, 0x07];
+    let err = Tagged::from_der_bytes(&untagged).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::UnexpectedFieldType);
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Extension {
+    extn_id: ASN1ObjectIdentifier,
+    #[asn1(default = "false")]
+    critical: bool,
+    extn_value: ASN1OctetString,
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Certificate {
+    #[asn1(context = 0, explicit, default = "0")]
+    version: i64,
+    serial: i64,
+    #[asn1(context = 1, implicit, optional)]
+    issuer_unique_id: Option<ASN1OctetString>,
+    #[asn1(context = 3, explicit, optional)]
+    extensions: Option<Vec<Extension>>,
+}
+
+#[test]
+fn test_derive_optional_and_default() {
+    let extension = Extension {
+        extn_id: ASN1ObjectIdentifier::new(&[2, 5, 29, 19]).unwrap(),
+        critical: false,
+        extn_value: ASN1OctetString::from(vec![0x30, 0x00]),
+    };
+    // The DEFAULT FALSE field is omitted.
+    let encoded = assert_der_roundtrip(&extension);
+    assert_eq!(encoded, vec![0x30, 0x09, 0x06, 0x03, 0x55, 0x1D, 0x13, 0x04, 0x02, 0x30, 0x00]);
+    assert_der_roundtrip(&Extension { critical: true, ..extension });
+
+    let minimal = Certificate { version: 0, serial: 5, issuer_unique_id: None, extensions: None };
+    let encoded = assert_der_roundtrip(&minimal);
+    assert_eq!(encoded, vec![0x30, 0x03, 0x02, 0x01, 0x05]);
+
+    let full = Certificate {
+        version: 2,
+        serial: 5,
+        issuer_unique_id: Some(ASN1OctetString::from(vec![0x01])),
+        extensions: Some(vec![]),
+    };
+    let encoded = assert_der_roundtrip(&full);
+    assert_eq!(
+        encoded,
+        vec![0x30, 0x0F, 0xA0, 0x03, 0x02, 0x01, 0x02, 0x02, 0x01, 0x05, 0x81, 0x01, 0x01, 0xA3, 0x02, 0x30, 0x00]
+    );
+
+    // DER rejects an explicitly encoded DEFAULT value.
+    let err = Extension::from_der_bytes(&[
+        0x30, 0x0C, 0x06, 0x03, 0x55, 0x1D, 0x13, 0x01, 0x01, 0x00, 0x04, 0x02, 0x30, 0x00,
+    ])
+    .unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+    let err = Certificate::from_der_bytes(&[0x30, 0x08, 0xA0, 0x03, 0x02, 0x01, 0x00, 0x02, 0x01, 0x05]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::InvalidASN1Object);
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+#[asn1(automatic_tags)]
+struct Automatic {
+    id: i64,
+    #[asn1(optional)]
+    label: Option<ASN1OctetString>,
+    #[asn1(default = "true")]
+    enabled: bool,
+    #[asn1(explicit)]
+    pair: Pair,
+}
+
+#[test]
+fn test_derive_automatic_tags() {
+    let value = Automatic {
+        id: 1,
+        label: Some(ASN1OctetString::from(vec![0x61])),
+        enabled: false,
+        pair: Pair(ASN1Integer::from(2), true),
+    };
+    let encoded = assert_der_roundtrip(&value);
+    assert_eq!(
+        encoded,
+        vec![
+            0x30, 0x13, 0x80, 0x01, 0x01, 0x81, 0x01, 0x61, 0x82, 0x01, 0x00, 0xA3, 0x08, 0x30, 0x06,
+            0x02, 0x01, 0x02, 0x01, 0x01, 0xFF,
+        ]
+    );
+
+    // Absent OPTIONAL and DEFAULT fields keep the numbering of the later fields.
+    let value = Automatic { label: None, enabled: true, ..value };
+    let encoded = assert_der_roundtrip(&value);
+    assert_eq!(
+        encoded,
+        vec![0x30, 0x0D, 0x80, 0x01, 0x01, 0xA3, 0x08, 0x30, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF]
+    );
+}
+
+/// Reads a `Pair` carried DER-encoded inside an OCTET STRING, as X.509 carries extension values.
+fn parse_wrapped_pair(node: ASN1Node) -> Result<Pair, ASN1Error> {
+    ASN1OctetString::from_der_node(node)?.decode_inner_der()
+}
+
+fn serialize_wrapped_pair(pair: &Pair, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+    let mut inner = Serializer::new();
+    inner.serialize(pair)?;
+    serializer.serialize(&ASN1OctetString::from(&inner.serialized_bytes()[..]))
+}
+
+/// Older writers encoded the flag as an INTEGER 0 or 1.
+fn parse_legacy_flag(node: ASN1Node) -> Result<bool, ASN1Error> {
+    if node.identifier != ASN1Identifier::INTEGER {
+        return bool::from_der_node(node);
+    }
+    match i64::from_der_node(node)? {
+        0 => Ok(false),
+        1 => Ok(true),
+        other => Err(asn1_err!(ErrorCode::ValueOutOfRange, "{} is not a legacy flag", other)),
+    }
+}
+
+#[derive(Debug, PartialEq, DERSequence)]
+struct Hooked {
+    #[asn1(parse_with = "parse_wrapped_pair", serialize_with = "serialize_wrapped_pair")]
+    pair: Pair,
+    #[asn1(context = 0, explicit, optional, parse_with = "parse_legacy_flag")]
+    flag: Option<bool>,
+    serial: i64,
+}
+
+fn parse_address(node: ASN1Node) -> Result<Ipv4Addr, ASN1Error> {
+    let octets = ASN1OctetString::from_der_node(node)?;
+    let octets: [u8; 4] = octets[..]
+        .try_into()
+        .map_err(|_| asn1_err!(ErrorCode::InvalidASN1Object, "an IPv4 address is 4 octets, got {}", octets.len()))?;
+    Ok(Ipv4Addr::from(octets))
+}
+
+fn serialize_address(address: &Ipv4Addr, serializer: &mut Serializer) -> Result<(), ASN1Error> {
+    serializer.serialize(&ASN1OctetString::from(&address.octets()[..]))
+}
+
+/// `Ipv4Addr` has no ASN.1 impls, so the generated bounds must leave it out.
+#[derive(Debug, PartialEq, DERSequence)]
+struct Endpoint<T> {
+    #[asn1(context = 1, explicit, default = "Ipv4Addr::LOCALHOST", parse_with = "parse_address", serialize_with = "serialize_address")]
+    address: Ipv4Addr,
+    port: T,
+}
+
+#[test]
+fn test_derive_parse_with_and_serialize_with() {
+    let value = Hooked { pair: Pair(ASN1Integer::from(2), true), flag: Some(true), serial: 7 };
+    let encoded = assert_der_roundtrip(&value);
+    assert_eq!(
+        encoded,
+        vec![
+            0x30, 0x12, 0x04, 0x08, 0x30, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF, 0xA0, 0x03, 0x01, 0x01,
+            0xFF, 0x02, 0x01, 0x07,
+        ]
+    );
+    assert_der_roundtrip(&Hooked { flag: None, ..value });
+
+    // The hook takes the legacy INTEGER form inside the explicit tag.
+    let legacy = [
+        0x30, 0x12, 0x04, 0x08, 0x30, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF, 0xA0, 0x03, 0x02, 0x01,
+        0x01, 0x02, 0x01, 0x07,
+    ];
+    assert_parses_to(&legacy, &Hooked { pair: Pair(ASN1Integer::from(2), true), flag: Some(true), serial: 7 });
+    let mut bad = legacy;
+    bad[16] = 0x02;
+    assert_eq!(Hooked::from_der_bytes(&bad).unwrap_err().code(), ErrorCode::ValueOutOfRange);
+
+    // The unwrapped encoding is rejected by the hook.
+    let unwrapped = [0x30, 0x0B, 0x30, 0x06, 0x02, 0x01, 0x02, 0x01, 0x01, 0xFF, 0x02, 0x01, 0x07];
+    assert_eq!(Hooked::from_der_bytes(&unwrapped).unwrap_err().code(), ErrorCode::UnexpectedFieldType);
+
+    let endpoint = Endpoint { address: Ipv4Addr::new(192, 0, 2, 1), port: 443i64 };
+    let encoded = assert_der_roundtrip(&endpoint);
+    assert_eq!(encoded, vec![0x30, 0x0C, 0xA1, 0x06, 0x04, 0x04, 0xC0, 0x00, 0x02, 0x01, 0x02, 0x02, 0x01, 0xBB]);
+    let encoded = assert_der_roundtrip(&Endpoint { address: Ipv4Addr::LOCALHOST, port: 443i64 });
+    assert_eq!(encoded, vec![0x30, 0x04, 0x02, 0x02, 0x01, 0xBB]);
+    let short = [0x30, 0x0B, 0xA1, 0x05, 0x04, 0x03, 0xC0, 0x00, 0x02, 0x02, 0x02, 0x01, 0xBB];
+    assert_eq!(Endpoint::<i64>::from_der_bytes(&short).unwrap_err().code(), ErrorCode::InvalidASN1Object);
+}
+
+#[cfg(feature = "real")]
+#[derive(Debug, PartialEq, DERSequence)]
+struct Measurement {
+    value: f64,
+    tolerance: Option<f32>,
+}
+
+#[cfg(feature = "real")]
+#[test]
+fn test_derive_sequence_float_fields() {
+    let value = Measurement { value: 1.5, tolerance: Some(0.25) };
+    let encoded = assert_der_roundtrip(&value);
+    assert_eq!(encoded, vec![0x30, 0x0A, 0x09, 0x03, 0x80, 0xFF, 0x03, 0x09, 0x03, 0x80, 0xFE, 0x01]);
+    assert_der_roundtrip(&Measurement { value: f64::NEG_INFINITY, tolerance: None });
+
+    // An f32 field rejects a REAL it cannot hold exactly.
+    let err = Measurement::from_der_bytes(&[0x30, 0x0B, 0x09, 0x03, 0x80, 0xFF, 0x03, 0x09, 0x04, 0x81, 0xFF, 0x38, 0x01]).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+}
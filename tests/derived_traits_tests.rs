@@ -1,8 +1,11 @@
+#![allow(clippy::clone_on_copy)]
+
 use rust_asn1::asn1_types::*;
 use rust_asn1::asn1::{EncodingRules, Content};
-use rust_asn1::errors::{ASN1Error, ErrorCode};
-use bytes::Bytes;
-use chrono::Utc;
+use rust_asn1::errors::ErrorCode;
+use rust_asn1::asn1_types::time::Timestamp;
+use rust_asn1::buffer::Bytes;
+use std::time::SystemTime;
 
 #[test]
 fn test_derived_traits() {
@@ -66,7 +69,7 @@ fn test_derived_traits() {
     
     // ASN1Integer From/Into
     let i_from: ASN1Integer = 123i64.into();
-    let i_u8: ASN1Integer = (123u8 as i64).into();
+    let _i_u8: ASN1Integer = (123u8 as i64).into();
     // Test clone/debug for all these is covered by generic derive logic usually but let's be sure
     assert_eq!(i_from, ASN1Integer::from(123));
 
@@ -92,7 +95,7 @@ fn test_derived_traits() {
     assert!(check_hash(&oid));
     
     // GeneralizedTime
-    let now = Utc::now();
+    let now: Timestamp = GeneralizedTime::try_from(SystemTime::now()).unwrap().0;
     let gt = GeneralizedTime(now);
     let gt2 = gt.clone();
     assert_eq!(gt, gt2);
@@ -111,24 +114,27 @@ fn test_derived_traits() {
     assert_eq!(ut, ut_from);
     
     // Strings
-    let s = ASN1UTF8String::new("A".to_string()).unwrap();
-    let s2 = s.clone();
-    assert_eq!(s, s2);
-    assert!(check_hash(&s));
-    let s_str: String = s.into();
-    assert_eq!(s_str, "A");
-    
-    let ps = ASN1PrintableString::new("A".to_string()).unwrap();
-    let ps2 = ps.clone();
-    assert_eq!(ps, ps2);
-    
-    let ia5 = ASN1IA5String::new("A".to_string()).unwrap();
-    let ia5_2 = ia5.clone();
-    assert_eq!(ia5, ia5_2);
-    
-    let num = ASN1NumericString::new("123".to_string()).unwrap();
-    let num2 = num.clone();
-    assert_eq!(num, num2);
+    #[cfg(feature = "strings")]
+    {
+        let s = ASN1UTF8String::new("A".to_string()).unwrap();
+        let s2 = s.clone();
+        assert_eq!(s, s2);
+        assert!(check_hash(&s));
+        let s_str: String = s.into();
+        assert_eq!(s_str, "A");
+
+        let ps = ASN1PrintableString::new("A".to_string()).unwrap();
+        let ps2 = ps.clone();
+        assert_eq!(ps, ps2);
+
+        let ia5 = ASN1IA5String::new("A".to_string()).unwrap();
+        let ia5_2 = ia5.clone();
+        assert_eq!(ia5, ia5_2);
+
+        let num = ASN1NumericString::new("123".to_string()).unwrap();
+        let num2 = num.clone();
+        assert_eq!(num, num2);
+    }
 }
 
 fn check_hash<T: std::hash::Hash>(t: &T) -> bool {
@@ -1,6 +1,12 @@
-use rust_asn1::asn1_types::{ASN1Boolean, ASN1Integer, ASN1OctetString, ASN1ObjectIdentifier, GeneralizedTime, UTCTime, ASN1Null, ASN1BitString, ASN1UTF8String, ASN1PrintableString, ASN1IA5String, ASN1NumericString};
+use rust_asn1::asn1_types::{ASN1Boolean, ASN1Integer, ASN1OctetString, ASN1ObjectIdentifier, ASN1Null, ASN1BitString};
+#[cfg(feature = "strings")]
+use rust_asn1::asn1_types::{ASN1UTF8String, ASN1PrintableString, ASN1IA5String, ASN1NumericString};
 use rust_asn1::der::{DERParseable, Serializer};
+#[cfg(feature = "num-bigint")]
 use num_bigint::BigInt;
+#[cfg(feature = "chrono")]
+use rust_asn1::asn1_types::{GeneralizedTime, UTCTime};
+#[cfg(feature = "chrono")]
 use chrono::{Utc, TimeZone};
 use std::fs;
 use std::path::Path;
@@ -8,7 +14,7 @@ use std::path::Path;
 
 fn read_golden(name: &str) -> Vec<u8> {
     let path = Path::new("tests/golden").join(name);
-    fs::read(&path).expect(&format!("Failed to read golden file: {}", path.display()))
+    fs::read(&path).unwrap_or_else(|_| panic!("Failed to read golden file: {}", path.display()))
 }
 
 #[test]
@@ -56,6 +62,7 @@ fn test_integer_neg1() {
 }
 
 #[test]
+#[cfg(feature = "num-bigint")]
 fn test_integer_large() {
     let bytes = read_golden("int_large.der");
     let val = ASN1Integer::from_der_bytes(&bytes).expect("Parse failed");
@@ -104,6 +111,7 @@ fn test_oid() {
 }
 
 #[test]
+#[cfg(feature = "chrono")]
 fn test_generalized_time() {
     let bytes = read_golden("generalized_time.der");
     let val = GeneralizedTime::from_der_bytes(&bytes).expect("Parse failed");
@@ -117,6 +125,7 @@ fn test_generalized_time() {
 }
 
 #[test]
+#[cfg(feature = "chrono")]
 fn test_utc_time() {
     let bytes = read_golden("utc_time.der");
     let val = UTCTime::from_der_bytes(&bytes).expect("Parse failed");
@@ -154,6 +163,7 @@ fn test_bit_string() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_utf8_string() {
     let bytes = read_golden("utf8_string.der");
     let val = ASN1UTF8String::from_der_bytes(&bytes).expect("Parse failed");
@@ -165,6 +175,7 @@ fn test_utf8_string() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_printable_string() {
     let bytes = read_golden("printable_string.der");
     let val = ASN1PrintableString::from_der_bytes(&bytes).expect("Parse failed");
@@ -176,6 +187,7 @@ fn test_printable_string() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_ia5_string() {
     let bytes = read_golden("ia5_string.der");
     let val = ASN1IA5String::from_der_bytes(&bytes).expect("Parse failed");
@@ -187,6 +199,7 @@ fn test_ia5_string() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_numeric_string() {
     let bytes = read_golden("numeric_string.der");
     let val = ASN1NumericString::from_der_bytes(&bytes).expect("Parse failed");
@@ -0,0 +1,50 @@
+//! The time types on the `time` crate; run with
+//! `cargo test --no-default-features --features time --test time_backend_tests`.
+#![cfg(all(feature = "time", not(feature = "chrono")))]
+
+use rust_asn1::asn1_types::{ASN1Time, GeneralizedTime, UTCTime};
+use rust_asn1::ber::{self, BERParseable};
+use rust_asn1::der::{DERParseable, DERSerializable, Serializer};
+use time::{Date, Month, OffsetDateTime, PrimitiveDateTime, Time, UtcOffset};
+
+fn utc(year: i32, month: Month, day: u8, (hour, minute, second): (u8, u8, u8), millis: u16) -> OffsetDateTime {
+    let date = Date::from_calendar_date(year, month, day).unwrap();
+    PrimitiveDateTime::new(date, Time::from_hms_milli(hour, minute, second, millis).unwrap()).assume_utc()
+}
+
+#[test]
+fn test_generalized_time_on_offset_date_time() {
+    let expected = utc(2023, Month::January, 1, (12, 0, 0), 250);
+    let encoded = [&[0x18, 0x12][..], b"20230101120000.25Z"].concat();
+    let parsed = GeneralizedTime::from_der_bytes(&encoded).unwrap();
+    assert_eq!(parsed.0, expected);
+
+    let mut serializer = Serializer::new();
+    parsed.serialize(&mut serializer).unwrap();
+    assert_eq!(serializer.serialized_bytes().to_vec(), encoded);
+
+    // A foreign offset is written out in UTC.
+    let shifted = GeneralizedTime(utc(2023, Month::January, 1, (12, 0, 0), 250).to_offset(UtcOffset::from_hms(1, 0, 0).unwrap()));
+    assert_eq!(shifted.to_string(), "20230101120000.25Z");
+    assert_eq!(shifted, parsed);
+
+    let offset = [&[0x18, 0x13][..], b"20230101070000-0500"].concat();
+    let parsed = GeneralizedTime::from_ber_node(ber::parse(&offset).unwrap()).unwrap();
+    assert_eq!(parsed.0, utc(2023, Month::January, 1, (12, 0, 0), 0));
+}
+
+#[test]
+fn test_utc_time_and_timestamps_on_offset_date_time() {
+    let utc_time = [&[0x17, 0x0D][..], b"491231235959Z"].concat();
+    let parsed = UTCTime::from_der_bytes(&utc_time).unwrap();
+    assert_eq!(parsed.0, utc(2049, Month::December, 31, (23, 59, 59), 0));
+    assert_eq!(parsed.to_string(), "491231235959Z");
+    assert_eq!(OffsetDateTime::from_der_bytes(&utc_time).unwrap(), parsed);
+
+    let mut serializer = Serializer::new();
+    utc(2023, Month::January, 1, (12, 0, 0), 0).serialize(&mut serializer).unwrap();
+    assert_eq!(&serializer.serialized_bytes()[2..], b"20230101120000Z");
+
+    assert!("20230229000000Z".parse::<GeneralizedTime>().is_err());
+    assert_eq!(ASN1Time::new(utc(2023, Month::January, 1, (12, 0, 0), 500)).as_str(), "2023-01-01T12:00:00.5Z");
+}
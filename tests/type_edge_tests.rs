@@ -1,11 +1,18 @@
-use bytes::Bytes;
-use chrono::{TimeZone, Utc};
+use rust_asn1::buffer::Bytes;
+#[cfg(feature = "chrono")]
+use chrono::{Datelike, TimeZone, Utc};
 use rust_asn1::asn1::ASN1Node;
 use rust_asn1::asn1_types::{
-    ASN1BitString, ASN1Boolean, ASN1IA5String, ASN1Identifier, ASN1Integer, ASN1Null,
-    ASN1NumericString, ASN1OctetString, ASN1PrintableString, ASN1UTF8String, GeneralizedTime,
-    UTCTime,
+    ASN1BitString, BitStringBuilder, ASN1Boolean, ASN1Identifier, ASN1Integer, ASN1Null, ASN1OctetString,
+    GeneralizedTime, UTCTime,
 };
+#[cfg(feature = "strings")]
+use rust_asn1::asn1_types::{
+    ASN1GeneralString, ASN1GraphicString, ASN1IA5String, ASN1NumericString, ASN1ObjectDescriptor, ASN1PrintableString,
+    ASN1UTF8String, ASN1VideotexString,
+};
+#[cfg(feature = "chrono")]
+use rust_asn1::asn1_types::{ASN1Time, RawTime};
 use rust_asn1::ber::{self, BERImplicitlyTaggable, BERParseable};
 use rust_asn1::der::{self, DERImplicitlyTaggable, DERParseable, DERSerializable, Serializer};
 
@@ -137,6 +144,55 @@ fn test_integer_neg_one() {
     assert_eq!(serializer.serialized_bytes(), vec![0x02, 0x01, 0xFF]);
 }
 
+#[test]
+fn test_integer_unsigned_bytes() {
+    // An RSA-style modulus with the top bit set carries a sign-padding zero on the wire.
+    let node = der::parse(&[0x02, 0x03, 0x00, 0xC1, 0x01]).unwrap();
+    let modulus = ASN1Integer::from_der_node(node).unwrap();
+    assert_eq!(modulus.to_unsigned_bytes_be().unwrap(), vec![0xC1, 0x01]);
+    assert_eq!(ASN1Integer::from(65537).to_unsigned_bytes_be().unwrap(), vec![0x01, 0x00, 0x01]);
+    assert_eq!(ASN1Integer::from(0).to_unsigned_bytes_be().unwrap(), vec![0x00]);
+    let err = ASN1Integer::from(-1).to_unsigned_bytes_be().unwrap_err();
+    assert_eq!(err.code(), rust_asn1::errors::ErrorCode::ValueOutOfRange);
+}
+
+#[test]
+fn test_integer_from_unsigned_bytes() {
+    let encode = |value: &ASN1Integer| {
+        let mut serializer = Serializer::new();
+        value.serialize(&mut serializer).unwrap();
+        serializer.serialized_bytes().to_vec()
+    };
+    assert_eq!(encode(&ASN1Integer::from_unsigned_bytes_be(&[0xC1, 0x01])), vec![0x02, 0x03, 0x00, 0xC1, 0x01]);
+    assert_eq!(encode(&ASN1Integer::from_unsigned_bytes_be(&[0x00, 0x00, 0x7F])), vec![0x02, 0x01, 0x7F]);
+    assert_eq!(ASN1Integer::from_unsigned_bytes_be(&[]), ASN1Integer::from(0));
+    assert_eq!(ASN1Integer::from_unsigned_bytes_be(&[0x01, 0x00, 0x01]), ASN1Integer::from(65537));
+    let bytes = [0xFF; 32];
+    assert_eq!(ASN1Integer::from_unsigned_bytes_be(&bytes).to_unsigned_bytes_be().unwrap(), bytes.to_vec());
+}
+
+#[test]
+fn test_integer_string_forms() {
+    let big = "-340282366920938463463374607431768211457";
+    for text in ["0", "127", "-128", "256", "65537", "-1", big] {
+        assert_eq!(text.parse::<ASN1Integer>().unwrap().to_string(), text);
+    }
+    assert_eq!("+42".parse::<ASN1Integer>().unwrap(), ASN1Integer::from(42));
+    assert_eq!("-0".parse::<ASN1Integer>().unwrap(), ASN1Integer::from(0));
+    assert_eq!("000128".parse::<ASN1Integer>().unwrap(), ASN1Integer::from(128));
+    for text in ["", "-", "12a", " 1", "1_000", "0x10"] {
+        let err = text.parse::<ASN1Integer>().unwrap_err();
+        assert_eq!(err.code(), rust_asn1::errors::ErrorCode::InvalidStringRepresentation, "{:?}", text);
+    }
+
+    assert_eq!(format!("{:x}", ASN1Integer::from(255)), "ff");
+    assert_eq!(format!("{:#x}", ASN1Integer::from(-256)), "-0x100");
+    assert_eq!(format!("{:x}", ASN1Integer::from(0)), "0");
+    assert_eq!(format!("{:x}", ASN1Integer::from(-128)), "-80");
+    assert_eq!(format!("{:x}", big.parse::<ASN1Integer>().unwrap()), "-100000000000000000000000000000001");
+    assert_eq!(format!("{:>6x}", ASN1Integer::from(10)), "     a");
+}
+
 #[test]
 fn test_integer_ber_identifier_mismatch() {
     let node = ber::parse(&[0x02, 0x01, 0x00]).unwrap();
@@ -327,11 +383,8 @@ fn test_oid_construct() {
 }
 
 #[test]
+#[cfg(feature = "chrono")]
 fn test_time_methods() {
-    // GeneralizedTime with fractional seconds? (Not supported yet, but checking error?)
-    // GeneralizedTime("20230101120000.123Z") -> Error
-    // We only test what we have implemented.
-
     let dt = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
     let gt: GeneralizedTime = dt.into();
     assert_eq!(gt.0, dt);
@@ -344,8 +397,8 @@ fn test_time_parsing_errors() {
     let data = "20230101120000".as_bytes(); // No Z
     let node = ASN1Node {
         identifier: rust_asn1::asn1_types::ASN1Identifier::GENERALIZED_TIME,
-        content: rust_asn1::asn1::Content::Primitive(bytes::Bytes::copy_from_slice(data)),
-        encoded_bytes: bytes::Bytes::new(),
+        content: rust_asn1::asn1::Content::Primitive(rust_asn1::buffer::Bytes::copy_from_slice(data)),
+        encoded_bytes: rust_asn1::buffer::Bytes::new(),
     };
     assert!(GeneralizedTime::from_der_node(node.clone()).is_err()); // Missing Z
 
@@ -353,8 +406,8 @@ fn test_time_parsing_errors() {
     let data = "2023-01-01 12:00:00Z".as_bytes();
     let node = ASN1Node {
         identifier: rust_asn1::asn1_types::ASN1Identifier::GENERALIZED_TIME,
-        content: rust_asn1::asn1::Content::Primitive(bytes::Bytes::copy_from_slice(data)),
-        encoded_bytes: bytes::Bytes::new(),
+        content: rust_asn1::asn1::Content::Primitive(rust_asn1::buffer::Bytes::copy_from_slice(data)),
+        encoded_bytes: rust_asn1::buffer::Bytes::new(),
     };
     assert!(GeneralizedTime::from_der_node(node).is_err());
 
@@ -363,8 +416,8 @@ fn test_time_parsing_errors() {
     let data = "230101120000".as_bytes();
     let node = ASN1Node {
         identifier: rust_asn1::asn1_types::ASN1Identifier::UTC_TIME,
-        content: rust_asn1::asn1::Content::Primitive(bytes::Bytes::copy_from_slice(data)),
-        encoded_bytes: bytes::Bytes::new(),
+        content: rust_asn1::asn1::Content::Primitive(rust_asn1::buffer::Bytes::copy_from_slice(data)),
+        encoded_bytes: rust_asn1::buffer::Bytes::new(),
     };
     assert!(UTCTime::from_der_node(node.clone()).is_err());
 
@@ -372,8 +425,8 @@ fn test_time_parsing_errors() {
     let data = "23".as_bytes();
     let node = ASN1Node {
         identifier: rust_asn1::asn1_types::ASN1Identifier::UTC_TIME,
-        content: rust_asn1::asn1::Content::Primitive(bytes::Bytes::copy_from_slice(data)),
-        encoded_bytes: bytes::Bytes::new(),
+        content: rust_asn1::asn1::Content::Primitive(rust_asn1::buffer::Bytes::copy_from_slice(data)),
+        encoded_bytes: rust_asn1::buffer::Bytes::new(),
     };
     assert!(UTCTime::from_der_node(node).is_err());
 }
@@ -413,6 +466,7 @@ fn test_utc_time_single_z_rejected() {
 }
 
 #[test]
+#[cfg(feature = "chrono")]
 fn test_time_ber_wrappers() {
     let gt_bytes = b"20230101120000Z";
     let node = ber::parse(&[&[0x18, 0x0F][..], gt_bytes].concat()).unwrap();
@@ -441,6 +495,303 @@ fn test_time_ber_wrappers() {
     assert_eq!(v.0.format("%y%m%d%H%M%SZ").to_string(), "230101120000Z");
 }
 
+#[test]
+#[cfg(feature = "chrono")]
+fn test_time_ber_zone_offsets() {
+    use rust_asn1::errors::ErrorCode;
+
+    let expected = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    for value in ["20230101120000Z", "20230101143000+0230", "20230101070000-0500", "20230101120000"] {
+        let encoded = [&[0x18, value.len() as u8][..], value.as_bytes()].concat();
+        let parsed = GeneralizedTime::from_ber_node(ber::parse(&encoded).unwrap()).unwrap();
+        assert_eq!(parsed.0, expected, "{}", value);
+        assert_eq!(GeneralizedTime::from_ber_str(value).unwrap().0, expected, "{}", value);
+        if !value.ends_with('Z') {
+            assert!(GeneralizedTime::from_der_bytes(&encoded).is_err(), "{}", value);
+        }
+    }
+    for value in ["230101120000Z", "230101223000+1030", "230101120000"] {
+        let encoded = [&[0x17, value.len() as u8][..], value.as_bytes()].concat();
+        assert_eq!(UTCTime::from_ber_node(ber::parse(&encoded).unwrap()).unwrap().0, expected, "{}", value);
+        if !value.ends_with('Z') {
+            assert!(UTCTime::from_der_bytes(&encoded).is_err(), "{}", value);
+        }
+    }
+
+    // The offset can move the date across a year boundary.
+    let parsed = UTCTime::from_ber_str("991231230000-0200").unwrap();
+    assert_eq!(parsed.0, Utc.with_ymd_and_hms(2000, 1, 1, 1, 0, 0).unwrap());
+    let encoded = [&[0x18, 0x13][..], b"20230101070000-0500"].concat();
+    let node = ber::parse(&encoded).unwrap();
+    assert_eq!(chrono::DateTime::<Utc>::from_ber_node(node).unwrap(), expected);
+
+    for bad in ["20230101120000+2400", "20230101120000+0060", "20230101120000+01", "20230101120000+0a00", "20230101120000+01000", "2023010112+1é1"] {
+        let err = GeneralizedTime::from_ber_str(bad).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidStringRepresentation, "{}", bad);
+    }
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_generalized_time_ber_reduced_precision() {
+    for (value, expected) in [
+        ("2023010112Z", Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0)),
+        ("202301011230Z", Utc.with_ymd_and_hms(2023, 1, 1, 12, 30, 0)),
+        ("2023010112", Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0)),
+        ("202301011230+0100", Utc.with_ymd_and_hms(2023, 1, 1, 11, 30, 0)),
+    ] {
+        let encoded = [&[0x18, value.len() as u8][..], value.as_bytes()].concat();
+        let parsed = GeneralizedTime::from_ber_node(ber::parse(&encoded).unwrap()).unwrap();
+        assert_eq!(parsed.0, expected.unwrap(), "{}", value);
+        assert!(GeneralizedTime::from_der_bytes(&encoded).is_err(), "{}", value);
+    }
+    for bad in ["2023010124Z", "202301011260Z", "20230101Z", "2023010112301Z", "20230101123Z"] {
+        assert!(GeneralizedTime::from_ber_str(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_generalized_time_der_canonical_form() {
+    use rust_asn1::errors::ErrorCode;
+
+    let whole = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    let fractional = GeneralizedTime(whole + chrono::TimeDelta::milliseconds(120));
+    assert_eq!(fractional.to_string(), "20230101120000.12Z");
+    let mut serializer = Serializer::new();
+    fractional.serialize(&mut serializer).unwrap();
+    let encoded = serializer.serialized_bytes();
+    assert_eq!(&encoded[2..], b"20230101120000.12Z");
+    assert_eq!(GeneralizedTime::from_der_bytes(&encoded).unwrap(), fractional);
+    assert_eq!("20230101120000.000000001Z".parse::<GeneralizedTime>().unwrap().0, whole + chrono::TimeDelta::nanoseconds(1));
+
+    for (bad, reason) in [
+        ("20230101120000.120Z", "trailing zeros"),
+        ("20230101120000.0Z", "trailing zeros"),
+        ("20230101120000.Z", "no digits"),
+        ("20230101120000,12Z", "full stop"),
+        ("202301011200Z", "seconds must be present"),
+        ("2023010112Z", "seconds must be present"),
+        ("20230101240000Z", "midnight"),
+        ("20230101120000.1234567891Z", "beyond nanoseconds"),
+    ] {
+        let err = bad.parse::<GeneralizedTime>().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidStringRepresentation, "{}", bad);
+        assert!(format!("{}", err).contains(reason), "{}: {}", bad, err);
+    }
+
+    // BER takes the non-canonical fractions.
+    for value in ["20230101120000.120Z", "20230101120000,12Z", "20230101130000.12+0100"] {
+        assert_eq!(GeneralizedTime::from_ber_str(value).unwrap(), fractional, "{}", value);
+    }
+    for bad in ["20230101120000.Z", "202301011200.5Z", "20230101120000.1aZ"] {
+        assert!(GeneralizedTime::from_ber_str(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_time_ordering_across_representations() {
+    let not_before = UTCTime(Utc.with_ymd_and_hms(2023, 1, 1, 0, 0, 0).unwrap());
+    let not_after = GeneralizedTime(Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap());
+    let now = Utc.with_ymd_and_hms(2024, 6, 1, 0, 0, 0).unwrap();
+
+    assert!(not_before <= now && now <= not_after);
+    assert!(not_before < not_after);
+    assert!(not_after > not_before);
+    assert!(now >= not_before && not_after >= now);
+    assert_eq!(not_after.partial_cmp(&now), Some(std::cmp::Ordering::Greater));
+
+    let same_instant = GeneralizedTime(not_before.0);
+    assert!(same_instant == not_before);
+    assert!(not_before == same_instant);
+    assert!(not_before == not_before.0);
+    assert!(not_before.0 == not_before);
+    assert_eq!(same_instant.partial_cmp(&not_before), Some(std::cmp::Ordering::Equal));
+
+    let mut times = vec![not_after.clone(), GeneralizedTime(now), same_instant.clone()];
+    times.sort();
+    assert_eq!(times, vec![same_instant, GeneralizedTime(now), not_after]);
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_asn1_time() {
+    use rust_asn1::errors::ErrorCode;
+
+    let instant = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    let time = ASN1Time::new(instant);
+    assert_eq!(time.as_str(), "2023-01-01T12:00:00Z");
+    let mut serializer = Serializer::new();
+    time.serialize(&mut serializer).unwrap();
+    let encoded = serializer.serialized_bytes();
+    assert_eq!(&encoded[..2], &[0x0E, 0x14]);
+    assert_eq!(ASN1Time::from_der_bytes(&encoded).unwrap(), time);
+
+    // TIME is an assigned tag, so strict parsing accepts it.
+    let strict = rust_asn1::asn1::ParseOptions { reject_reserved_universal_tags: true };
+    let node = rust_asn1::asn1::parse_with_options(&encoded, rust_asn1::asn1::EncodingRules::Distinguished, strict).unwrap();
+    assert_eq!(ASN1Time::from_der_node(node).unwrap(), time);
+
+    let fractional = instant + chrono::TimeDelta::milliseconds(250);
+    assert_eq!(ASN1Time::new(fractional).as_str(), "2023-01-01T12:00:00.25Z");
+    assert_eq!("2023-01-01T12:00:00.25Z".parse::<ASN1Time>().unwrap().instant(), fractional);
+
+    for value in ["2023-01-01T12:00:00.250Z", "2023-01-01T12:00:00,25Z", "2023-01-01T13:00:00.25+01:00"] {
+        let encoded = [&[0x0E, value.len() as u8][..], value.as_bytes()].concat();
+        let err = ASN1Time::from_der_bytes(&encoded).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidStringRepresentation, "{}", value);
+        let parsed = ASN1Time::from_ber_node(ber::parse(&encoded).unwrap()).unwrap();
+        assert_eq!(parsed.instant(), fractional, "{}", value);
+        assert_eq!(parsed.as_str(), value);
+    }
+    for bad in ["2023-01-01T12:00:00", "2023-01-01 12:00:00Z", "20230101T120000Z", "2023-13-01T12:00:00Z", "2023-01-01T12:00:00.Z", "2023-01-01T12:00:00+0100"] {
+        assert!(ASN1Time::from_ber_str(bad).is_err(), "{}", bad);
+    }
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_raw_time_round_trips_original_bytes() {
+    let reencode = |value: &dyn Fn(&mut Serializer)| {
+        let mut serializer = Serializer::new();
+        value(&mut serializer);
+        serializer.serialized_bytes().to_vec()
+    };
+
+    // A BER offset is normalized away by GeneralizedTime but kept by RawTime.
+    let encoded = [&[0x18, 0x13][..], b"20230101070000-0500"].concat();
+    let raw = RawTime::<GeneralizedTime>::from_ber_node(ber::parse(&encoded).unwrap()).unwrap();
+    assert_eq!(raw.value().0, Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap());
+    assert_eq!(raw.original_bytes(), Some(&b"20230101070000-0500"[..]));
+    assert_eq!(reencode(&|s| raw.serialize(s).unwrap()), encoded);
+    assert_ne!(reencode(&|s| raw.value().serialize(s).unwrap()), encoded);
+
+    // Implicit tags are re-applied around the original content.
+    let mut serializer = Serializer::new();
+    serializer.serialize_with_identifier(&raw, ASN1Identifier::new(0, rust_asn1::asn1_types::TagClass::ContextSpecific)).unwrap();
+    assert_eq!(serializer.serialized_bytes()[0], 0x80);
+    assert_eq!(&serializer.serialized_bytes()[1..], &encoded[1..]);
+
+    let fresh = RawTime::new(UTCTime(Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap()));
+    assert_eq!(fresh.original_bytes(), None);
+    let encoded = reencode(&|s| fresh.serialize(s).unwrap());
+    assert_eq!(encoded, [&[0x17, 0x0D][..], b"230101120000Z"].concat());
+    let decoded = RawTime::<UTCTime>::from_der_bytes(&encoded).unwrap();
+    assert_eq!(decoded.into_inner(), fresh.into_inner());
+
+    assert!(RawTime::<UTCTime>::from_der_bytes(&[0x18, 0x01, b'0']).is_err());
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_utc_time_year_window() {
+    use rust_asn1::errors::ErrorCode;
+
+    let encoded = [&[0x17, 0x0D][..], b"700101000000Z"].concat();
+    let default = UTCTime::from_der_bytes(&encoded).unwrap();
+    assert_eq!(default.0, Utc.with_ymd_and_hms(1970, 1, 1, 0, 0, 0).unwrap());
+    let shifted = UTCTime::from_der_node_with_window(der::parse(&encoded).unwrap(), 1990).unwrap();
+    assert_eq!(shifted.0, Utc.with_ymd_and_hms(2070, 1, 1, 0, 0, 0).unwrap());
+    let ber_encoded = [&[0x17, 0x11][..], b"891231000000+0100"].concat();
+    let ber_node = ber::parse(&ber_encoded).unwrap();
+    assert_eq!(UTCTime::from_ber_node_with_window(ber_node, 1990).unwrap().0, Utc.with_ymd_and_hms(2089, 12, 30, 23, 0, 0).unwrap());
+    assert_eq!(UTCTime::from_str_with_window("900101000000Z", 1990).unwrap().0.year(), 1990);
+
+    // Serializing checks the value falls in the window the reader will use.
+    let mut serializer = Serializer::new();
+    shifted.serialize_with_window(&mut serializer, 1990).unwrap();
+    assert_eq!(serializer.serialized_bytes().to_vec(), encoded);
+    let err = shifted.serialize(&mut Serializer::new()).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+    let err = default.serialize_with_window(&mut Serializer::new(), 1990).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+
+    // Windows running past the end of i32 are rejected rather than overflowing.
+    let err = UTCTime::from_str_with_window("700101000000Z", i32::MAX).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+    let err = UTCTime::from_ber_str_with_window("700101000000Z", i32::MAX - 98).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+    let err = default.serialize_with_window(&mut Serializer::new(), i32::MAX).unwrap_err();
+    assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+    assert!(UTCTime::from_str_with_window("700101000000Z", i32::MIN).is_err());
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_chrono_datetime_accepts_either_time_type() {
+    use chrono::DateTime;
+
+    let expected = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    let utc_time = [&[0x17, 0x0D][..], b"230101120000Z"].concat();
+    let generalized = [&[0x18, 0x0F][..], b"20230101120000Z"].concat();
+    assert_eq!(DateTime::<Utc>::from_der_bytes(&utc_time).unwrap(), expected);
+    assert_eq!(DateTime::<Utc>::from_der_bytes(&generalized).unwrap(), expected);
+    assert_eq!(DateTime::<Utc>::from_ber_node(ber::parse(&utc_time).unwrap()).unwrap(), expected);
+    let offset = [&[0x17, 0x11][..], b"230101130000+0100"].concat();
+    assert_eq!(DateTime::<Utc>::from_ber_node(ber::parse(&offset).unwrap()).unwrap(), expected);
+    assert!(DateTime::<Utc>::from_der_bytes(&[0x04, 0x01, 0x00]).is_err());
+
+    // An implicit tag still implies GeneralizedTime.
+    let tagged = [&[0x80, 0x0D][..], b"230101120000Z"].concat();
+    let node = der::parse(&tagged).unwrap();
+    let tag = ASN1Identifier::new(0, rust_asn1::asn1_types::TagClass::ContextSpecific);
+    assert!(DateTime::<Utc>::from_der_node_with_identifier(node, tag).is_err());
+
+    // OPTIONAL fields and CHOICE alternatives recognise both time types.
+    for time in [&utc_time, &generalized] {
+        let encoded = [&[0x30, time.len() as u8 + 3][..], time, &[0x02, 0x01, 0x07]].concat();
+        let (when, serial) = der::sequence(der::parse(&encoded).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+            Ok((Option::<DateTime<Utc>>::from_der_iterator(iter)?, i64::from_der_iterator(iter)?))
+        })
+        .unwrap();
+        assert_eq!((when, serial), (Some(expected), 7));
+        let choice = der::choice(der::parse(time).unwrap()).alternative(|t: i64| t.to_string()).alternative(|t: DateTime<Utc>| t.to_string()).finish();
+        assert_eq!(choice.unwrap(), expected.to_string());
+    }
+    let absent = [0x30, 0x03, 0x02, 0x01, 0x07];
+    let when = der::sequence(der::parse(&absent).unwrap(), ASN1Identifier::SEQUENCE, |iter| {
+        let when = Option::<DateTime<Utc>>::from_der_iterator(iter)?;
+        i64::from_der_iterator(iter)?;
+        Ok(when)
+    });
+    assert_eq!(when.unwrap(), None);
+}
+
+#[test]
+fn test_system_time() {
+    use std::time::{Duration, SystemTime, UNIX_EPOCH};
+
+    let time = UNIX_EPOCH + Duration::from_secs(1_672_574_400) + Duration::from_millis(5);
+    let mut serializer = Serializer::new();
+    time.serialize(&mut serializer).unwrap();
+    let encoded = serializer.serialized_bytes();
+    assert_eq!(&encoded[2..], b"20230101120000.005Z");
+    assert_eq!(SystemTime::from_der_bytes(&encoded).unwrap(), time);
+    assert_eq!(SystemTime::from(GeneralizedTime::try_from(time).unwrap()), time);
+
+    let utc_time = [&[0x17, 0x0D][..], b"230101120000Z"].concat();
+    let whole = UNIX_EPOCH + Duration::from_secs(1_672_574_400);
+    assert_eq!(SystemTime::from_der_bytes(&utc_time).unwrap(), whole);
+    assert_eq!(SystemTime::from_ber_node(ber::parse(&utc_time).unwrap()).unwrap(), whole);
+    assert_eq!(SystemTime::from(UTCTime::try_from(whole).unwrap()), whole);
+
+    let optional = [&[0x30, 0x0F][..], &utc_time].concat();
+    let when = der::sequence(der::parse(&optional).unwrap(), ASN1Identifier::SEQUENCE, Option::<SystemTime>::from_der_iterator);
+    assert_eq!(when.unwrap(), Some(whole));
+
+    let before_epoch = UNIX_EPOCH - Duration::from_secs(86_400);
+    assert_eq!(GeneralizedTime::try_from(before_epoch).unwrap().to_string(), "19691231000000Z");
+
+    // A SystemTime past the backend's range is an error, not a panic.
+    let far_future = UNIX_EPOCH + Duration::from_secs(1 << 62);
+    let mut serializer = Serializer::new();
+    assert_eq!(far_future.serialize(&mut serializer).unwrap_err().code(), rust_asn1::errors::ErrorCode::ValueOutOfRange);
+    assert!(serializer.serialized_bytes().is_empty());
+    assert_eq!(GeneralizedTime::try_from(far_future).unwrap_err().code(), rust_asn1::errors::ErrorCode::ValueOutOfRange);
+    assert!(UTCTime::try_from(far_future).is_err());
+}
+
 #[test]
 fn test_time_der_invalid_utf8() {
     let node = der::parse(&[0x18, 0x01, 0xFF]).unwrap();
@@ -462,6 +813,7 @@ fn test_time_ber_identifier_mismatch_wrappers() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_string_validation() {
     // PrintableString
     // Valid
@@ -483,6 +835,48 @@ fn test_string_validation() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
+fn test_iso2022_string_types() {
+    // GeneralString, as used for Kerberos principal names.
+    let node = der::parse(&[0x1B, 0x06, 0x6B, 0x72, 0x62, 0x74, 0x67, 0x74]).unwrap();
+    assert_eq!(ASN1GeneralString::from_der_node(node).unwrap().0, "krbtgt");
+    let general = ASN1GeneralString::new("line\r\n".to_string()).unwrap();
+    let mut serializer = Serializer::new();
+    general.serialize(&mut serializer).unwrap();
+    assert_eq!(serializer.serialized_bytes().as_ref(), &[0x1B, 0x06, 0x6C, 0x69, 0x6E, 0x65, 0x0D, 0x0A]);
+
+    // GraphicString allows escape sequences but no other control characters.
+    assert!(ASN1GraphicString::new("\u{1b}(BText".to_string()).is_ok());
+    assert!(ASN1GraphicString::new("Text\n".to_string()).is_err());
+    let node = der::parse(&[0x19, 0x02, 0x41, 0x07]).unwrap();
+    assert!(ASN1GraphicString::from_der_node(node).is_err());
+
+    let node = ber::parse(&[0x35, 0x80, 0x15, 0x01, 0x41, 0x15, 0x01, 0x42, 0x00, 0x00]).unwrap();
+    assert_eq!(ASN1VideotexString::from_ber_node(node).unwrap().0, "AB");
+    let node = der::parse(&[0x15, 0x01, 0x41]).unwrap();
+    assert!(ASN1GeneralString::from_der_node(node).is_err());
+}
+
+#[test]
+#[cfg(feature = "strings")]
+fn test_object_descriptor() {
+    let descriptor = ASN1ObjectDescriptor::new("ASN.1 basic encoding".to_string()).unwrap();
+    let mut serializer = Serializer::new();
+    descriptor.serialize(&mut serializer).unwrap();
+    let encoded = serializer.serialized_bytes();
+    assert_eq!(&encoded[..2], &[0x07, 0x14]);
+    assert_eq!(ASN1ObjectDescriptor::from_der_bytes(&encoded).unwrap(), descriptor);
+    assert_eq!(ASN1ObjectDescriptor::default_identifier(), ASN1Identifier::OBJECT_DESCRIPTOR);
+
+    // Same character set as GraphicString, but a different tag.
+    assert!(ASN1ObjectDescriptor::new("tab\t".to_string()).is_err());
+    assert!(ASN1ObjectDescriptor::from_der_bytes(&[0x19, 0x01, 0x41]).is_err());
+    let node = ber::parse(&[0x27, 0x06, 0x07, 0x01, 0x41, 0x07, 0x01, 0x42]).unwrap();
+    assert_eq!(ASN1ObjectDescriptor::from_ber_node(node).unwrap().0, "AB");
+}
+
+#[test]
+#[cfg(feature = "strings")]
 fn test_strings_der_identifier_mismatch() {
     let node = der::parse(&[0x13, 0x01, 0x41]).unwrap();
     let res = <ASN1PrintableString as DERImplicitlyTaggable>::from_der_node_with_identifier(
@@ -493,6 +887,7 @@ fn test_strings_der_identifier_mismatch() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_der_invalid_content() {
     let node = der::parse(&[0x13, 0x01, 0x40]).unwrap();
     let res = ASN1PrintableString::from_der_node(node);
@@ -500,6 +895,7 @@ fn test_strings_der_invalid_content() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_der_constructed_rejected() {
     let node = der::parse(&[0x2C, 0x00]).unwrap();
     let res = ASN1UTF8String::from_der_node(node);
@@ -507,6 +903,7 @@ fn test_strings_der_constructed_rejected() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_ber_identifier_mismatch() {
     let node = ber::parse(&[0x0C, 0x01, 0x41]).unwrap();
     let res = <ASN1UTF8String as BERImplicitlyTaggable>::from_ber_node_with_identifier(
@@ -517,6 +914,7 @@ fn test_strings_ber_identifier_mismatch() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_ber_invalid_content() {
     let node = ber::parse(&[0x12, 0x03, 0x31, 0x41, 0x32]).unwrap();
     let res = ASN1NumericString::from_ber_node(node);
@@ -524,6 +922,7 @@ fn test_strings_ber_invalid_content() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_der_invalid_utf8() {
     let node = der::parse(&[0x0C, 0x01, 0xFF]).unwrap();
     let res = ASN1UTF8String::from_der_node(node);
@@ -531,6 +930,7 @@ fn test_strings_der_invalid_utf8() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_der_invalid_content_numeric_and_ia5() {
     let node = der::parse(&[0x12, 0x03, 0x31, 0x41, 0x32]).unwrap();
     let res = ASN1NumericString::from_der_node(node);
@@ -543,6 +943,7 @@ fn test_strings_der_invalid_content_numeric_and_ia5() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_der_constructed_rejected_for_all_types() {
     let node = der::parse(&[0x2C, 0x00]).unwrap();
     assert!(ASN1UTF8String::from_der_node(node).is_err());
@@ -558,6 +959,7 @@ fn test_strings_der_constructed_rejected_for_all_types() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_ber_constructed_concat_success_for_multiple_types() {
     // PrintableString constructed: "AB" + "CD"
     let data = [0x33, 0x08, 0x13, 0x02, 0x41, 0x42, 0x13, 0x02, 0x43, 0x44];
@@ -579,6 +981,7 @@ fn test_strings_ber_constructed_concat_success_for_multiple_types() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_ber_constructed_child_type_error() {
     // Constructed PrintableString containing IA5String child should error
     let data = [0x33, 0x04, 0x16, 0x02, 0x41, 0x42];
@@ -588,6 +991,7 @@ fn test_strings_ber_constructed_child_type_error() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_strings_ber_invalid_utf8() {
     let node = ber::parse(&[0x0C, 0x01, 0xFF]).unwrap();
     let res = ASN1UTF8String::from_ber_node(node);
@@ -595,6 +999,7 @@ fn test_strings_ber_invalid_utf8() {
 }
 
 #[test]
+#[cfg(feature = "strings")]
 fn test_ber_constructed_string() {
     // Constructed OCTET STRING is already tested in ber_tests.rs
     // Let's test constructed UTF8String if implemented or supported by generic logic?
@@ -697,6 +1102,7 @@ fn test_integer_to_u64_actual_value() {
 /// Kill mutant: From<ASN1Integer> for BigInt -> Default::default()
 /// Default for BigInt is 0, so test with non-zero values.
 #[test]
+#[cfg(feature = "num-bigint")]
 fn test_asn1integer_into_bigint_actual_value() {
     use num_bigint::BigInt;
 
@@ -769,3 +1175,300 @@ fn test_option_serialize_with_boolean() {
     // BOOLEAN true = 01 01 FF
     assert_eq!(serializer.serialized_bytes().as_ref(), &[0x01, 0x01, 0xFF]);
 }
+
+#[test]
+fn test_octet_string_of_vec_roundtrip() {
+    use rust_asn1::asn1_types::OctetStringOf;
+
+    let value = OctetStringOf(vec![0xDE, 0xAD]);
+    let mut serializer = Serializer::new();
+    serializer.serialize(&value).unwrap();
+    assert_eq!(serializer.serialized_bytes(), vec![0x04, 0x02, 0xDE, 0xAD]);
+
+    // A bare Vec<u8> is a SEQUENCE OF INTEGER, which is what the wrapper avoids.
+    let mut seq = Serializer::new();
+    seq.serialize(&vec![0xDEu8, 0xAD]).unwrap();
+    assert_eq!(seq.serialized_bytes()[0], 0x30);
+
+    let parsed = OctetStringOf::<Vec<u8>>::from_der_bytes(&[0x04, 0x02, 0xDE, 0xAD]).unwrap();
+    assert_eq!(parsed, value);
+    assert_eq!(parsed.into_inner(), vec![0xDE, 0xAD]);
+
+    // BER constructed form concatenates the segments.
+    let node = ber::parse(&[0x24, 0x06, 0x04, 0x01, 0xDE, 0x04, 0x01, 0xAD]).unwrap();
+    let parsed = OctetStringOf::<Vec<u8>>::from_ber_node(node).unwrap();
+    assert_eq!(parsed, value);
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_time_display_and_from_str() {
+    let dt = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+
+    let gt: GeneralizedTime = "20230101120000Z".parse().unwrap();
+    assert_eq!(gt, GeneralizedTime(dt));
+    assert_eq!(gt.to_string(), "20230101120000Z");
+
+    let ut: UTCTime = "230101120000Z".parse().unwrap();
+    assert_eq!(ut, UTCTime(dt));
+    assert_eq!(ut.to_string(), "230101120000Z");
+
+    let old: UTCTime = "500101000000Z".parse().unwrap();
+    assert_eq!(old.0, Utc.with_ymd_and_hms(1950, 1, 1, 0, 0, 0).unwrap());
+
+    assert!("20230101120000".parse::<GeneralizedTime>().is_err());
+    assert!("2301011200Z".parse::<UTCTime>().is_err());
+    assert!("23010112000aZ".parse::<UTCTime>().is_err());
+}
+
+#[test]
+#[cfg(feature = "chrono")]
+fn test_chrono_datetime_as_generalized_time() {
+    use chrono::DateTime;
+
+    let dt = Utc.with_ymd_and_hms(2023, 1, 1, 12, 0, 0).unwrap();
+    let mut serializer = Serializer::new();
+    serializer.serialize(&dt).unwrap();
+    let encoded = serializer.serialized_bytes();
+
+    let mut expected = Serializer::new();
+    expected.serialize(&GeneralizedTime(dt)).unwrap();
+    assert_eq!(encoded, expected.serialized_bytes());
+
+    assert_eq!(DateTime::<Utc>::from_der_bytes(&encoded).unwrap(), dt);
+    assert_eq!(DateTime::<Utc>::from_ber_node(ber::parse(&encoded).unwrap()).unwrap(), dt);
+    assert_eq!(DateTime::<Utc>::default_identifier(), ASN1Identifier::GENERALIZED_TIME);
+
+    let mut utc = Serializer::new();
+    utc.serialize(&UTCTime(dt)).unwrap();
+    assert_eq!(DateTime::<Utc>::from_der_bytes(&utc.serialized_bytes()).unwrap(), dt);
+}
+
+#[test]
+fn test_bit_string_bitwise_operations() {
+    // KeyUsage-style flags: 9 bits 1000_0000 1 vs 3 bits 101.
+    let long = ASN1BitString::new(Bytes::from_static(&[0x80, 0x80]), 7).unwrap();
+    let short = ASN1BitString::new(Bytes::from_static(&[0xA0]), 5).unwrap();
+    assert_eq!(long.len_bits(), 9);
+    assert_eq!(short.len_bits(), 3);
+    assert_eq!(long.count_ones(), 2);
+
+    let and = long.and(&short);
+    assert_eq!((and.bytes.as_ref(), and.padding_bits), (&[0x80, 0x00][..], 7));
+    let or = long.or(&short);
+    assert_eq!((or.bytes.as_ref(), or.padding_bits), (&[0xA0, 0x80][..], 7));
+    let xor = short.xor(&long);
+    assert_eq!((xor.bytes.as_ref(), xor.padding_bits), (&[0x20, 0x80][..], 7));
+    assert_eq!(xor.count_ones(), 2);
+
+    assert!(and.is_subset_of(&long) && and.is_subset_of(&short));
+    assert!(!long.is_subset_of(&short));
+    assert!(ASN1BitString::new(Bytes::new(), 0).unwrap().is_subset_of(&short));
+
+    // Non-zero padding bits (tolerated by BER) never leak into results.
+    let dirty = ASN1BitString { bytes: Bytes::from_static(&[0xFF]), padding_bits: 4 };
+    assert_eq!(dirty.count_ones(), 4);
+    assert_eq!(dirty.or(&dirty).bytes.as_ref(), &[0xF0]);
+    assert!(dirty.is_subset_of(&ASN1BitString::new(Bytes::from_static(&[0xF0]), 4).unwrap()));
+}
+
+#[test]
+fn test_bit_string_bit_access() {
+    // KeyUsage: digitalSignature (0) and keyCertSign (5).
+    let usage = ASN1BitString::new(Bytes::from_static(&[0x84]), 2).unwrap();
+    assert!(usage.bit(0) && usage.bit(5));
+    assert!(!usage.bit(1) && !usage.bit(6) && !usage.bit(100));
+    assert_eq!(usage.bits().collect::<Vec<_>>(), vec![true, false, false, false, false, true]);
+
+    // Padding bits never read as set.
+    let dirty = ASN1BitString { bytes: Bytes::from_static(&[0xFF]), padding_bits: 4 };
+    assert!(dirty.bit(3) && !dirty.bit(4));
+    assert_eq!(dirty.bits().count(), 4);
+
+    let mut builder = usage.to_builder();
+    builder.set_bit(5, false).set_bit(8, true);
+    let built = builder.build();
+    assert_eq!((built.bytes.as_ref(), built.padding_bits), (&[0x80, 0x80][..], 7));
+    assert_eq!(dirty.to_builder().build().bytes.as_ref(), &[0xF0]);
+
+    let empty = BitStringBuilder::new().build();
+    assert_eq!((empty.bytes.len(), empty.padding_bits), (0, 0));
+    let zeros = BitStringBuilder::with_len(12).build();
+    assert_eq!((zeros.bytes.as_ref(), zeros.padding_bits, zeros.len_bits()), (&[0x00, 0x00][..], 4, 12));
+    let mut serializer = Serializer::new();
+    serializer.serialize(&BitStringBuilder::new().set_bit(2, true).build()).unwrap();
+    assert_eq!(serializer.serialized_bytes(), vec![0x03, 0x02, 0x05, 0x20]);
+}
+
+#[test]
+fn test_bit_string_from_bits() {
+    let bits = ASN1BitString::from_bits([true, false, true, true, false, false, false, false, true]);
+    assert_eq!((bits.bytes.as_ref(), bits.padding_bits), (&[0xB0, 0x80][..], 7));
+    assert_eq!(bits.bits().collect::<Vec<_>>(), vec![true, false, true, true, false, false, false, false, true]);
+    let byte = ASN1BitString::from_bits(vec![true; 8]);
+    assert_eq!((byte.bytes.as_ref(), byte.padding_bits), (&[0xFF][..], 0));
+    let empty = ASN1BitString::from_bits(std::iter::empty());
+    assert_eq!((empty.bytes.len(), empty.padding_bits), (0, 0));
+
+    let sliced = ASN1BitString::from_bytes_with_bit_len(Bytes::from_static(&[0xAA, 0xFF]), 10).unwrap();
+    assert_eq!((sliced.bytes.as_ref(), sliced.padding_bits), (&[0xAA, 0xC0][..], 6));
+    let whole = ASN1BitString::from_bytes_with_bit_len(Bytes::from_static(&[0x12]), 8).unwrap();
+    assert_eq!(whole.padding_bits, 0);
+    assert!(ASN1BitString::from_bytes_with_bit_len(Bytes::new(), 0).unwrap().bytes.is_empty());
+    assert!(ASN1BitString::from_bytes_with_bit_len(Bytes::from_static(&[0x12, 0x34]), 8).is_err());
+    assert!(ASN1BitString::from_bytes_with_bit_len(Bytes::from_static(&[0x12]), 9).is_err());
+}
+
+#[test]
+fn test_bit_string_slice() {
+    // 1011_0000 1 followed by padding.
+    let bits = ASN1BitString::new(Bytes::from_static(&[0xB0, 0x80]), 7).unwrap();
+    let middle = bits.slice(2..9);
+    assert_eq!((middle.bytes.as_ref(), middle.padding_bits), (&[0xC2][..], 1));
+    assert_eq!(bits.slice(..3).bits().collect::<Vec<_>>(), vec![true, false, true]);
+    assert_eq!(bits.slice(8..).bits().collect::<Vec<_>>(), vec![true]);
+    assert_eq!(bits.slice(..), bits);
+    assert_eq!(bits.slice(4..4).len_bits(), 0);
+    assert_eq!(bits.slice(0..=7).bytes.as_ref(), &[0xB0]);
+    assert!(std::panic::catch_unwind(|| bits.slice(0..10)).is_err());
+}
+
+#[test]
+fn test_bit_string_decode_inner_der() {
+    // An RSAPublicKey-like SEQUENCE { INTEGER 5, INTEGER 3 } wrapped in a BIT STRING.
+    let wrapped = ASN1BitString::new(Bytes::from_static(&[0x30, 0x06, 0x02, 0x01, 0x05, 0x02, 0x01, 0x03]), 0).unwrap();
+    assert_eq!(wrapped.decode_inner_der::<Vec<ASN1Integer>>().unwrap(), vec![ASN1Integer::from(5), ASN1Integer::from(3)]);
+
+    let padded = ASN1BitString::new(Bytes::from_static(&[0x05, 0x00]), 1).unwrap();
+    let err = padded.decode_inner_der::<ASN1Null>().unwrap_err();
+    assert_eq!(err.code(), rust_asn1::errors::ErrorCode::InvalidASN1Object);
+    let trailing = ASN1BitString::new(Bytes::from_static(&[0x05, 0x00, 0x00]), 0).unwrap();
+    assert!(trailing.decode_inner_der::<ASN1Null>().is_err());
+    let ber_only = ASN1BitString::new(Bytes::from_static(&[0x01, 0x01, 0x01]), 0).unwrap();
+    assert!(ber_only.decode_inner_der::<ASN1Boolean>().is_err());
+}
+
+#[test]
+fn test_octet_and_bit_string_hex() {
+    let octets = ASN1OctetString::from_hex("0aBC00").unwrap();
+    assert_eq!(octets, ASN1OctetString::from(vec![0x0A, 0xBC, 0x00]));
+    assert_eq!(format!("{:X}", octets), "0ABC00");
+    assert_eq!(format!("{:#X}", octets), "0x0ABC00");
+    assert_eq!(ASN1OctetString::from_hex("").unwrap().0.len(), 0);
+    for bad in ["abc", "zz", "0x00", "a b ", "é0"] {
+        let err = ASN1OctetString::from_hex(bad).unwrap_err();
+        assert_eq!(err.code(), rust_asn1::errors::ErrorCode::InvalidStringRepresentation, "{:?}", bad);
+    }
+
+    let bits = ASN1BitString::from_hex("F0a1").unwrap();
+    assert_eq!((bits.bytes.as_ref(), bits.padding_bits), (&[0xF0, 0xA1][..], 0));
+    assert_eq!(format!("{:x}", bits), "f0a1");
+    assert_eq!(format!("{:#X}", bits), "0xF0A1");
+    assert!(ASN1BitString::from_hex("f").is_err());
+    // Padding bits do not show.
+    let dirty = ASN1BitString { bytes: Bytes::from_static(&[0xFF]), padding_bits: 4 };
+    assert_eq!(format!("{:x}", dirty), "f0");
+}
+
+#[test]
+fn test_octet_string_as_bytes() {
+    let octets = ASN1OctetString::from(vec![0xDE, 0xAD]);
+    assert_eq!(octets, [0xDE, 0xAD]);
+    assert_eq!(octets, &[0xDE, 0xAD][..]);
+    assert_eq!(octets, [0xDE, 0xAD][..]);
+    assert_eq!(octets, vec![0xDE, 0xAD]);
+    assert_ne!(octets, [0xDE]);
+    assert_eq!(octets.len(), 2);
+    assert_eq!(octets[1], 0xAD);
+    assert!(octets.starts_with(&[0xDE]));
+    let takes_slice = |bytes: &[u8]| bytes.to_vec();
+    assert_eq!(takes_slice(&octets), vec![0xDE, 0xAD]);
+    let takes_as_ref = |bytes: &dyn AsRef<[u8]>| bytes.as_ref().len();
+    assert_eq!(takes_as_ref(&octets), 2);
+}
+
+#[test]
+fn test_bit_string_from_str() {
+    let bits: ASN1BitString = "0101110".parse().unwrap();
+    assert_eq!((bits.bytes.as_ref(), bits.padding_bits), (&[0x5C][..], 1));
+    let hex: ASN1BitString = "5D/7".parse().unwrap();
+    assert_eq!(hex, bits);
+    let long: ASN1BitString = "ffff01/17".parse().unwrap();
+    assert_eq!((long.bytes.as_ref(), long.padding_bits), (&[0xFF, 0xFF, 0x00][..], 7));
+    assert_eq!("".parse::<ASN1BitString>().unwrap().len_bits(), 0);
+    assert_eq!("/0".parse::<ASN1BitString>().unwrap().len_bits(), 0);
+    for bad in ["0102", "5c/", "5c/9", "5c/x", "5/4", "ab"] {
+        let err = bad.parse::<ASN1BitString>().unwrap_err();
+        assert_eq!(err.code(), rust_asn1::errors::ErrorCode::InvalidStringRepresentation, "{:?}", bad);
+    }
+}
+
+#[test]
+fn test_integer_and_octet_string_display() {
+    let serial = ASN1Integer::from_der_bytes(&[0x02, 0x09, 0x00, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF]).unwrap();
+    assert_eq!(serial.to_string(), "18446744073709551615");
+    assert_eq!(ASN1Integer::from(-42).to_string(), "-42");
+    assert_eq!(format!("{:>5}", ASN1Integer::from(7)), "    7");
+
+    let key_id = ASN1OctetString::from(vec![0x0A, 0xBC, 0x00]);
+    assert_eq!(key_id.to_string(), "0abc00");
+    assert_eq!(format!("{:x}", key_id), "0abc00");
+    assert_eq!(format!("{:#x}", key_id), "0x0abc00");
+    assert_eq!(ASN1OctetString::from(Vec::new()).to_string(), "");
+}
+
+#[test]
+fn test_time_strict_range_validation() {
+    use rust_asn1::errors::ErrorCode;
+
+    for bad in [
+        "00001231235959Z", // year 0
+        "20231301000000Z", // month 13
+        "20230229000000Z", // not a leap year
+        "20230431000000Z", // April 31
+        "20230101240000Z", // hour 24
+        "20230101006000Z", // minute 60
+        "20231231235960Z", // leap second
+        "+2023010112000Z", // sign accepted by lenient parsers
+        "2023010112000.Z",
+    ] {
+        let err = bad.parse::<GeneralizedTime>().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidStringRepresentation, "{}", bad);
+    }
+    assert!("20240229000000Z".parse::<GeneralizedTime>().is_ok());
+    assert!("00010101000000Z".parse::<GeneralizedTime>().is_ok());
+
+    for bad in ["230229000000Z", "231200000000Z", "230101000060Z", "991232000000Z"] {
+        let err = bad.parse::<UTCTime>().unwrap_err();
+        assert_eq!(err.code(), ErrorCode::InvalidStringRepresentation, "{}", bad);
+    }
+    assert_eq!("491231235959Z".parse::<UTCTime>().unwrap().to_string(), "491231235959Z");
+
+    // Values outside the representable window cannot be serialized.
+    #[cfg(feature = "chrono")]
+    {
+        assert_eq!("491231235959Z".parse::<UTCTime>().unwrap().0, Utc.with_ymd_and_hms(2049, 12, 31, 23, 59, 59).unwrap());
+        let mut serializer = Serializer::new();
+        let err = serializer.serialize(&UTCTime(Utc.with_ymd_and_hms(2050, 1, 1, 0, 0, 0).unwrap())).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+        let err = serializer.serialize(&GeneralizedTime(Utc.with_ymd_and_hms(10000, 1, 1, 0, 0, 0).unwrap())).unwrap_err();
+        assert_eq!(err.code(), ErrorCode::ValueOutOfRange);
+        assert!(serializer.serialized_bytes().is_empty());
+    }
+}
+
+#[test]
+fn test_octet_string_decode_inner_across_rules() {
+    // DER outer OCTET STRING wrapping an indefinite-length constructed BER OCTET STRING.
+    let outer = [0x04, 0x07, 0x24, 0x80, 0x04, 0x01, 0xAA, 0x00, 0x00];
+    let wrapped = ASN1OctetString::from_der_bytes(&outer).unwrap();
+    assert_eq!(wrapped.decode_inner_ber::<ASN1OctetString>().unwrap(), ASN1OctetString::from(vec![0xAA]));
+    assert!(wrapped.decode_inner_der::<ASN1OctetString>().is_err());
+
+    let der_inner = ASN1OctetString::from(vec![0x01, 0x01, 0xFF]);
+    assert!(der_inner.decode_inner_der::<bool>().unwrap());
+
+    // The contents must be exactly one element.
+    let trailing = ASN1OctetString::from(vec![0x05, 0x00, 0x05, 0x00]);
+    assert!(trailing.decode_inner_ber::<ASN1Null>().is_err());
+}